@@ -0,0 +1,107 @@
+use lin_alg_lab::affine::{Affine2, AffineDecomposition};
+use lin_alg_lab::matrix::Matrix2x2;
+use lin_alg_lab::matrix_dense::{MatrixError, matmul, matvec};
+use lin_alg_lab::vector::{Vector2, distance};
+use lin_alg_lab::{assert_approx_eq, assert_approx_ne};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+fn main() -> Result<(), MatrixError> {
+    let p = Vector2::new(2.0, 1.0);
+    let v = Vector2::new(1.0, 0.0);
+
+    // 点与方向：平移只影响点
+    let t = Affine2::translation(Vector2::new(3.0, -1.0));
+    assert_approx_eq!(t.transform_point(&p), Vector2::new(5.0, 0.0));
+    assert_approx_eq!(t.transform_vector(&v), v);
+
+    // 旋转与 Matrix2x2::rotation 一致
+    let r = Affine2::rotation(FRAC_PI_2);
+    assert_approx_eq!(r.transform_point(&p), Matrix2x2::rotation(FRAC_PI_2) * p);
+    assert_approx_eq!(r.transform_point(&p), Vector2::new(-1.0, 2.0));
+
+    // 绕某点旋转：中心不动，到中心的距离不变
+    let c = Vector2::new(1.0, 1.0);
+    let rc = Affine2::rotation_about(c, FRAC_PI_2);
+    assert_approx_eq!(rc.transform_point(&c), c);
+    assert_approx_eq!(rc.transform_point(&p), Vector2::new(1.0, 2.0));
+    assert_approx_eq!(distance(&rc.transform_point(&p), &c), distance(&p, &c));
+
+    // “绕点旋转再移动”：then 按先后顺序书写，* 按矩阵顺序书写
+    let moved = rc.then(&t);
+    assert_approx_eq!(moved, t * rc);
+    assert_approx_eq!(moved.transform_point(&p), Vector2::new(4.0, 1.0));
+    assert_approx_ne!(moved, rc * t);
+
+    // 缩放、错切、镜像
+    let s = Affine2::scaling(2.0, 3.0);
+    assert_approx_eq!(s.transform_point(&p), Vector2::new(4.0, 3.0));
+    assert_approx_eq!(s.determinant(), 6.0);
+    let h = Affine2::shear(0.5, 0.0);
+    assert_approx_eq!(h.transform_point(&p), Vector2::new(2.5, 1.0));
+    assert_approx_eq!(h.determinant(), 1.0);
+    let m = Affine2::reflection(Vector2::new(1.0, 1.0)).unwrap();
+    assert_approx_eq!(m.transform_point(&p), Vector2::new(1.0, 2.0));
+    assert_approx_eq!(m.determinant(), -1.0);
+    assert_approx_eq!(m * m, Affine2::identity());
+    assert!(Affine2::reflection(Vector2::new(0.0, 0.0)).is_none());
+
+    // 逆变换
+    let a = Affine2::translation(Vector2::new(-2.0, 5.0))
+        * Affine2::rotation(0.7)
+        * Affine2::shear(0.3, -0.2)
+        * Affine2::scaling(1.5, 0.4);
+    let inv = a.inverse()?;
+    assert_approx_eq!(inv.transform_point(&a.transform_point(&p)), p);
+    assert_approx_eq!(a * inv, Affine2::identity());
+    assert_approx_eq!(inv * a, Affine2::identity());
+    assert!(matches!(
+        Affine2::scaling(1.0, 0.0).inverse(),
+        Err(MatrixError::Singular)
+    ));
+
+    // 齐次矩阵：合成 = 3 × 3 矩阵乘积，变换点 = 乘 (x, y, 1)
+    let ha = a.to_homogeneous();
+    let hb = rc.to_homogeneous();
+    assert_approx_eq!((a * rc).to_homogeneous(), matmul(&ha, &hb)?);
+    let q = a.transform_point(&p);
+    assert_approx_eq!(matvec(&ha, &[p.x(), p.y(), 1.0])?, vec![q.x(), q.y(), 1.0]);
+
+    // 分解：平移 + 旋转 + 缩放精确还原
+    let trs = Affine2::translation(Vector2::new(4.0, -3.0))
+        * Affine2::rotation(2.0)
+        * Affine2::scaling(3.0, 0.5);
+    let d = trs.decompose()?;
+    assert_approx_eq!(d.translation, Vector2::new(4.0, -3.0));
+    assert_approx_eq!(d.rotation, 2.0);
+    assert_approx_eq!(d.shear, 0.0, abs = 1e-14);
+    assert_approx_eq!(d.scale, Vector2::new(3.0, 0.5));
+    assert_approx_eq!(Affine2::compose(&d), trs);
+
+    // 一般的仿射变换（含错切、反射）也能精确重组
+    for affine in [
+        a,
+        m,
+        moved,
+        Affine2::rotation(-3.0) * Affine2::scaling(-2.0, 1.0),
+    ] {
+        let d = affine.decompose()?;
+        assert!(d.rotation.abs() <= PI);
+        assert_approx_eq!(Affine2::compose(&d), affine, abs = 1e-14);
+        assert_eq!(d.scale.y() < 0.0, affine.determinant() < 0.0);
+    }
+    let parts = AffineDecomposition {
+        translation: Vector2::new(1.0, 2.0),
+        rotation: FRAC_PI_4,
+        shear: 0.25,
+        scale: Vector2::new(2.0, 3.0),
+    };
+    let back = Affine2::compose(&parts).decompose()?;
+    assert_approx_eq!(back.rotation, FRAC_PI_4);
+    assert_approx_eq!(back.shear, 0.25);
+    assert_approx_eq!(back.scale, parts.scale);
+    assert!(Affine2::scaling(0.0, 1.0).decompose().is_err());
+
+    println!("decomposition of a: {:?}", a.decompose()?);
+    println!("all Affine2 checks passed");
+    Ok(())
+}
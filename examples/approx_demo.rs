@@ -0,0 +1,52 @@
+use lin_alg_lab::approx::{ApproxEq, Mismatch, Tolerance, ulp_distance};
+use lin_alg_lab::matrix::Matrix2x2;
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError};
+use lin_alg_lab::vector::Vector2;
+use lin_alg_lab::{assert_approx_eq, assert_approx_ne};
+
+fn main() -> Result<(), MatrixError> {
+    // 标量：三种判据
+    assert_approx_eq!(0.1 + 0.2, 0.3);
+    assert_approx_ne!(0.1 + 0.2, 0.3, ulps = 0);
+    assert_eq!(ulp_distance(1.0, 1.0 + f64::EPSILON), 1);
+    assert_eq!(ulp_distance(0.0, -0.0), 0);
+    assert_approx_eq!(1e-20, 0.0, abs = 1e-15);
+    assert_approx_ne!(1e-20, 0.0, rel = 1e-3);
+    assert_approx_eq!(1e20, 1e20 * (1.0 + 1e-10), rel = 1e-9);
+    assert_approx_ne!(f64::NAN, f64::NAN, abs = 1.0);
+    assert_approx_eq!(f64::INFINITY, f64::INFINITY);
+
+    // Vector2 / Matrix2x2
+    let v = Matrix2x2::rotation(std::f64::consts::FRAC_PI_2) * Vector2::new(1.0, 0.0);
+    assert_approx_eq!(v, Vector2::new(0.0, 1.0));
+    let r = Matrix2x2::rotation(0.3) * Matrix2x2::rotation(-0.3);
+    assert_approx_eq!(r, Matrix2x2::identity());
+
+    // 切片与稠密矩阵
+    assert_approx_eq!(vec![1.0, 2.0], vec![1.0, 2.0 + 1e-13]);
+    assert_approx_eq!(&[1.0, 2.0][..], &[1.0, 2.0][..]);
+    let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0])?;
+    let b = Matrix::new(2, 2, vec![1.0, 2.1, 3.0, 4.5])?;
+    assert_approx_eq!(a, b, abs = 0.6);
+
+    // 不相等时报告误差最大的元素
+    let m = a.worst_mismatch(&b, Tolerance::absolute(0.01));
+    println!("worst mismatch: {}", m.clone().unwrap());
+    assert!(matches!(m, Some(Mismatch::Value { ref location, .. }) if location == "(1, 1)"));
+    let shape = a.worst_mismatch(&Matrix::zeros(2, 3), Tolerance::default());
+    println!("shape mismatch: {}", shape.clone().unwrap());
+    assert!(matches!(shape, Some(Mismatch::Shape { .. })));
+
+    // 断言失败时的 panic 信息（临时关掉默认的 panic 输出）
+    std::panic::set_hook(Box::new(|_| {}));
+    let message = std::panic::catch_unwind(|| {
+        assert_approx_eq!(vec![1.0, 2.0, 3.0], vec![1.0, 2.5, 3.1], abs = 1e-3);
+    })
+    .unwrap_err();
+    let _ = std::panic::take_hook();
+    let text = message.downcast_ref::<String>().unwrap();
+    println!("panic message:\n{}", text);
+    assert!(text.contains("at [1]"));
+
+    Ok(())
+}
@@ -0,0 +1,224 @@
+use lin_alg_lab::blas::{
+    Diag, Side, Transpose, UpLo, axpy, dot, gemm, gemv, ger, nrm2, scal, syrk, trsm, trsv,
+};
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul};
+use lin_alg_lab::random::Rng;
+
+fn random(rows: usize, cols: usize, rng: &mut Rng) -> Matrix {
+    Matrix::new(
+        rows,
+        cols,
+        (0..rows * cols).map(|_| rng.next_gaussian()).collect(),
+    )
+    .unwrap()
+}
+
+fn op(a: &Matrix, t: Transpose) -> Matrix {
+    match t {
+        Transpose::No => a.clone(),
+        Transpose::Yes => a.transpose().unwrap(),
+    }
+}
+
+fn max_diff(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).fold(0.0, |m, (x, y)| m.max((x - y).abs()))
+}
+
+/// 只保留 uplo 指定的三角部分，并把对角元调大，保证良态
+fn triangular(a: &Matrix, uplo: UpLo) -> Matrix {
+    let n = a.rows();
+    let mut t = Matrix::zeros(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            let keep = match uplo {
+                UpLo::Lower => j <= i,
+                UpLo::Upper => j >= i,
+            };
+            if keep {
+                let v = a.get(i, j).unwrap();
+                t.set(i, j, if i == j { v.abs() + 4.0 } else { v });
+            }
+        }
+    }
+    t
+}
+
+fn main() -> Result<(), MatrixError> {
+    let mut rng = Rng::new(35);
+    let flags = [Transpose::No, Transpose::Yes];
+
+    // 1 级
+    let mut y = vec![1.0, 2.0, 3.0];
+    axpy(2.0, &[1.0, 0.0, -1.0], &mut y)?;
+    assert_eq!(y, vec![3.0, 2.0, 1.0]);
+    scal(0.5, &mut y);
+    assert_eq!(y, vec![1.5, 1.0, 0.5]);
+    assert_eq!(dot(&[1.0, 2.0], &[3.0, 4.0])?, 11.0);
+    assert!(dot(&[1.0], &[1.0, 2.0]).is_err());
+    assert_eq!(nrm2(&[3.0, 4.0]), 5.0);
+    // 朴素的 sqrt(Σx²) 在这里会上溢成 inf
+    assert!((nrm2(&[3e200, 4e200]) / 5e200 - 1.0).abs() < 1e-15);
+
+    // gemv：两种转置
+    for t in flags {
+        let a = random(5, 3, &mut rng);
+        let x: Vec<f64> = (0..op(&a, t).cols()).map(|_| rng.next_gaussian()).collect();
+        let y0: Vec<f64> = (0..op(&a, t).rows()).map(|_| rng.next_gaussian()).collect();
+        let mut y = y0.clone();
+        gemv(t, 2.0, &a, &x, -1.0, &mut y)?;
+        let ax = matmul(&op(&a, t), &Matrix::new(x.len(), 1, x.clone())?)?;
+        let expect: Vec<f64> = ax
+            .as_slice()
+            .iter()
+            .zip(&y0)
+            .map(|(p, q)| 2.0 * p - q)
+            .collect();
+        assert!(max_diff(&y, &expect) < 1e-13);
+    }
+    // beta = 0 时不读旧值：NaN 不会传播
+    let mut y = vec![f64::NAN; 2];
+    gemv(
+        Transpose::No,
+        1.0,
+        &Matrix::identity(2),
+        &[1.0, 2.0],
+        0.0,
+        &mut y,
+    )?;
+    assert_eq!(y, vec![1.0, 2.0]);
+
+    // ger
+    let mut a = Matrix::zeros(2, 3);
+    ger(2.0, &[1.0, -1.0], &[1.0, 2.0, 3.0], &mut a)?;
+    assert_eq!(a.as_slice(), &[2.0, 4.0, 6.0, -2.0, -4.0, -6.0]);
+
+    // gemm：四种转置组合与 matmul 对照
+    for ta in flags {
+        for tb in flags {
+            let a = match ta {
+                Transpose::No => random(4, 6, &mut rng),
+                Transpose::Yes => random(6, 4, &mut rng),
+            };
+            let b = match tb {
+                Transpose::No => random(6, 3, &mut rng),
+                Transpose::Yes => random(3, 6, &mut rng),
+            };
+            let c0 = random(4, 3, &mut rng);
+            let mut c = c0.clone();
+            gemm(ta, tb, 0.5, &a, &b, 2.0, &mut c)?;
+            let ab = matmul(&op(&a, ta), &op(&b, tb))?;
+            let expect: Vec<f64> = ab
+                .as_slice()
+                .iter()
+                .zip(c0.as_slice())
+                .map(|(p, q)| 0.5 * p + 2.0 * q)
+                .collect();
+            assert!(max_diff(c.as_slice(), &expect) < 1e-13);
+        }
+    }
+    assert!(
+        gemm(
+            Transpose::No,
+            Transpose::No,
+            1.0,
+            &Matrix::zeros(2, 3),
+            &Matrix::zeros(2, 3),
+            0.0,
+            &mut Matrix::zeros(2, 3)
+        )
+        .is_err()
+    );
+
+    // syrk：只更新指定三角，另一半保持不变
+    for uplo in [UpLo::Upper, UpLo::Lower] {
+        for t in flags {
+            let a = random(4, 7, &mut rng);
+            let n = op(&a, t).rows();
+            let mut c = Matrix::new(n, n, vec![7.0; n * n])?;
+            syrk(uplo, t, 1.0, &a, 0.0, &mut c)?;
+            let full = matmul(&op(&a, t), &op(&a, t).transpose()?)?;
+            for i in 0..n {
+                for j in 0..n {
+                    let in_tri = match uplo {
+                        UpLo::Upper => j >= i,
+                        UpLo::Lower => j <= i,
+                    };
+                    let expect = if in_tri { full.get(i, j)? } else { 7.0 };
+                    assert!((c.get(i, j)? - expect).abs() < 1e-13);
+                }
+            }
+        }
+    }
+
+    // syrk 的空维度：A 为 3 × 0 时 op(A) op(A)ᵀ 是 3 × 3 零矩阵（上三角只剩 βC）或 0 × 0
+    for t in flags {
+        let a = Matrix::zeros(3, 0);
+        let n = op(&a, t).rows();
+        let mut c = Matrix::new(n, n, vec![2.0; n * n])?;
+        syrk(UpLo::Upper, t, 1.0, &a, 0.5, &mut c)?;
+        for i in 0..n {
+            for j in 0..n {
+                assert_eq!(c.get(i, j)?, if j >= i { 1.0 } else { 2.0 });
+            }
+        }
+    }
+
+    // trsv / trsm：全部标志组合，解回代入检查
+    for uplo in [UpLo::Upper, UpLo::Lower] {
+        for t in flags {
+            for diag in [Diag::NonUnit, Diag::Unit] {
+                let stored = triangular(&random(5, 5, &mut rng), uplo);
+                // Diag::Unit 时对角元不被读取，参照矩阵里要换成 1
+                let mut effective = stored.clone();
+                if diag == Diag::Unit {
+                    for i in 0..5 {
+                        effective.set(i, i, 1.0);
+                    }
+                }
+                let op_a = op(&effective, t);
+
+                let b: Vec<f64> = (0..5).map(|_| rng.next_gaussian()).collect();
+                let mut x = b.clone();
+                trsv(uplo, t, diag, &stored, &mut x)?;
+                let back = matmul(&op_a, &Matrix::new(5, 1, x)?)?;
+                assert!(max_diff(back.as_slice(), &b) < 1e-12);
+
+                let b_left = random(5, 3, &mut rng);
+                let mut x = b_left.clone();
+                trsm(Side::Left, uplo, t, diag, 2.0, &stored, &mut x)?;
+                let back: Vec<f64> = matmul(&op_a, &x)?
+                    .as_slice()
+                    .iter()
+                    .map(|v| v / 2.0)
+                    .collect();
+                assert!(max_diff(&back, b_left.as_slice()) < 1e-12);
+
+                let b_right = random(3, 5, &mut rng);
+                let mut x = b_right.clone();
+                trsm(Side::Right, uplo, t, diag, 1.0, &stored, &mut x)?;
+                assert!(max_diff(matmul(&x, &op_a)?.as_slice(), b_right.as_slice()) < 1e-12);
+            }
+        }
+    }
+    let mut x = vec![1.0, 1.0];
+    assert!(matches!(
+        trsv(
+            UpLo::Lower,
+            Transpose::No,
+            Diag::NonUnit,
+            &Matrix::zeros(2, 2),
+            &mut x
+        ),
+        Err(MatrixError::Singular)
+    ));
+
+    // matmul / matvec / solve_lu 现在都建立在这些内核上
+    let a = Matrix::new(3, 3, vec![4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0])?;
+    let (l, u) = a.lu_decomposition()?;
+    let x = Matrix::solve_lu(&l, &u, &[1.0, 2.0, 3.0])?;
+    let ax = matmul(&a, &Matrix::new(3, 1, x)?)?;
+    assert!(max_diff(ax.as_slice(), &[1.0, 2.0, 3.0]) < 1e-14);
+    println!("all BLAS kernels agree with the reference products");
+
+    Ok(())
+}
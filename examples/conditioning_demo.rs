@@ -0,0 +1,63 @@
+use lin_alg_lab::conditioning::{MatrixNorm, condition_estimate_1};
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matvec};
+
+fn hilbert(n: usize) -> Result<Matrix, MatrixError> {
+    let mut data = Vec::with_capacity(n * n);
+    for i in 0..n {
+        for j in 0..n {
+            data.push(1.0 / (i + j + 1) as f64);
+        }
+    }
+    Matrix::new(n, n, data)
+}
+
+fn main() -> Result<(), MatrixError> {
+    // 1. 各种矩阵范数
+    //
+    // [  1  -2 ]
+    // [ -3   4 ]
+    let a = Matrix::new(2, 2, vec![1.0, -2.0, -3.0, 4.0])?;
+    assert_eq!(a.norm(MatrixNorm::One), 6.0);
+    assert_eq!(a.norm(MatrixNorm::Inf), 7.0);
+    assert_eq!(a.norm(MatrixNorm::Max), 4.0);
+    assert!((a.norm(MatrixNorm::Frobenius) - 30.0_f64.sqrt()).abs() < 1e-12);
+
+    // 2. 2×2 情况下可以精确算出 κ₁：A⁻¹ = 1/(-2) [4 2; 3 1]，‖A⁻¹‖₁ = 3.5
+    let (l, u) = a.lu_decomposition()?;
+    let kappa = condition_estimate_1(&a, &l, &u)?;
+    println!("kappa_1(A) estimate = {}", kappa);
+    assert!((kappa - 6.0 * 3.5).abs() < 1e-10);
+
+    // 3. Hilbert 矩阵：条件数随 n 指数增长
+    //    已知 κ₁(H_6) ≈ 2.9e7，κ₁(H_8) ≈ 3.4e10
+    for (n, reference) in [(6, 2.907e7), (8, 3.387e10)] {
+        let h = hilbert(n)?;
+        let x_true = vec![1.0; n];
+        let b = matvec(&h, &x_true)?;
+        let report = h.solve_with_report(&b)?;
+
+        let forward_err = report
+            .solution
+            .iter()
+            .map(|x| (x - 1.0).abs())
+            .fold(0.0, f64::max);
+        println!(
+            "H_{}: cond ≈ {:e}, residual = {:e}, backward error = {:e}, forward error = {:e} (bound {:e})",
+            n,
+            report.condition_estimate,
+            report.residual_norm,
+            report.backward_error,
+            forward_err,
+            report.forward_error_bound()
+        );
+
+        // 估计值在真实值的 1/3 到 1 倍之间
+        assert!(report.condition_estimate <= reference * 1.01);
+        assert!(report.condition_estimate >= reference / 3.0);
+        // 后向误差很小：LU 对这个矩阵是后向稳定的
+        assert!(report.backward_error < 1e-15);
+    }
+
+    println!("Conditioning demo passed.");
+    Ok(())
+}
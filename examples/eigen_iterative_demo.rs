@@ -0,0 +1,145 @@
+use lin_alg_lab::eigen::symmetric_eigen;
+use lin_alg_lab::eigen_iterative::{
+    EigenTarget, FnOperator, IterationOptions, KrylovOptions, arnoldi, inverse_iteration, lanczos,
+    power_iteration, rayleigh_quotient_iteration,
+};
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError};
+use lin_alg_lab::schur::{real_schur, schur_eigenvalues};
+
+fn main() -> Result<(), MatrixError> {
+    // 1. 矩阵无关：一维 Dirichlet Laplacian（三对角 -1, 2, -1），只用闭包描述 y = A x
+    //    精确特征值：λ_k = 2 - 2 cos(kπ / (n + 1))，k = 1..n
+    let n = 200;
+    let laplacian = FnOperator::new(n, |x: &[f64], y: &mut [f64]| {
+        for i in 0..x.len() {
+            let left = if i > 0 { x[i - 1] } else { 0.0 };
+            let right = if i + 1 < x.len() { x[i + 1] } else { 0.0 };
+            y[i] = 2.0 * x[i] - left - right;
+        }
+    });
+    let exact = |k: usize| 2.0 - 2.0 * (k as f64 * std::f64::consts::PI / (n + 1) as f64).cos();
+
+    let options = KrylovOptions {
+        max_dim: n,
+        tol: 1e-10,
+        target: EigenTarget::LargestReal,
+    };
+    let top = lanczos(&laplacian, 3, &options)?;
+    for (i, pair) in top.iter().enumerate() {
+        println!(
+            "Lanczos λ_{} = {:.12} (exact {:.12}), residual = {:e}",
+            n - i,
+            pair.value,
+            exact(n - i),
+            pair.residual
+        );
+        assert!((pair.value - exact(n - i)).abs() < 1e-9);
+        assert!(pair.residual < 1e-8);
+    }
+
+    // 2. 稠密对称矩阵：幂迭代、反迭代、Rayleigh 商迭代，与 Jacobi 全特征分解对比
+    let m = 6;
+    let mut data = vec![0.0; m * m];
+    for i in 0..m {
+        for j in 0..m {
+            data[i * m + j] =
+                1.0 / (1.0 + (i as f64 - j as f64).abs()) + if i == j { i as f64 } else { 0.0 };
+        }
+    }
+    let a = Matrix::new(m, m, data)?;
+    let (values, _) = symmetric_eigen(&a)?;
+    println!("all eigenvalues (Jacobi) = {:?}", values);
+
+    let it = IterationOptions::default();
+    let dominant = power_iteration(&a, &it)?;
+    println!(
+        "power iteration: λ = {}, residual = {:e}",
+        dominant.value, dominant.residual
+    );
+    assert!((dominant.value - values[m - 1]).abs() < 1e-9);
+
+    let near_two = inverse_iteration(&a, 2.1, &it)?;
+    let closest = values
+        .iter()
+        .copied()
+        .min_by(|x, y| (x - 2.1).abs().total_cmp(&(y - 2.1).abs()))
+        .unwrap_or(f64::NAN);
+    println!("inverse iteration near 2.1: λ = {}", near_two.value);
+    assert!((near_two.value - closest).abs() < 1e-9);
+
+    let rqi = rayleigh_quotient_iteration(&a, &[1.0, 0.0, 0.0, 0.0, 0.0, 0.0], &it)?;
+    println!(
+        "RQI from e_0: λ = {}, residual = {:e}",
+        rqi.value, rqi.residual
+    );
+    assert!(values.iter().any(|v| (v - rqi.value).abs() < 1e-10));
+
+    // 3. 非对称矩阵：Arnoldi 求模最大的特征值（这里是一对共轭复数）
+    let k = 30;
+    let mut data = vec![0.0; k * k];
+    for i in 0..k {
+        data[i * k + i] = 0.5 + 0.02 * i as f64;
+        if i + 1 < k {
+            data[i * k + i + 1] = 0.1;
+        }
+    }
+    // 左上角 2×2 旋转块：特征值 3 e^{±iπ/3}
+    let (c, s) = (
+        3.0 * (std::f64::consts::PI / 3.0).cos(),
+        3.0 * (std::f64::consts::PI / 3.0).sin(),
+    );
+    data[0] = c;
+    data[1] = -s;
+    data[k] = s;
+    data[k + 1] = c;
+    let g = Matrix::new(k, k, data)?;
+
+    let ritz = arnoldi(&g, 3, &KrylovOptions::default())?;
+    for pair in &ritz {
+        println!(
+            "Arnoldi λ = {:.10} + {:.10}i, residual = {:e}",
+            pair.re, pair.im, pair.residual
+        );
+        assert!(pair.residual < 1e-8);
+    }
+    // 复数对只返回虚部为正的代表，所以 3 个目标里有一对 + 一个实特征值
+    assert_eq!(ritz.len(), 2);
+    assert!((ritz[0].re - c).abs() < 1e-10 && (ritz[0].im - s).abs() < 1e-10);
+
+    let (_, t) = real_schur(&g)?;
+    let mut all = schur_eigenvalues(&t)?;
+    all.sort_by(|x, y| y.0.hypot(y.1).total_cmp(&x.0.hypot(x.1)));
+    assert!((ritz[1].re - all[2].0).abs() < 1e-9);
+
+    // 4. 重特征值：起始向量张成的 Krylov 子空间里每个重特征值只出现一次，
+    //    中断后必须换新方向继续，否则会漏掉重数
+    let diag = |d: &[f64]| {
+        let mut m = Matrix::zeros(d.len(), d.len());
+        for (i, &v) in d.iter().enumerate() {
+            m.set(i, i, v);
+        }
+        m
+    };
+    let largest = KrylovOptions {
+        target: EigenTarget::LargestReal,
+        ..KrylovOptions::default()
+    };
+    let repeated = lanczos(&diag(&[4.0, 4.0, 4.0, 1.0, 2.0, 3.0]), 3, &largest)?;
+    for pair in &repeated {
+        assert!((pair.value - 4.0).abs() < 1e-12 && pair.residual < 1e-10);
+    }
+    let repeated = lanczos(&diag(&[2.0, 2.0, 0.0, 0.0, 0.0]), 2, &largest)?;
+    assert!(repeated.iter().all(|p| (p.value - 2.0).abs() < 1e-12));
+    let smallest = KrylovOptions {
+        target: EigenTarget::SmallestReal,
+        ..KrylovOptions::default()
+    };
+    let repeated = arnoldi(&diag(&[1.0, 1.0, 5.0, 6.0, 7.0]), 2, &smallest)?;
+    assert_eq!(repeated.len(), 2);
+    for pair in &repeated {
+        assert!((pair.re - 1.0).abs() < 1e-12 && pair.im == 0.0 && pair.residual < 1e-10);
+    }
+
+    println!("Iterative eigen demo passed.");
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul};
+use lin_alg_lab::qr::householder_qr;
+use lin_alg_lab::random::Rng;
+
+fn max_diff(a: &Matrix, b: &Matrix) -> f64 {
+    a.as_slice()
+        .iter()
+        .zip(b.as_slice())
+        .fold(0.0, |m, (x, y)| m.max((x - y).abs()))
+}
+
+fn random(rows: usize, cols: usize, rng: &mut Rng) -> Matrix {
+    Matrix::new(
+        rows,
+        cols,
+        (0..rows * cols).map(|_| rng.next_gaussian()).collect(),
+    )
+    .unwrap()
+}
+
+fn gram(a: &Matrix) -> Result<Matrix, MatrixError> {
+    matmul(&a.transpose()?, a)
+}
+
+fn with_row(a: &Matrix, k: usize, row: Option<&[f64]>) -> Result<Matrix, MatrixError> {
+    let n = a.cols();
+    let mut data = Vec::new();
+    for i in 0..a.rows() {
+        if i == k {
+            if let Some(r) = row {
+                data.extend_from_slice(r);
+            } else {
+                continue;
+            }
+        }
+        data.extend_from_slice(&a.as_slice()[i * n..(i + 1) * n]);
+    }
+    if k == a.rows() {
+        data.extend_from_slice(row.unwrap());
+    }
+    Matrix::new(data.len() / n, n, data)
+}
+
+fn main() -> Result<(), MatrixError> {
+    let mut rng = Rng::new(37);
+    let n = 5;
+
+    // 1. Cholesky 秩一更新/降阶，与重新分解对照
+    let a = random(12, n, &mut rng);
+    let mut l = gram(&a)?.cholesky()?;
+    let x: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+    l.cholesky_update(&x)?;
+    let xm = Matrix::new(1, n, x.clone())?;
+    let updated = {
+        let g = gram(&a)?;
+        let xx = gram(&xm)?;
+        Matrix::new(
+            n,
+            n,
+            g.as_slice()
+                .iter()
+                .zip(xx.as_slice())
+                .map(|(p, q)| p + q)
+                .collect(),
+        )?
+    };
+    let fresh = updated.cholesky()?;
+    println!("cholesky update vs fresh: {:e}", max_diff(&l, &fresh));
+    assert!(max_diff(&l, &fresh) < 1e-12);
+
+    l.cholesky_downdate(&x)?;
+    let original = gram(&a)?.cholesky()?;
+    println!("cholesky downdate vs fresh: {:e}", max_diff(&l, &original));
+    assert!(max_diff(&l, &original) < 1e-12);
+
+    // 降阶后不再正定：报错且因子保持不变
+    let before = l.clone();
+    let huge: Vec<f64> = x.iter().map(|v| v * 100.0).collect();
+    assert!(matches!(
+        l.cholesky_downdate(&huge),
+        Err(MatrixError::NotPositiveDefinite)
+    ));
+    assert_eq!(before.as_slice(), l.as_slice());
+
+    // 2. Householder QR
+    let a = random(8, n, &mut rng);
+    let mut qr = householder_qr(&a)?;
+    assert!(max_diff(&matmul(&qr.q, &qr.r)?, &a) < 1e-13);
+    assert!(max_diff(&gram(&qr.q)?, &Matrix::identity(8)) < 1e-14);
+
+    // 3. 在线最小二乘：交替插入、删除行，每一步都和重新分解得到的最小二乘解对照
+    let mut current = a.clone();
+    let truth: Vec<f64> = (0..n).map(|j| j as f64 - 2.0).collect();
+    for step in 0..20 {
+        if step % 3 == 2 {
+            let k = step % current.rows();
+            qr.delete_row(k)?;
+            current = with_row(&current, k, None)?;
+        } else {
+            let row: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+            let k = (step * 7) % (current.rows() + 1);
+            qr.insert_row(k, &row)?;
+            current = with_row(&current, k, Some(&row))?;
+        }
+        let m = current.rows();
+        assert!(max_diff(&matmul(&qr.q, &qr.r)?, &current) < 1e-12);
+        assert!(max_diff(&gram(&qr.q)?, &Matrix::identity(m)) < 1e-12);
+        for i in 0..m {
+            for j in 0..i.min(n) {
+                assert_eq!(qr.r.get(i, j)?, 0.0);
+            }
+        }
+
+        let b: Vec<f64> = (0..m)
+            .map(|i| {
+                let row = &current.as_slice()[i * n..(i + 1) * n];
+                row.iter().zip(&truth).map(|(p, q)| p * q).sum::<f64>() + 1e-3 * rng.next_gaussian()
+            })
+            .collect();
+        let x_updated = qr.solve_least_squares(&b)?;
+        let x_fresh = householder_qr(&current)?.solve_least_squares(&b)?;
+        let diff = x_updated
+            .iter()
+            .zip(&x_fresh)
+            .fold(0.0_f64, |d, (p, q)| d.max((p - q).abs()));
+        assert!(diff < 1e-10);
+    }
+    println!(
+        "QR after 20 row insertions/deletions: {} × {}",
+        qr.r.rows(),
+        qr.r.cols()
+    );
+
+    // 删除到行数少于列数时，最小二乘解不再唯一
+    let mut small = householder_qr(&random(3, 2, &mut rng))?;
+    small.delete_row(0)?;
+    small.delete_row(0)?;
+    assert!(small.solve_least_squares(&[1.0]).is_err());
+
+    Ok(())
+}
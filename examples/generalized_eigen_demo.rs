@@ -0,0 +1,137 @@
+use lin_alg_lab::generalized_eigen::{
+    generalized_eigenvalues, generalized_singular_values, qz, symmetric_definite_eigen,
+};
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul, matvec};
+use lin_alg_lab::svd::svd;
+
+fn max_abs(a: &Matrix) -> f64 {
+    a.as_slice().iter().fold(0.0, |m, x| m.max(x.abs()))
+}
+
+fn add(a: &Matrix, b: &Matrix, alpha: f64) -> Result<Matrix, MatrixError> {
+    let data = a
+        .as_slice()
+        .iter()
+        .zip(b.as_slice())
+        .map(|(x, y)| x + alpha * y)
+        .collect();
+    Matrix::new(a.rows(), a.cols(), data)
+}
+
+fn main() -> Result<(), MatrixError> {
+    // 1. 对称-正定：弹簧-质量系统 K x = ω² M x
+    let k = Matrix::new(
+        3,
+        3,
+        vec![
+            2.0, -1.0, 0.0, //
+            -1.0, 2.0, -1.0, //
+            0.0, -1.0, 1.0,
+        ],
+    )?;
+    let m = Matrix::new(3, 3, vec![2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.5])?;
+    let (omega2, modes) = symmetric_definite_eigen(&k, &m)?;
+    println!("ω² = {:?}", omega2);
+    for (j, lambda) in omega2.iter().enumerate() {
+        let x = modes.col(j)?;
+        let kx = matvec(&k, &x)?;
+        let mx = matvec(&m, &x)?;
+        let res = kx
+            .iter()
+            .zip(&mx)
+            .fold(0.0_f64, |r, (a, b)| r.max((a - lambda * b).abs()));
+        assert!(res < 1e-12);
+    }
+    assert!(omega2.windows(2).all(|w| w[0] <= w[1]));
+    // 振型关于 M 正交归一：Xᵀ M X = I
+    let xtmx = matmul(&matmul(&modes.transpose()?, &m)?, &modes)?;
+    assert!(max_abs(&add(&xtmx, &Matrix::identity(3), -1.0)?) < 1e-12);
+
+    // M 不正定时报错
+    let not_pd = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 1.0])?;
+    assert!(matches!(
+        symmetric_definite_eigen(&Matrix::identity(2), &not_pd),
+        Err(MatrixError::NotPositiveDefinite)
+    ));
+
+    // 2. QZ：一般矩阵对，A = Q S Zᵀ，B = Q T Zᵀ
+    let a = Matrix::new(
+        4,
+        4,
+        vec![
+            1.0, 2.0, 0.0, 3.0, //
+            -2.0, 1.0, 1.0, 0.0, //
+            0.5, 0.0, 3.0, 1.0, //
+            1.0, 1.0, -1.0, 2.0,
+        ],
+    )?;
+    let b = Matrix::new(
+        4,
+        4,
+        vec![
+            2.0, 1.0, 0.0, 0.0, //
+            0.0, 1.0, 0.5, 0.0, //
+            1.0, 0.0, 3.0, 1.0, //
+            0.0, 0.0, 1.0, 1.0,
+        ],
+    )?;
+    let schur = qz(&a, &b)?;
+    let a_back = matmul(&matmul(&schur.q, &schur.s)?, &schur.z.transpose()?)?;
+    let b_back = matmul(&matmul(&schur.q, &schur.t)?, &schur.z.transpose()?)?;
+    assert!(max_abs(&add(&a_back, &a, -1.0)?) < 1e-12);
+    assert!(max_abs(&add(&b_back, &b, -1.0)?) < 1e-12);
+    for i in 0..4 {
+        for j in 0..i {
+            assert_eq!(schur.t.get(i, j)?, 0.0);
+        }
+    }
+
+    // 每个广义特征值都满足 det(A - λB) ≈ 0：用 A - λB 的最小奇异值检查（只查实特征值）
+    let values = generalized_eigenvalues(&a, &b)?;
+    println!("λ(A, B) = {:?}", values);
+    assert_eq!(values.len(), 4);
+    for v in &values {
+        let (re, im) = v.value().expect("B 非奇异，特征值都有限");
+        if im == 0.0 {
+            let shifted = add(&a, &b, -re)?;
+            let sigma = svd(&shifted)?.singular_values;
+            assert!(sigma[3] < 1e-10 * sigma[0]);
+        }
+    }
+
+    // 3. B 奇异：出现无穷特征值
+    let a2 = Matrix::new(3, 3, vec![1.0, 2.0, 0.0, 0.0, 3.0, 1.0, 1.0, 0.0, 2.0])?;
+    let b2 = Matrix::new(3, 3, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0])?;
+    let values = generalized_eigenvalues(&a2, &b2)?;
+    println!("λ(A2, B2) = {:?}", values);
+    assert_eq!(values.iter().filter(|v| v.is_infinite()).count(), 1);
+
+    // 4. 复共轭特征值对：旋转矩阵与单位阵，λ = cos θ ± i sin θ
+    let theta: f64 = 0.7;
+    let rot = Matrix::new(
+        2,
+        2,
+        vec![theta.cos(), -theta.sin(), theta.sin(), theta.cos()],
+    )?;
+    let values = generalized_eigenvalues(&rot, &Matrix::identity(2))?;
+    for v in &values {
+        let (re, im) = v.value().unwrap();
+        assert!((re - theta.cos()).abs() < 1e-12);
+        assert!((im.abs() - theta.sin()).abs() < 1e-12);
+    }
+
+    // 5. 奇异矩阵束：A、B 有公共零空间
+    let zero_col = Matrix::new(2, 2, vec![1.0, 0.0, 2.0, 0.0])?;
+    assert!(matches!(
+        generalized_eigenvalues(&zero_col, &zero_col),
+        Err(MatrixError::Singular)
+    ));
+
+    // 6. 广义奇异值：B = I 时退化为普通奇异值
+    let c = Matrix::new(3, 2, vec![3.0, 0.0, 0.0, 2.0, 0.0, 0.0])?;
+    let gsv = generalized_singular_values(&c, &Matrix::identity(2))?;
+    println!("generalized singular values = {:?}", gsv);
+    assert!((gsv[0] - 2.0).abs() < 1e-12 && (gsv[1] - 3.0).abs() < 1e-12);
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+use lin_alg_lab::assert_approx_eq;
+use lin_alg_lab::primitives::{
+    Line2, LineIntersection, Orientation, Ray2, Segment2, SegmentIntersection, cross_diff,
+    orient2d, orientation,
+};
+use lin_alg_lab::random::Rng;
+use lin_alg_lab::vector::Vector2;
+
+/// 用 i128 精确计算 (b - a) × (c - a) 的符号；坐标是 n · 2^-scale 形式的整数 n。
+fn exact_orient(a: (i128, i128), b: (i128, i128), c: (i128, i128)) -> i32 {
+    let det = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+    det.signum() as i32
+}
+
+fn sign(x: f64) -> i32 {
+    if x > 0.0 {
+        1
+    } else if x < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+fn to_point(p: (i128, i128), scale: f64) -> Vector2 {
+    Vector2::new(p.0 as f64 * scale, p.1 as f64 * scale)
+}
+
+fn v(x: f64, y: f64) -> Vector2 {
+    Vector2::new(x, y)
+}
+
+fn main() {
+    // 基本转向
+    let o = v(0.0, 0.0);
+    assert_eq!(
+        orientation(&o, &v(1.0, 0.0), &v(0.0, 1.0)),
+        Orientation::CounterClockwise
+    );
+    assert_eq!(
+        orientation(&o, &v(0.0, 1.0), &v(1.0, 0.0)),
+        Orientation::Clockwise
+    );
+    assert_eq!(
+        orientation(&o, &v(1.0, 1.0), &v(3.0, 3.0)),
+        Orientation::Collinear
+    );
+    assert_approx_eq!(orient2d(&o, &v(2.0, 0.0), &v(0.0, 3.0)), 6.0);
+
+    // 经典反例（Kettner 等）：在 (0.5, 0.5) 附近按 ulp 扫一个网格，
+    // 判断它相对直线 (12, 12)–(24, 24) 的方向。朴素公式的结果在网格上乱成一片，
+    // 自适应谓词必须与精确的整数运算完全一致。
+    let ulp = f64::EPSILON / 2.0; // 0.5 附近一个 ulp 为 2^-53
+    let q = v(12.0, 12.0);
+    let r = v(24.0, 24.0);
+    let unit = 2f64.powi(53);
+    let qi = (12 * (1i128 << 53), 12 * (1i128 << 53));
+    let ri = (24 * (1i128 << 53), 24 * (1i128 << 53));
+    let (mut naive_wrong, mut robust_wrong) = (0, 0);
+    for i in 0..64 {
+        for j in 0..64 {
+            let p = v(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+            let pi = ((1i128 << 52) + i, (1i128 << 52) + j);
+            assert_eq!(p.x() * unit, pi.0 as f64);
+            let exact = exact_orient(pi, qi, ri);
+            let naive = (q.x() - p.x()) * (r.y() - p.y()) - (q.y() - p.y()) * (r.x() - p.x());
+            naive_wrong += (sign(naive) != exact) as usize;
+            robust_wrong += (sign(orient2d(&p, &q, &r)) != exact) as usize;
+        }
+    }
+    println!("ulp 网格 64 × 64：朴素公式错 {naive_wrong} 次，orient2d 错 {robust_wrong} 次");
+    assert!(naive_wrong > 0);
+    assert_eq!(robust_wrong, 0);
+
+    // 随机的近退化三点：c 恰好在直线 ab 上，再在整数格上扰动 0 或 ±1 个单位
+    let mut rng = Rng::new(48);
+    let scale = 2f64.powi(-30);
+    let rand_coord = |rng: &mut Rng| (rng.next_u64() >> 14) as i128 - (1i128 << 49);
+    for _ in 0..2000 {
+        let a = (rand_coord(&mut rng), rand_coord(&mut rng));
+        let b = (rand_coord(&mut rng), rand_coord(&mut rng));
+        let k = (rng.next_u64() % 7) as i128 - 3;
+        let dx = (rng.next_u64() % 3) as i128 - 1;
+        let dy = (rng.next_u64() % 3) as i128 - 1;
+        let c = (a.0 + k * (b.0 - a.0) + dx, a.1 + k * (b.1 - a.1) + dy);
+        let (pa, pb, pc) = (to_point(a, scale), to_point(b, scale), to_point(c, scale));
+        let exact = exact_orient(a, b, c);
+        assert_eq!(sign(orient2d(&pa, &pb, &pc)), exact);
+        // 轮换不变、交换变号
+        assert_eq!(sign(orient2d(&pb, &pc, &pa)), exact);
+        assert_eq!(sign(orient2d(&pb, &pa, &pc)), -exact);
+        // cross_diff 的一般形式：(b - a) × (d - c)
+        assert_eq!(sign(cross_diff(&pa, &pb, &pa, &pc)), exact);
+    }
+
+    // 直线：侧向、距离、投影、交点
+    let l = Line2::through(v(0.0, 0.0), v(4.0, 0.0)).unwrap();
+    assert_eq!(l.side(&v(1.0, 2.0)), Orientation::CounterClockwise);
+    assert_eq!(l.side(&v(1.0, -2.0)), Orientation::Clockwise);
+    assert_eq!(l.side(&v(-7.0, 0.0)), Orientation::Collinear);
+    assert_approx_eq!(l.signed_distance(&v(3.0, -2.5)), -2.5);
+    assert_approx_eq!(l.distance_to_point(&v(3.0, -2.5)), 2.5);
+    assert_approx_eq!(l.closest_point(&v(3.0, -2.5)), v(3.0, 0.0));
+    assert!(Line2::through(v(1.0, 1.0), v(1.0, 1.0)).is_none());
+    assert!(Line2::new(v(1.0, 1.0), v(0.0, 0.0)).is_none());
+
+    let diag = Line2::new(v(0.0, -1.0), v(1.0, 1.0)).unwrap();
+    match l.intersect(&diag) {
+        LineIntersection::Point(p) => assert_approx_eq!(p, v(1.0, 0.0)),
+        other => panic!("应交于一点，得到 {other:?}"),
+    }
+    let parallel = Line2::new(v(0.0, 1e-300), v(3.0, 0.0)).unwrap();
+    assert!(matches!(l.intersect(&parallel), LineIntersection::Parallel));
+    let same = Line2::through(v(10.0, 0.0), v(-3.0, 0.0)).unwrap();
+    assert!(matches!(l.intersect(&same), LineIntersection::Coincident));
+    // 几乎平行但不平行：仍然给出交点
+    let almost = Line2::through(v(0.0, 1.0), v(1.0, 1.0 - 1e-12)).unwrap();
+    match l.intersect(&almost) {
+        LineIntersection::Point(p) => assert_approx_eq!(p, v(1e12, 0.0), rel = 1e-3),
+        other => panic!("应交于一点，得到 {other:?}"),
+    }
+
+    // 线段：最近点与距离（投影落在内部 / 端点之外 / 退化线段）
+    let s = Segment2::new(v(0.0, 0.0), v(4.0, 0.0));
+    assert_approx_eq!(s.length(), 4.0);
+    assert_approx_eq!(s.midpoint(), v(2.0, 0.0));
+    assert_approx_eq!(s.closest_point(&v(1.0, 3.0)), v(1.0, 0.0));
+    assert_approx_eq!(s.distance_to_point(&v(1.0, 3.0)), 3.0);
+    assert_approx_eq!(s.closest_point(&v(7.0, 4.0)), v(4.0, 0.0));
+    assert_approx_eq!(s.distance_to_point(&v(7.0, 4.0)), 5.0);
+    assert_approx_eq!(s.distance_to_point(&v(-3.0, 0.0)), 3.0);
+    let dot_seg = Segment2::new(v(1.0, 1.0), v(1.0, 1.0));
+    assert_approx_eq!(dot_seg.distance_to_point(&v(4.0, 5.0)), 5.0);
+    assert!(dot_seg.line().is_none());
+    assert!(s.contains(&v(4.0, 0.0)));
+    assert!(s.contains(&v(2.5, 0.0)));
+    assert!(!s.contains(&v(4.000000000000001, 0.0)));
+    assert!(!s.contains(&v(2.0, 1e-300)));
+
+    // 线段相交：一般位置
+    let cross_seg = Segment2::new(v(1.0, -1.0), v(3.0, 1.0));
+    match s.intersect(&cross_seg) {
+        SegmentIntersection::Point(p) => assert_approx_eq!(p, v(2.0, 0.0)),
+        other => panic!("{other:?}"),
+    }
+    // 端点相接与 T 形相交：交点就是输入的端点，没有舍入
+    let touching = Segment2::new(v(4.0, 0.0), v(6.0, 5.0));
+    assert!(
+        matches!(s.intersect(&touching), SegmentIntersection::Point(p) if p.x() == 4.0 && p.y() == 0.0)
+    );
+    let t_junction = Segment2::new(v(0.3, 0.0), v(0.3, 2.0));
+    assert!(
+        matches!(s.intersect(&t_junction), SegmentIntersection::Point(p) if p.x() == 0.3 && p.y() == 0.0)
+    );
+    // 不相交：直线相交但交点在线段之外；平行
+    assert!(!s.intersects(&Segment2::new(v(5.0, -1.0), v(6.0, 1.0))));
+    assert!(!s.intersects(&Segment2::new(v(0.0, 1.0), v(4.0, 1.0))));
+
+    // 共线：重叠、包含、只接触一个端点、分离、完全相同
+    let expect_overlap = |other: Segment2, lo: Vector2, hi: Vector2| match s.intersect(&other) {
+        SegmentIntersection::Overlap(o) => {
+            assert_approx_eq!(o.a(), lo);
+            assert_approx_eq!(o.b(), hi);
+        }
+        res => panic!("应为重叠，得到 {res:?}"),
+    };
+    expect_overlap(
+        Segment2::new(v(6.0, 0.0), v(2.0, 0.0)),
+        v(2.0, 0.0),
+        v(4.0, 0.0),
+    );
+    expect_overlap(
+        Segment2::new(v(1.0, 0.0), v(3.0, 0.0)),
+        v(1.0, 0.0),
+        v(3.0, 0.0),
+    );
+    expect_overlap(s, v(0.0, 0.0), v(4.0, 0.0));
+    expect_overlap(
+        Segment2::new(v(4.0, 0.0), v(0.0, 0.0)),
+        v(0.0, 0.0),
+        v(4.0, 0.0),
+    );
+    assert!(matches!(
+        s.intersect(&Segment2::new(v(4.0, 0.0), v(9.0, 0.0))),
+        SegmentIntersection::Point(p) if p.x() == 4.0
+    ));
+    assert!(matches!(
+        s.intersect(&Segment2::new(v(5.0, 0.0), v(9.0, 0.0))),
+        SegmentIntersection::None
+    ));
+    // 斜线上的共线重叠（字典序在竖直线段上也成立）。坐标取二进制小数才真正共线：
+    // 换成 (0.1, 0.3) 这样的十进制小数，舍入后的点并不共线，精确谓词也会如实报告
+    let d1 = Segment2::new(v(0.125, 0.375), v(0.625, 1.875));
+    let d2 = Segment2::new(v(0.375, 1.125), v(1.0, 3.0));
+    assert_ne!(
+        orientation(&v(0.1, 0.3), &v(0.7, 2.1), &v(0.4, 1.2)),
+        Orientation::Collinear
+    );
+    assert_eq!(
+        orientation(&d1.a(), &d1.b(), &d2.a()),
+        Orientation::Collinear
+    );
+    assert!(matches!(d1.intersect(&d2), SegmentIntersection::Overlap(_)));
+    let vert = Segment2::new(v(1.0, 5.0), v(1.0, -5.0));
+    assert!(matches!(
+        vert.intersect(&Segment2::new(v(1.0, 0.0), v(1.0, 9.0))),
+        SegmentIntersection::Overlap(o) if o.a().y() == 0.0 && o.b().y() == 5.0
+    ));
+
+    // 退化线段
+    assert!(matches!(
+        s.intersect(&Segment2::new(v(2.0, 0.0), v(2.0, 0.0))),
+        SegmentIntersection::Point(_)
+    ));
+    assert!(!s.intersects(&Segment2::new(v(2.0, 0.5), v(2.0, 0.5))));
+    assert!(!dot_seg.intersects(&Segment2::new(v(2.0, 2.0), v(2.0, 2.0))));
+    assert!(dot_seg.intersects(&dot_seg));
+
+    // 射线
+    let ray = Ray2::new(v(-1.0, 1.0), v(1.0, -0.5)).unwrap();
+    assert!(Ray2::new(v(0.0, 0.0), v(0.0, 0.0)).is_none());
+    assert_approx_eq!(ray.point_at(2.0), v(1.0, 0.0));
+    assert_approx_eq!(ray.intersect_segment(&s).unwrap(), v(1.0, 0.0));
+    // 背对线段
+    let away = Ray2::new(v(-1.0, 1.0), v(-1.0, 0.5)).unwrap();
+    assert!(away.intersect_segment(&s).is_none());
+    // 最近点：投影在起点之后时取起点
+    assert_approx_eq!(away.closest_point(&v(5.0, 1.0)), v(-1.0, 1.0));
+    assert_approx_eq!(away.distance_to_point(&v(2.0, 5.0)), 5.0);
+    // 共线射线：从线段外射入，碰到近端点；从线段内部出发，交点是起点
+    let along = Ray2::new(v(-3.0, 0.0), v(1.0, 0.0)).unwrap();
+    assert_approx_eq!(along.intersect_segment(&s).unwrap(), v(0.0, 0.0));
+    let inside = Ray2::new(v(2.0, 0.0), v(-1.0, 0.0)).unwrap();
+    assert_approx_eq!(inside.intersect_segment(&s).unwrap(), v(2.0, 0.0));
+    let behind = Ray2::new(v(5.0, 0.0), v(1.0, 0.0)).unwrap();
+    assert!(behind.intersect_segment(&s).is_none());
+    // 起点在线段所在直线上、从侧面穿过
+    let from_line = Ray2::new(v(3.0, 0.0), v(0.0, 1.0)).unwrap();
+    assert_approx_eq!(from_line.intersect_segment(&s).unwrap(), v(3.0, 0.0));
+    // 擦过线段端点
+    let graze = Ray2::new(v(0.0, 2.0), v(2.0, -1.0)).unwrap();
+    assert_approx_eq!(graze.intersect_segment(&s).unwrap(), v(4.0, 0.0));
+
+    println!("geometry demo 全部断言通过");
+}
@@ -0,0 +1,80 @@
+use lin_alg_lab::gram_schmidt::{classical_gram_schmidt, modified_gram_schmidt, orthonormal_basis};
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul};
+use lin_alg_lab::vector::project_onto_columns;
+
+/// max |QᵀQ - I|，衡量 Q 的列偏离正交的程度
+fn orthogonality_loss(q: &Matrix) -> Result<f64, MatrixError> {
+    let qtq = matmul(&q.transpose()?, q)?;
+    let mut worst: f64 = 0.0;
+    for i in 0..qtq.rows() {
+        for j in 0..qtq.cols() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            worst = worst.max((qtq.get(i, j)? - expected).abs());
+        }
+    }
+    Ok(worst)
+}
+
+fn main() -> Result<(), MatrixError> {
+    // 8×6 的 Hilbert 型矩阵：列向量几乎线性相关，很能暴露 CGS 的问题
+    let (m, n) = (8, 6);
+    let mut data = Vec::with_capacity(m * n);
+    for i in 0..m {
+        for j in 0..n {
+            data.push(1.0 / (i + j + 1) as f64);
+        }
+    }
+    let a = Matrix::new(m, n, data)?;
+
+    let (q_cgs, _) = classical_gram_schmidt(&a, false)?;
+    let (q_mgs, _) = modified_gram_schmidt(&a, false)?;
+    let (q_re, r_re) = modified_gram_schmidt(&a, true)?;
+    let (q_cgs2, _) = classical_gram_schmidt(&a, true)?;
+
+    let loss_cgs = orthogonality_loss(&q_cgs)?;
+    let loss_mgs = orthogonality_loss(&q_mgs)?;
+    let loss_re = orthogonality_loss(&q_re)?;
+    let loss_cgs2 = orthogonality_loss(&q_cgs2)?;
+    println!("CGS       loss = {:e}", loss_cgs);
+    println!("MGS       loss = {:e}", loss_mgs);
+    println!("CGS + re  loss = {:e}", loss_cgs2);
+    println!("MGS + re  loss = {:e}", loss_re);
+    assert!(loss_mgs < loss_cgs);
+    assert!(loss_re < 1e-13);
+    assert!(loss_cgs2 < 1e-13);
+
+    // A = Q R，且 R 是上三角
+    let qr = matmul(&q_re, &r_re)?;
+    for i in 0..m {
+        for j in 0..n {
+            assert!((qr.get(i, j)? - a.get(i, j)?).abs() < 1e-12);
+        }
+    }
+    for i in 0..n {
+        for j in 0..i {
+            assert_eq!(r_re.get(i, j)?, 0.0);
+        }
+    }
+
+    // 列线性相关时：QR 报错，但 orthonormal_basis 会跳过相关列
+    // 第三列 = 第一列 + 第二列
+    let dep = Matrix::new(3, 3, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 0.0, 1.0, 1.0])?;
+    assert!(matches!(
+        modified_gram_schmidt(&dep, true),
+        Err(MatrixError::Singular)
+    ));
+    let basis = orthonormal_basis(&dep, 1e-10)?;
+    println!("basis of dependent columns = {:?}", basis);
+    assert_eq!(basis.cols(), 2);
+
+    // 投影到子空间：xy 平面（由两列张成，其中还带一列冗余）
+    let plane = Matrix::new(3, 3, vec![1.0, 1.0, 2.0, 1.0, -1.0, 0.0, 0.0, 0.0, 0.0])?;
+    let p = project_onto_columns(&[3.0, -2.0, 5.0], &plane)?;
+    println!("projection onto xy-plane = {:?}", p);
+    assert!((p[0] - 3.0).abs() < 1e-12);
+    assert!((p[1] + 2.0).abs() < 1e-12);
+    assert!(p[2].abs() < 1e-12);
+
+    println!("Gram-Schmidt demo passed.");
+    Ok(())
+}
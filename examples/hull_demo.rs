@@ -0,0 +1,292 @@
+use lin_alg_lab::assert_approx_eq;
+use lin_alg_lab::hull::{
+    Aabb, Circle, convex_hull, diameter, farthest_pair, min_area_bounding_box,
+    min_enclosing_circle, width,
+};
+use lin_alg_lab::primitives::{Orientation, orient2d};
+use lin_alg_lab::random::Rng;
+use lin_alg_lab::vector::{Vector2, distance, dot};
+
+fn v(x: f64, y: f64) -> Vector2 {
+    Vector2::new(x, y)
+}
+
+fn same(a: &Vector2, b: &Vector2) -> bool {
+    a.x() == b.x() && a.y() == b.y()
+}
+
+/// 暴力求最小外接圆：枚举所有两点直径圆和三点外接圆，取包住全部点的最小者。
+fn brute_force_circle(points: &[Vector2]) -> f64 {
+    let encloses = |c: &Vector2, r: f64| points.iter().all(|p| distance(c, p) <= r * (1.0 + 1e-9));
+    let mut best = f64::INFINITY;
+    let n = points.len();
+    for i in 0..n {
+        for j in i + 1..n {
+            let c = (points[i] + points[j]) * 0.5;
+            let r = distance(&points[i], &points[j]) / 2.0;
+            if r < best && encloses(&c, r) {
+                best = r;
+            }
+            for k in j + 1..n {
+                let (a, b, cc) = (points[i], points[j] - points[i], points[k] - points[i]);
+                let d = 2.0 * (b.x() * cc.y() - b.y() * cc.x());
+                if d.abs() < 1e-12 {
+                    continue;
+                }
+                let (bb, ccl) = (b.length_sq(), cc.length_sq());
+                let u = v(
+                    (cc.y() * bb - b.y() * ccl) / d,
+                    (b.x() * ccl - cc.x() * bb) / d,
+                );
+                let r = u.length_sq().sqrt();
+                if r < best && encloses(&(a + u), r) {
+                    best = r;
+                }
+            }
+        }
+    }
+    best
+}
+
+/// 暴力求最小面积有向包围盒：对凸包的每条边方向投影全部点。
+fn brute_force_box_area(points: &[Vector2]) -> f64 {
+    let hull = convex_hull(points);
+    hull.edges()
+        .filter_map(|e| (e.b() - e.a()).normalize())
+        .map(|u| {
+            let w = v(-u.y(), u.x());
+            let (mut lo, mut hi, mut bot, mut top) = (f64::MAX, f64::MIN, f64::MAX, f64::MIN);
+            for p in points {
+                lo = lo.min(dot(p, &u));
+                hi = hi.max(dot(p, &u));
+                bot = bot.min(dot(p, &w));
+                top = top.max(dot(p, &w));
+            }
+            (hi - lo) * (top - bot)
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn brute_force_diameter(points: &[Vector2]) -> f64 {
+    let mut best: f64 = 0.0;
+    for a in points {
+        for b in points {
+            best = best.max(distance(a, b));
+        }
+    }
+    best
+}
+
+/// 暴力求宽度：每条凸包边到最远点的距离取最小。
+fn brute_force_width(points: &[Vector2]) -> f64 {
+    let hull = convex_hull(points);
+    hull.edges()
+        .map(|e| {
+            points
+                .iter()
+                .map(|p| orient2d(&e.a(), &e.b(), p) / e.length())
+                .fold(0.0, f64::max)
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn random_cloud(rng: &mut Rng, n: usize) -> Vec<Vector2> {
+    (0..n)
+        .map(|_| v(rng.next_gaussian() * 3.0, rng.next_gaussian()))
+        .collect()
+}
+
+fn main() {
+    let mut rng = Rng::new(50);
+
+    // 凸包：正方形网格（含边上的共线点、内部点和重复点）只剩四个角，逆时针
+    let mut grid = Vec::new();
+    for i in 0..=4 {
+        for j in 0..=4 {
+            grid.push(v(i as f64, j as f64));
+        }
+    }
+    grid.extend_from_slice(&[v(0.0, 0.0), v(4.0, 4.0), v(2.0, 2.0)]);
+    let hull = convex_hull(&grid);
+    let expected = [v(0.0, 0.0), v(4.0, 0.0), v(4.0, 4.0), v(0.0, 4.0)];
+    assert_eq!(hull.len(), 4);
+    for (p, q) in hull.vertices().iter().zip(&expected) {
+        assert!(same(p, q), "{p:?} != {q:?}");
+    }
+    assert_eq!(hull.orientation(), Orientation::CounterClockwise);
+    assert_approx_eq!(hull.area(), 16.0);
+
+    // 退化：空、单点（多次重复）、全部共线（乱序 + 重复）
+    assert!(convex_hull(&[]).is_empty());
+    let single = convex_hull(&[v(1.5, -2.0); 5]);
+    assert_eq!(single.len(), 1);
+    let line: Vec<Vector2> = [3.0, -1.0, 2.0, 0.5, 3.0, -1.0, 1.0]
+        .iter()
+        .map(|&t| v(t, 2.0 * t + 1.0))
+        .collect();
+    let seg = convex_hull(&line);
+    assert_eq!(seg.len(), 2);
+    assert!(same(&seg.vertices()[0], &v(-1.0, -1.0)));
+    assert!(same(&seg.vertices()[1], &v(3.0, 7.0)));
+    // 共线但不在网格上的点：x 坐标互不相同，y 与 x 成精确比例
+    let diag: Vec<Vector2> = (0..50)
+        .map(|k| v(k as f64 * 0.25, k as f64 * 0.75))
+        .collect();
+    assert_eq!(convex_hull(&diag).len(), 2);
+
+    // 近乎共线的点：朴素判断容易把凹点留下，精确谓词保证结果严格凸
+    let eps = f64::EPSILON;
+    let near = [
+        v(0.0, 0.0),
+        v(1.0, 1.0),
+        v(0.5, 0.5 + eps / 4.0),
+        v(0.5, 0.5 - eps / 4.0),
+        v(0.25, 0.25),
+        v(2.0, 0.0),
+    ];
+    let h = convex_hull(&near);
+    assert!(h.is_convex());
+    for p in &near {
+        assert!(h.contains_nonzero(p));
+    }
+
+    // 随机点集：凸包是凸的、顶点取自输入、所有点都在凸包内
+    for n in [3, 10, 100, 1000] {
+        let pts = random_cloud(&mut rng, n);
+        let h = convex_hull(&pts);
+        assert!(h.is_convex());
+        assert!(h.vertices().iter().all(|q| pts.iter().any(|p| same(p, q))));
+        assert!(pts.iter().all(|p| h.contains_nonzero(p)));
+    }
+
+    // 最小外接圆
+    assert!(min_enclosing_circle(&[], &mut rng).is_none());
+    let c = min_enclosing_circle(&[v(2.0, 3.0); 4], &mut rng).unwrap();
+    assert_eq!(c.radius(), 0.0);
+    let c = min_enclosing_circle(&[v(0.0, 0.0), v(4.0, 0.0)], &mut rng).unwrap();
+    assert_approx_eq!(c.center(), v(2.0, 0.0));
+    assert_approx_eq!(c.radius(), 2.0);
+    // 共线点：以两端为直径
+    let c = min_enclosing_circle(&line, &mut rng).unwrap();
+    assert_approx_eq!(c.center(), v(1.0, 3.0));
+    assert_approx_eq!(c.radius(), 20f64.sqrt());
+    // 等边三角形：外接圆；钝角三角形：最长边为直径
+    let s3 = 3f64.sqrt();
+    let c = min_enclosing_circle(&[v(0.0, 0.0), v(2.0, 0.0), v(1.0, s3)], &mut rng).unwrap();
+    assert_approx_eq!(c.radius(), 2.0 / s3);
+    assert_approx_eq!(c.center(), v(1.0, 1.0 / s3));
+    let c = min_enclosing_circle(&[v(0.0, 0.0), v(4.0, 0.0), v(2.0, 0.5)], &mut rng).unwrap();
+    assert_approx_eq!(c.radius(), 2.0);
+    // 圆上的点（含重复）：外接圆就是这个圆
+    let on_circle: Vec<Vector2> = (0..40)
+        .map(|k| {
+            let t = (k % 20) as f64 * 0.3;
+            v(5.0 + 2.0 * t.cos(), -1.0 + 2.0 * t.sin())
+        })
+        .collect();
+    let c = min_enclosing_circle(&on_circle, &mut rng).unwrap();
+    assert_approx_eq!(c.radius(), 2.0, rel = 1e-12);
+    assert_approx_eq!(c.center(), v(5.0, -1.0), abs = 1e-12);
+    // 随机点集与暴力枚举对比；不同种子给出同一个圆
+    for _ in 0..20 {
+        let pts = random_cloud(&mut rng, 25);
+        let c = min_enclosing_circle(&pts, &mut rng).unwrap();
+        assert!(pts.iter().all(|p| c.contains(p)));
+        assert_approx_eq!(c.radius(), brute_force_circle(&pts), rel = 1e-9);
+        let c2 = min_enclosing_circle(&pts, &mut Rng::new(7)).unwrap();
+        assert_approx_eq!(c2.radius(), c.radius(), rel = 1e-12);
+    }
+    let big = random_cloud(&mut rng, 20000);
+    let c: Circle = min_enclosing_circle(&big, &mut rng).unwrap();
+    assert!(big.iter().all(|p| c.contains(p)));
+    println!(
+        "20000 个点的最小外接圆：圆心 {:?}，半径 {:.6}",
+        c.center(),
+        c.radius()
+    );
+
+    // 轴对齐包围盒
+    assert!(Aabb::from_points(&[]).is_none());
+    let aabb = Aabb::from_points(&grid).unwrap();
+    assert_approx_eq!(aabb.area(), 16.0);
+    assert_approx_eq!(aabb.center(), v(2.0, 2.0));
+    assert!(grid.iter().all(|p| aabb.contains(p)));
+    let flat = Aabb::from_points(&line).unwrap();
+    assert_approx_eq!(flat.width(), 4.0);
+    assert_approx_eq!(flat.height(), 8.0);
+
+    // 有向包围盒：旋转 30° 的 3 × 1 矩形（边上和内部再加些点），应当恰好找回这个矩形
+    let (s, co) = (0.5f64, 0.75f64.sqrt());
+    let rot = |x: f64, y: f64| v(10.0 + co * x - s * y, -3.0 + s * x + co * y);
+    let mut rect_pts = vec![rot(0.0, 0.0), rot(3.0, 0.0), rot(3.0, 1.0), rot(0.0, 1.0)];
+    for _ in 0..50 {
+        rect_pts.push(rot(rng.next_f64() * 3.0, rng.next_f64()));
+    }
+    rect_pts.push(rot(1.5, 0.0));
+    let obb = min_area_bounding_box(&rect_pts).unwrap();
+    assert_approx_eq!(obb.area(), 3.0, rel = 1e-12);
+    let mut ext = obb.half_extents();
+    ext.sort_by(f64::total_cmp);
+    assert_approx_eq!(ext[0], 0.5, rel = 1e-12);
+    assert_approx_eq!(ext[1], 1.5, rel = 1e-12);
+    assert_approx_eq!(obb.center(), rot(1.5, 0.5), abs = 1e-12);
+    assert_approx_eq!(dot(&obb.axes()[0], &obb.axes()[1]), 0.0);
+    assert!(obb.area() < Aabb::from_points(&rect_pts).unwrap().area());
+    // 随机点集：与逐边暴力投影一致，且盒子包住所有点
+    for n in [3, 7, 50, 500] {
+        let pts = random_cloud(&mut rng, n);
+        let obb = min_area_bounding_box(&pts).unwrap();
+        assert_approx_eq!(obb.area(), brute_force_box_area(&pts), rel = 1e-10);
+        assert!(obb.area() <= Aabb::from_points(&pts).unwrap().area() * (1.0 + 1e-12));
+        let poly = obb.to_polygon();
+        assert_approx_eq!(poly.area(), obb.area(), rel = 1e-10);
+        for p in &pts {
+            let d = *p - obb.center();
+            for (axis, half) in obb.axes().iter().zip(obb.half_extents()) {
+                assert!(dot(&d, axis).abs() <= half + 1e-9);
+            }
+        }
+    }
+    // 退化：空、单点、共线（面积 0，长度等于两端距离）
+    assert!(min_area_bounding_box(&[]).is_none());
+    let point_box = min_area_bounding_box(&[v(1.0, 1.0); 3]).unwrap();
+    assert_eq!(point_box.area(), 0.0);
+    assert_approx_eq!(point_box.center(), v(1.0, 1.0));
+    let line_box = min_area_bounding_box(&line).unwrap();
+    assert_eq!(line_box.area(), 0.0);
+    assert_approx_eq!(line_box.half_extents()[0], 20f64.sqrt());
+    assert_approx_eq!(line_box.center(), v(1.0, 3.0));
+
+    // 直径与宽度
+    assert_approx_eq!(diameter(&grid), 32f64.sqrt());
+    assert_approx_eq!(width(&grid), 4.0);
+    assert_approx_eq!(diameter(&rect_pts), 10f64.sqrt(), rel = 1e-12);
+    assert_approx_eq!(width(&rect_pts), 1.0, rel = 1e-12);
+    let tri = [v(0.0, 0.0), v(2.0, 0.0), v(1.0, s3)];
+    assert_approx_eq!(diameter(&tri), 2.0);
+    assert_approx_eq!(width(&tri), s3);
+    // 退化：共线点宽度为 0，直径为两端距离；单点直径为 0；空输入
+    assert_eq!(width(&line), 0.0);
+    assert_approx_eq!(diameter(&line), 80f64.sqrt());
+    assert_eq!(diameter(&[v(3.0, 3.0); 4]), 0.0);
+    assert_eq!(diameter(&[]), 0.0);
+    assert!(farthest_pair(&[]).is_none());
+    let (a, b) = farthest_pair(&line).unwrap();
+    assert_approx_eq!(distance(&a, &b), 80f64.sqrt());
+    for n in [3, 4, 20, 300] {
+        let pts = random_cloud(&mut rng, n);
+        assert_approx_eq!(diameter(&pts), brute_force_diameter(&pts), rel = 1e-12);
+        assert_approx_eq!(width(&pts), brute_force_width(&pts), rel = 1e-10);
+    }
+    // 正多边形（有平行边）：偶数边时直径是对顶点距离
+    let hexagon: Vec<Vector2> = (0..6)
+        .map(|k| {
+            let t = k as f64 * std::f64::consts::PI / 3.0;
+            v(t.cos(), t.sin())
+        })
+        .collect();
+    assert_approx_eq!(diameter(&hexagon), 2.0);
+    assert_approx_eq!(width(&hexagon), s3);
+
+    println!("hull demo 全部断言通过");
+}
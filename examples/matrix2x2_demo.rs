@@ -0,0 +1,156 @@
+use lin_alg_lab::assert_approx_eq;
+use lin_alg_lab::matrix::{Eigen2, Matrix2x2};
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul};
+use lin_alg_lab::random::Rng;
+use lin_alg_lab::schur::{real_schur, schur_eigenvalues};
+use lin_alg_lab::vector::{Vector2, dot};
+use std::f64::consts::FRAC_PI_3;
+
+fn main() -> Result<(), MatrixError> {
+    let a = Matrix2x2::new(4.0, 1.0, 2.0, 3.0);
+    let b = Matrix2x2::new(-1.0, 0.5, 2.0, 0.0);
+
+    // 算术
+    assert_approx_eq!(a + b, Matrix2x2::new(3.0, 1.5, 4.0, 3.0));
+    assert_approx_eq!(a - b, Matrix2x2::new(5.0, 0.5, 0.0, 3.0));
+    assert_approx_eq!(-a, a * -1.0);
+    assert_approx_eq!(2.0 * a, a + a);
+    assert_approx_eq!(a.transpose(), Matrix2x2::new(4.0, 2.0, 1.0, 3.0));
+    assert_approx_eq!((a * b).transpose(), b.transpose() * a.transpose());
+
+    // 迹、行列式、逆
+    assert_eq!(a.trace(), 7.0);
+    assert_eq!(a.determinant(), 10.0);
+    assert_approx_eq!(a.determinant() * b.determinant(), (a * b).determinant());
+    assert_approx_eq!(a * a.inverse()?, Matrix2x2::identity());
+    assert_approx_eq!(a.inverse()?, Matrix2x2::new(0.3, -0.1, -0.2, 0.4));
+    assert!(matches!(
+        Matrix2x2::new(1.0, 2.0, 2.0, 4.0).inverse(),
+        Err(MatrixError::Singular)
+    ));
+    assert!(matches!(
+        Matrix2x2::new(1e20, 1e20, 1e20, 1e20 + 1.0).inverse(),
+        Err(MatrixError::Singular)
+    ));
+
+    // 构造函数
+    let p = Vector2::new(2.0, 1.0);
+    assert_approx_eq!(Matrix2x2::scaling(2.0, -1.0) * p, Vector2::new(4.0, -1.0));
+    assert_approx_eq!(Matrix2x2::shear(0.5, 0.0) * p, Vector2::new(2.5, 1.0));
+    let m = Matrix2x2::reflection(Vector2::new(0.0, 1.0)).unwrap();
+    assert_approx_eq!(m * p, Vector2::new(-2.0, 1.0));
+    assert_approx_eq!(m.determinant(), -1.0);
+    assert!(Matrix2x2::reflection(Vector2::new(0.0, 0.0)).is_none());
+
+    // 与稠密矩阵互转
+    let dense = Matrix::from(a);
+    assert_approx_eq!(
+        Matrix2x2::try_from(&matmul(&dense, &Matrix::from(b))?)?,
+        a * b
+    );
+    assert!(matches!(
+        Matrix2x2::try_from(&Matrix::identity(3)),
+        Err(MatrixError::DimensionMismatch)
+    ));
+
+    // 实特征值：[4 1; 2 3] 的特征值为 5 和 2
+    match a.eigen() {
+        Eigen2::Real { values, vectors } => {
+            assert_approx_eq!(values.to_vec(), vec![5.0, 2.0]);
+            check_real(&a, values, vectors);
+        }
+        other => panic!("expected real eigenvalues, got {:?}", other),
+    }
+
+    // 复特征值：旋转 60° 的特征值为 e^{±iπ/3}
+    let r = Matrix2x2::rotation(FRAC_PI_3);
+    let [(re, im), (re2, im2)] = r.eigenvalues();
+    assert_approx_eq!(vec![re, im], vec![0.5, 3.0_f64.sqrt() / 2.0]);
+    assert_eq!((re2, im2), (re, -im));
+    check(&r);
+
+    // 亏损矩阵：只有一个特征方向
+    match Matrix2x2::new(1.0, 1.0, 0.0, 1.0).eigen() {
+        Eigen2::Real { values, vectors } => {
+            assert_eq!(values, [1.0, 1.0]);
+            assert_approx_eq!(vectors[0], Vector2::new(1.0, 0.0));
+            assert_approx_eq!(vectors[1], vectors[0]);
+        }
+        other => panic!("expected real eigenvalues, got {:?}", other),
+    }
+    // 数量矩阵：任何方向都是特征向量，返回两条坐标轴
+    match (Matrix2x2::identity() * 3.0).eigen() {
+        Eigen2::Real { values, vectors } => {
+            assert_eq!(values, [3.0, 3.0]);
+            assert_approx_eq!(dot(&vectors[0], &vectors[1]), 0.0);
+        }
+        other => panic!("expected real eigenvalues, got {:?}", other),
+    }
+    // 对角元几乎相等、非对角元极小：tr²/4 - det 的写法在这里会算出 0
+    let close = Matrix2x2::new(1.0, 1e-9, 1e-9, 1.0);
+    let [(l1, _), (l2, _)] = close.eigenvalues();
+    assert_approx_eq!(l1 - l2, 2e-9, rel = 1e-6);
+    // 特征值相差很大时，小的那个也保持相对精度（直接算 tr/2 - √disc 只剩 8 位左右）
+    let stiff = Matrix2x2::new(1e8, 1.0, 1.0, 1.0);
+    let [(big, _), (small, _)] = stiff.eigenvalues();
+    assert_approx_eq!(big, 1e8, rel = 1e-15);
+    assert_approx_eq!(small, 0.99999999, rel = 1e-15);
+
+    // 随机矩阵：与实 Schur 分解给出的特征值一致
+    let mut rng = Rng::new(46);
+    let (mut n_real, mut n_complex) = (0, 0);
+    for _ in 0..500 {
+        let m = Matrix2x2::new(
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+        );
+        check(&m);
+        let (_, t) = real_schur(&Matrix::from(m))?;
+        let mut expected = schur_eigenvalues(&t)?;
+        expected.sort_by(|x, y| y.0.total_cmp(&x.0).then(y.1.total_cmp(&x.1)));
+        let got = m.eigenvalues();
+        for ((er, ei), (gr, gi)) in expected.iter().zip(got) {
+            assert_approx_eq!(vec![*er, *ei], vec![gr, gi], abs = 1e-12);
+        }
+        match m.eigen() {
+            Eigen2::Real { .. } => n_real += 1,
+            Eigen2::Complex { .. } => n_complex += 1,
+        }
+    }
+    println!(
+        "500 random matrices: {} real, {} complex pairs, all match real_schur",
+        n_real, n_complex
+    );
+
+    println!("all Matrix2x2 checks passed");
+    Ok(())
+}
+
+/// 检查 A v = λ v（实数情形）或 A (u + i w) = (re + i im)(u + i w)（复数情形）。
+fn check(m: &Matrix2x2) {
+    match m.eigen() {
+        Eigen2::Real { values, vectors } => check_real(m, values, vectors),
+        Eigen2::Complex {
+            re,
+            im,
+            vector_re: u,
+            vector_im: w,
+        } => {
+            assert!(im > 0.0);
+            assert_approx_eq!(u.length_sq() + w.length_sq(), 1.0);
+            assert_approx_eq!(*m * u, u * re - w * im, abs = 1e-12);
+            assert_approx_eq!(*m * w, u * im + w * re, abs = 1e-12);
+        }
+    }
+}
+
+fn check_real(m: &Matrix2x2, values: [f64; 2], vectors: [Vector2; 2]) {
+    assert!(values[0] >= values[1]);
+    assert_approx_eq!(values[0] + values[1], m.trace(), abs = 1e-12);
+    for (l, v) in values.iter().zip(vectors) {
+        assert_approx_eq!(v.length_sq(), 1.0);
+        assert_approx_eq!(*m * v, v * *l, abs = 1e-12);
+    }
+}
@@ -6,8 +6,7 @@ fn main() {
     println!("data = {:?}", data);
 
     // 1. 直接使用 windows 迭代器
-    let mut iter = windows(&data, 3);
-    while let Some(w) = iter.next() {
+    for w in windows(&data, 3) {
         println!("window: {:?}", w);
     }
 
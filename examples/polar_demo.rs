@@ -0,0 +1,159 @@
+use lin_alg_lab::assert_approx_eq;
+use lin_alg_lab::matrix::Matrix2x2;
+use lin_alg_lab::matrix_dense::Matrix;
+use lin_alg_lab::random::Rng;
+use lin_alg_lab::svd::svd;
+use lin_alg_lab::vector::Vector2;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn main() {
+    // 变形梯度 F = R(0.3) · S，S 沿与 x 轴成 0.4 rad 的方向拉伸 2 倍、垂直方向压缩到 0.5
+    let axes = Matrix2x2::rotation(0.4);
+    let s = axes * Matrix2x2::scaling(2.0, 0.5) * axes.transpose();
+    let f = Matrix2x2::rotation(0.3) * s;
+    let p = f.polar();
+    assert_approx_eq!(p.angle, 0.3);
+    assert_approx_eq!(f.rotation_angle(), 0.3);
+    assert_approx_eq!(p.rotation, Matrix2x2::rotation(0.3));
+    assert_approx_eq!(p.stretch, s, abs = 1e-15);
+    assert_approx_eq!(p.principal_stretches.to_vec(), vec![2.0, 0.5]);
+    // 主方向只确定到 ±π
+    assert_approx_eq!((p.principal_angle - 0.4).rem_euclid(PI), 0.0, abs = 1e-15);
+    assert_approx_eq!(p.max_shear(), 0.75);
+    assert_approx_eq!(p.reconstruct(), f);
+    println!(
+        "F = {:?}\n  rotation {:.3} rad, stretches {:?}",
+        f, p.angle, p.principal_stretches
+    );
+
+    // SVD 的基本性质
+    let svd2 = f.svd();
+    assert_approx_eq!(svd2.singular_values.to_vec(), vec![2.0, 0.5]);
+    assert_approx_eq!(svd2.reconstruct(), f);
+    assert_approx_eq!(svd2.condition_number(), 4.0);
+    // 奇异矩阵（包括零矩阵）的条件数是无穷大，而不是 0 / 0 = NaN
+    assert_eq!(
+        Matrix2x2::new(1.0, 2.0, 2.0, 4.0).svd().condition_number(),
+        f64::INFINITY
+    );
+    assert_eq!(
+        Matrix2x2::new(0.0, 0.0, 0.0, 0.0).svd().condition_number(),
+        f64::INFINITY
+    );
+
+    // 随机矩阵：重构、正交性、与通用 SVD 一致
+    let mut rng = Rng::new(47);
+    for _ in 0..1000 {
+        let a = Matrix2x2::new(
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+        );
+        check(&a);
+        let reference = svd(&Matrix::from(a)).expect("svd");
+        let got = a.svd().singular_values;
+        assert_approx_eq!(
+            reference.singular_values.to_vec(),
+            got.to_vec(),
+            abs = 1e-13
+        );
+    }
+
+    // 退化情形
+    let cases = [
+        ("zero", Matrix2x2::new(0.0, 0.0, 0.0, 0.0)),
+        ("identity", Matrix2x2::identity()),
+        ("pure rotation", Matrix2x2::rotation(2.5)),
+        ("rotation by π", Matrix2x2::rotation(PI)),
+        ("uniform scale", Matrix2x2::rotation(-1.0) * 3.0),
+        (
+            "reflection",
+            Matrix2x2::reflection((1.0, 2.0).into()).unwrap(),
+        ),
+        ("rank one", Matrix2x2::new(1.0, 2.0, 2.0, 4.0)),
+        (
+            "nearly singular",
+            Matrix2x2::new(1.0, 1.0, 1.0, 1.0 + 1e-12),
+        ),
+        (
+            "nearly equal σ",
+            Matrix2x2::rotation(0.7) * Matrix2x2::scaling(1.0, 1.0 + 1e-13),
+        ),
+        ("tiny", Matrix2x2::new(1e-200, 2e-200, -3e-200, 1e-200)),
+        ("huge", Matrix2x2::new(1e200, -2e200, 3e200, 1e200)),
+        ("shear", Matrix2x2::shear(1e6, 0.0)),
+        ("nilpotent", Matrix2x2::new(0.0, 1.0, 0.0, 0.0)),
+    ];
+    for (name, a) in cases {
+        check(&a);
+        println!("{:>32}: σ = {:?}", name, a.svd().singular_values);
+    }
+
+    // 纯旋转：σ = (1, 1)，伸长张量为单位阵
+    let p = Matrix2x2::rotation(2.5).polar();
+    assert_approx_eq!(p.angle, 2.5);
+    assert_approx_eq!(p.stretch, Matrix2x2::identity(), abs = 1e-15);
+    assert_approx_eq!(p.max_shear(), 0.0);
+    // 秩 1：σ₂ 精确为 0
+    assert_eq!(
+        Matrix2x2::new(1.0, 2.0, 2.0, 4.0).svd().singular_values[1],
+        0.0
+    );
+    // 接近奇异：σ₂ = det / σ₁ 保持相对精度（Q - R 的写法会完全丢失）
+    let near = Matrix2x2::new(1.0, 1.0, 1.0, 1.0 + 1e-12);
+    let sigma = near.svd().singular_values;
+    assert_approx_eq!(sigma[0] * sigma[1], near.determinant(), rel = 1e-14);
+    assert_approx_eq!(sigma[1], 5e-13, rel = 1e-3);
+    // 反射：U 是镜像，极分解的镜像留在 S 里
+    let m = Matrix2x2::scaling(-3.0, 1.0);
+    assert_approx_eq!(m.svd().u.determinant(), -1.0);
+    let p = m.polar();
+    assert_approx_eq!(p.rotation.determinant(), 1.0);
+    assert!(p.principal_stretches[1] < 0.0);
+    // 正交 90° 剪切型变形
+    let p = Matrix2x2::new(0.0, -1.0, 1.0, 0.0).polar();
+    assert_approx_eq!(p.angle, FRAC_PI_2);
+
+    println!("all polar / SVD checks passed");
+}
+
+/// 检查重构误差以及各因子的正交性 / 对称性。
+fn check(a: &Matrix2x2) {
+    let scale = a
+        .m11()
+        .abs()
+        .max(a.m12().abs())
+        .max(a.m21().abs())
+        .max(a.m22().abs());
+    let tol = 1e-14 * scale.max(f64::MIN_POSITIVE);
+
+    let d = a.svd();
+    assert!(d.singular_values[0] >= d.singular_values[1] && d.singular_values[1] >= 0.0);
+    assert_approx_eq!(d.reconstruct(), *a, abs = tol);
+    assert_approx_eq!(d.u * d.u.transpose(), Matrix2x2::identity(), abs = 1e-15);
+    assert_approx_eq!(d.vt * d.vt.transpose(), Matrix2x2::identity(), abs = 1e-15);
+    assert_approx_eq!(d.vt.determinant(), 1.0);
+
+    let p = a.polar();
+    assert_approx_eq!(p.reconstruct(), *a, abs = tol);
+    assert_approx_eq!(p.rotation.determinant(), 1.0);
+    assert_approx_eq!(p.rotation, Matrix2x2::rotation(p.angle));
+    assert_approx_eq!(p.stretch, p.stretch.transpose(), abs = tol);
+    assert_approx_eq!(
+        p.stretch.trace(),
+        p.principal_stretches[0] + p.principal_stretches[1],
+        abs = tol
+    );
+    assert_approx_eq!(p.angle, a.rotation_angle());
+    // 主伸长的绝对值就是奇异值
+    assert_approx_eq!(p.principal_stretches[0], d.singular_values[0]);
+    assert_approx_eq!(
+        p.principal_stretches[1].abs(),
+        d.singular_values[1],
+        abs = tol
+    );
+    // 主方向确实是 S 的特征向量
+    let dir = Matrix2x2::rotation(p.principal_angle) * Vector2::new(1.0, 0.0);
+    assert_approx_eq!(p.stretch * dir, dir * p.principal_stretches[0], abs = tol);
+}
@@ -0,0 +1,199 @@
+use lin_alg_lab::assert_approx_eq;
+use lin_alg_lab::polygon::Polygon;
+use lin_alg_lab::primitives::Orientation;
+use lin_alg_lab::random::Rng;
+use lin_alg_lab::vector::Vector2;
+use std::f64::consts::TAU;
+
+fn v(x: f64, y: f64) -> Vector2 {
+    Vector2::new(x, y)
+}
+
+fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> Polygon {
+    Polygon::new(vec![v(x0, y0), v(x1, y0), v(x1, y1), v(x0, y1)])
+}
+
+/// 随机凸多边形：在圆上取随机角度排序后的点。
+fn random_convex(rng: &mut Rng, center: Vector2, radius: f64, n: usize) -> Polygon {
+    let mut angles: Vec<f64> = (0..n).map(|_| rng.next_f64() * TAU).collect();
+    angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Polygon::new(
+        angles
+            .into_iter()
+            .map(|t| center + v(t.cos(), t.sin()) * radius)
+            .collect(),
+    )
+}
+
+fn main() {
+    // 单位正方形：面积、重心、周长、方向、凸性
+    let square = rect(0.0, 0.0, 1.0, 1.0);
+    assert_eq!(square.len(), 4);
+    assert_approx_eq!(square.signed_area(), 1.0);
+    assert_approx_eq!(square.centroid().unwrap(), v(0.5, 0.5));
+    assert_approx_eq!(square.perimeter(), 4.0);
+    assert_eq!(square.orientation(), Orientation::CounterClockwise);
+    assert!(square.is_convex());
+    let cw = square.reversed();
+    assert_approx_eq!(cw.signed_area(), -1.0);
+    assert_eq!(cw.orientation(), Orientation::Clockwise);
+    assert_approx_eq!(cw.centroid().unwrap(), v(0.5, 0.5));
+    assert!(cw.is_convex());
+    assert_eq!(square.edges().count(), 4);
+
+    // L 形：凹多边形，重心 = 三个单位正方形重心的平均
+    let l_shape = Polygon::from(vec![
+        v(0.0, 0.0),
+        v(2.0, 0.0),
+        v(2.0, 1.0),
+        v(1.0, 1.0),
+        v(1.0, 2.0),
+        v(0.0, 2.0),
+    ]);
+    assert_approx_eq!(l_shape.area(), 3.0);
+    assert_approx_eq!(l_shape.centroid().unwrap(), v(2.5 / 3.0, 2.5 / 3.0));
+    assert_approx_eq!(l_shape.perimeter(), 8.0);
+    assert!(!l_shape.is_convex());
+    println!(
+        "L 形：面积 {}，重心 {:?}",
+        l_shape.area(),
+        l_shape.centroid().unwrap()
+    );
+
+    // 点在多边形内：内部、凹口、边界、顶点、差一个 ulp
+    assert!(l_shape.contains_even_odd(&v(0.5, 0.5)));
+    assert!(l_shape.contains_nonzero(&v(0.5, 1.5)));
+    assert!(!l_shape.contains_even_odd(&v(1.5, 1.5)));
+    assert!(!l_shape.contains_nonzero(&v(1.5, 1.5)));
+    assert!(l_shape.on_boundary(&v(1.0, 1.5)));
+    assert!(l_shape.contains_even_odd(&v(1.0, 1.5)));
+    assert!(l_shape.contains_nonzero(&v(2.0, 0.0)));
+    assert!(!l_shape.contains_nonzero(&v(2.0 + f64::EPSILON * 2.0, 0.5)));
+    assert!(l_shape.contains_nonzero(&v(2.0 - f64::EPSILON, 0.5)));
+    // 射线恰好穿过顶点（y 与顶点相同）时不能重复计数
+    assert!(l_shape.contains_even_odd(&v(0.5, 1.0)));
+    assert!(!l_shape.contains_even_odd(&v(-0.5, 1.0)));
+    assert_eq!(l_shape.winding_number(&v(0.5, 1.0)), 1);
+    assert_eq!(l_shape.reversed().winding_number(&v(0.5, 1.0)), -1);
+    // 斜边上的点（二进制小数坐标，恰好在边上）与紧邻的点
+    let tri = Polygon::new(vec![v(0.0, 0.0), v(4.0, 0.0), v(0.0, 2.0)]);
+    assert!(tri.on_boundary(&v(1.0, 1.5)));
+    assert!(tri.contains_nonzero(&v(1.0, 1.5)));
+    assert!(!tri.contains_nonzero(&v(1.0, 1.5 + f64::EPSILON)));
+    assert!(tri.contains_nonzero(&v(1.0, 1.5 - f64::EPSILON)));
+
+    // 五角星：每个拐角同向，但绕了两圈，不是凸的；中心处的圈数为 2
+    let star = Polygon::new(
+        (0..5)
+            .map(|k| {
+                let t = TAU / 4.0 + k as f64 * 2.0 * TAU / 5.0;
+                v(t.cos(), t.sin())
+            })
+            .collect(),
+    );
+    assert!(!star.is_convex());
+    let center = v(0.0, 0.0);
+    assert_eq!(star.winding_number(&center), 2);
+    assert!(star.contains_nonzero(&center));
+    assert!(!star.contains_even_odd(&center));
+    // 星角里的点两种规则都算内部
+    let tip = v(0.0, 0.8);
+    assert_eq!(star.winding_number(&tip), 1);
+    assert!(star.contains_even_odd(&tip) && star.contains_nonzero(&tip));
+    assert!(!star.contains_nonzero(&v(0.0, 1.1)));
+
+    // 退化输入：空多边形、共线点、重复顶点、共线顶点
+    let empty = Polygon::new(Vec::new());
+    assert!(empty.is_empty());
+    assert_eq!(empty.signed_area(), 0.0);
+    assert_eq!(empty.perimeter(), 0.0);
+    assert!(empty.centroid().is_none());
+    assert!(!empty.is_convex());
+    assert_eq!(empty.winding_number(&center), 0);
+    let flat = Polygon::new(vec![v(0.0, 0.0), v(1.0, 1.0), v(3.0, 3.0)]);
+    assert_eq!(flat.signed_area(), 0.0);
+    assert_eq!(flat.orientation(), Orientation::Collinear);
+    assert!(flat.centroid().is_none());
+    assert!(!flat.is_convex());
+    assert_approx_eq!(flat.perimeter(), 2.0 * 18f64.sqrt());
+    let dup = Polygon::new(vec![
+        v(0.0, 0.0),
+        v(0.0, 0.0),
+        v(1.0, 0.0),
+        v(2.0, 0.0),
+        v(2.0, 2.0),
+        v(2.0, 2.0),
+        v(0.0, 2.0),
+        v(0.0, 0.0),
+    ]);
+    assert!(dup.is_convex());
+    assert_approx_eq!(dup.area(), 4.0);
+    assert_approx_eq!(dup.centroid().unwrap(), v(1.0, 1.0));
+    assert_approx_eq!(dup.perimeter(), 8.0);
+
+    // 远离原点的小多边形：扇形分解 + orient2d 让面积几乎不受平移影响。
+    // 坐标取 2^30 + 2^-k，保证输入本身可以精确表示；直接套鞋带公式时
+    // xᵢ yᵢ₊₁ 约为 1e18，面积 1e-6 会被完全淹没在舍入误差里
+    let (base, w, h) = (2f64.powi(30), 2f64.powi(-10), 2f64.powi(-9));
+    let far = rect(base, base, base + w, base + h);
+    assert_eq!(far.area(), w * h);
+    assert_approx_eq!(far.centroid().unwrap(), v(base + w / 2.0, base + h / 2.0));
+    let naive: f64 = far
+        .edges()
+        .map(|e| e.a().x() * e.b().y() - e.b().x() * e.a().y())
+        .sum::<f64>()
+        / 2.0;
+    println!(
+        "远离原点的小矩形：面积 {}，朴素鞋带公式 {naive}",
+        far.area()
+    );
+
+    // Sutherland–Hodgman 裁剪
+    let a = rect(0.0, 0.0, 2.0, 2.0);
+    let b = rect(1.0, 1.0, 3.0, 3.0);
+    let ab = a.clip(&b).unwrap();
+    assert_approx_eq!(ab.area(), 1.0);
+    assert_approx_eq!(ab.centroid().unwrap(), v(1.5, 1.5));
+    // 顺时针的裁剪多边形结果相同
+    assert_approx_eq!(a.clip(&b.reversed()).unwrap().area(), 1.0);
+    // 完全在内部 / 完全在外部 / 只共享一条边
+    assert_approx_eq!(rect(0.5, 0.5, 1.0, 1.0).clip(&a).unwrap().area(), 0.25);
+    assert!(rect(5.0, 5.0, 6.0, 6.0).clip(&a).unwrap().is_empty());
+    assert_eq!(rect(2.0, 0.0, 3.0, 2.0).clip(&a).unwrap().area(), 0.0);
+    // 裁剪多边形不是凸的
+    assert!(a.clip(&l_shape).is_none());
+    assert!(a.clip(&star).is_none());
+    // 凹的被裁剪多边形
+    let l_clipped = l_shape.clip(&rect(0.0, 0.0, 1.5, 1.5)).unwrap();
+    assert_approx_eq!(l_clipped.area(), 2.0);
+    // 用三角形裁剪正方形
+    let cut = square.clip(&Polygon::new(vec![v(0.0, 0.0), v(2.0, 0.0), v(0.0, 2.0)]));
+    assert_approx_eq!(cut.unwrap().area(), 1.0);
+    let cut = square.clip(&Polygon::new(vec![v(-1.0, 0.0), v(1.0, 0.0), v(-1.0, 2.0)]));
+    assert_approx_eq!(cut.unwrap().area(), 0.5);
+
+    // 两个凸多边形的交与裁剪顺序无关，且结果仍是凸的
+    let mut rng = Rng::new(49);
+    for _ in 0..200 {
+        let p = {
+            let c = v(rng.next_f64(), rng.next_f64());
+            random_convex(&mut rng, c, 1.0, 7)
+        };
+        let q = {
+            let c = v(rng.next_f64(), rng.next_f64());
+            random_convex(&mut rng, c, 1.0, 5)
+        };
+        let pq = p.clip(&q).unwrap();
+        let qp = q.clip(&p).unwrap();
+        assert_approx_eq!(pq.area(), qp.area(), abs = 1e-12);
+        assert!(pq.area() <= p.area().min(q.area()) + 1e-12);
+        if pq.area() > 1e-9 {
+            assert!(pq.is_convex());
+            // 交集的重心同时在两个多边形内
+            let c = pq.centroid().unwrap();
+            assert!(p.contains_nonzero(&c) && q.contains_nonzero(&c));
+        }
+    }
+
+    println!("polygon demo 全部断言通过");
+}
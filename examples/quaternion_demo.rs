@@ -0,0 +1,171 @@
+use lin_alg_lab::matrix::Matrix2x2;
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul, matvec};
+use lin_alg_lab::quaternion::{EulerOrder, Quaternion, dot, nlerp, slerp};
+use lin_alg_lab::random::Rng;
+use lin_alg_lab::vector::Vector2;
+use lin_alg_lab::vector3::{Vector3, angle_between, norm};
+use lin_alg_lab::{assert_approx_eq, assert_approx_ne};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn main() -> Result<(), MatrixError> {
+    let ex = Vector3::new(1.0, 0.0, 0.0);
+    let ey = Vector3::new(0.0, 1.0, 0.0);
+    let ez = Vector3::new(0.0, 0.0, 1.0);
+
+    // Hamilton 乘积：i² = j² = k² = ijk = -1
+    let i = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    let j = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+    let k = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+    let minus_one = Quaternion::new(-1.0, 0.0, 0.0, 0.0);
+    assert_eq!(i * i, minus_one);
+    assert_eq!(j * j, minus_one);
+    assert_eq!(i * j * k, minus_one);
+    assert_eq!(i * j, k);
+    assert_eq!(j * i, -k);
+
+    // 共轭、逆、归一化
+    let q = Quaternion::new(1.0, 2.0, -3.0, 4.0);
+    assert_approx_eq!(q * q.inverse().unwrap(), Quaternion::identity());
+    assert_approx_eq!((q * q.conjugate()).w(), q.norm_sq());
+    assert_approx_eq!(q.normalize().unwrap().norm(), 1.0);
+    assert!(Quaternion::new(0.0, 0.0, 0.0, 0.0).inverse().is_none());
+
+    // 平面情形：绕 z 轴旋转与 Matrix2x2::rotation 一致
+    for &theta in &[0.0, 0.3, FRAC_PI_2, 2.5, -1.2, PI] {
+        let qz = Quaternion::from_axis_angle(&ez, theta).unwrap();
+        let r2 = Matrix2x2::rotation(theta);
+        for &(x, y) in &[(1.0, 0.0), (0.0, 1.0), (2.0, -3.0)] {
+            let v3 = qz.rotate(&Vector3::new(x, y, 0.0));
+            let v2 = r2 * Vector2::new(x, y);
+            assert_approx_eq!(Vector2::new(v3.x(), v3.y()), v2, abs = 1e-14);
+            assert_approx_eq!(v3.z(), 0.0);
+        }
+        let r3 = qz.to_rotation_matrix();
+        let top_left = Matrix2x2::new(r3.get(0, 0)?, r3.get(0, 1)?, r3.get(1, 0)?, r3.get(1, 1)?);
+        assert_approx_eq!(top_left, r2, abs = 1e-15);
+        assert_approx_eq!(r3.get(2, 2)?, 1.0);
+    }
+    // 平面旋转的合成 = 四元数乘积
+    let (a, b) = (0.4, 1.1);
+    let qa = Quaternion::from_axis_angle(&ez, a).unwrap();
+    let qb = Quaternion::from_axis_angle(&ez, b).unwrap();
+    let (axis, angle) = (qb * qa).to_axis_angle().unwrap();
+    assert_approx_eq!(angle, a + b);
+    assert_approx_eq!(axis, ez);
+    assert_approx_eq!(
+        Matrix2x2::rotation(b) * Matrix2x2::rotation(a),
+        Matrix2x2::rotation(angle),
+        abs = 1e-15
+    );
+
+    // 轴角往返（包括很小的角度）
+    let axis = Vector3::new(1.0, -2.0, 0.5);
+    for &theta in &[1e-9, 0.7, 3.0] {
+        let q = Quaternion::from_axis_angle(&axis, theta).unwrap();
+        let (n, t) = q.to_axis_angle().unwrap();
+        assert_approx_eq!(t, theta, rel = 1e-12);
+        assert_approx_eq!(n, axis.normalize().unwrap());
+    }
+    assert!(Quaternion::from_axis_angle(&Vector3::zero(), 1.0).is_none());
+
+    // 欧拉角：Xyz 对应 R = Rx Ry Rz，Zyx 对应 R = Rz Ry Rx
+    let (ax, ay, az) = (0.3, -0.8, 1.9);
+    let rx = Quaternion::from_axis_angle(&ex, ax)
+        .unwrap()
+        .to_rotation_matrix();
+    let ry = Quaternion::from_axis_angle(&ey, ay)
+        .unwrap()
+        .to_rotation_matrix();
+    let rz = Quaternion::from_axis_angle(&ez, az)
+        .unwrap()
+        .to_rotation_matrix();
+    let xyz = Quaternion::from_euler([ax, ay, az], EulerOrder::Xyz);
+    assert_approx_eq!(
+        xyz.to_rotation_matrix(),
+        matmul(&matmul(&rx, &ry)?, &rz)?,
+        abs = 1e-14
+    );
+    let zyx = Quaternion::from_euler([az, ay, ax], EulerOrder::Zyx);
+    assert_approx_eq!(
+        zyx.to_rotation_matrix(),
+        matmul(&matmul(&rz, &ry)?, &rx)?,
+        abs = 1e-14
+    );
+    assert_approx_ne!(xyz, zyx, abs = 1e-3);
+
+    // 旋转矩阵往返：随机旋转，以及接近 π 的旋转（Shepperd 方法的难点）
+    let mut rng = Rng::new(43);
+    for trial in 0..200 {
+        let q = Quaternion::new(
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+        )
+        .normalize()
+        .unwrap();
+        let q = if trial % 4 == 0 {
+            // w ≈ 0：旋转角接近 π
+            Quaternion::new(1e-9, q.x(), q.y(), q.z())
+                .normalize()
+                .unwrap()
+        } else {
+            q
+        };
+        let r = q.to_rotation_matrix();
+        let back = Quaternion::from_rotation_matrix(&r)?;
+        // q 与 -q 是同一个旋转
+        assert_approx_eq!(dot(&q, &back).abs(), 1.0, abs = 1e-14);
+        // rotate 与矩阵乘向量一致，且保持长度
+        let v = Vector3::new(
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+            rng.next_gaussian(),
+        );
+        let rv = q.rotate(&v);
+        let mv = matvec(&r, &[v.x(), v.y(), v.z()])?;
+        assert_approx_eq!(vec![rv.x(), rv.y(), rv.z()], mv, abs = 1e-14);
+        assert_approx_eq!(norm(&rv), norm(&v), abs = 1e-14);
+        // RᵀR = I
+        assert_approx_eq!(
+            matmul(&r.transpose()?, &r)?,
+            Matrix::identity(3),
+            abs = 1e-14
+        );
+    }
+    assert!(matches!(
+        Quaternion::from_rotation_matrix(&Matrix::identity(2)),
+        Err(MatrixError::DimensionMismatch)
+    ));
+
+    // slerp：端点、恒定角速度、走最短路径
+    let q0 = Quaternion::identity();
+    let q1 = Quaternion::from_axis_angle(&ey, 2.0).unwrap();
+    assert_approx_eq!(slerp(&q0, &q1, 0.0), q0);
+    assert_approx_eq!(slerp(&q0, &q1, 1.0), q1);
+    for &t in &[0.1, 0.25, 0.5, 0.9] {
+        let (_, angle) = slerp(&q0, &q1, t).to_axis_angle().unwrap();
+        assert_approx_eq!(angle, 2.0 * t, abs = 1e-14);
+    }
+    // -q1 与 q1 是同一个旋转，插值结果也应相同
+    assert_approx_eq!(
+        slerp(&q0, &-q1, 0.3).rotate(&ex),
+        slerp(&q0, &q1, 0.3).rotate(&ex)
+    );
+    // nlerp 的路径相同，但中点以外的角速度不恒定
+    assert_approx_eq!(nlerp(&q0, &q1, 0.5), slerp(&q0, &q1, 0.5));
+    let (_, nl) = nlerp(&q0, &q1, 0.25).to_axis_angle().unwrap();
+    assert!((nl - 0.5).abs() > 1e-3);
+    // 几乎重合的两个旋转
+    let q2 = Quaternion::from_axis_angle(&ey, 1e-12).unwrap();
+    assert_approx_eq!(slerp(&q0, &q2, 0.5).norm(), 1.0);
+
+    let v = Quaternion::from_euler([0.1, 0.2, 0.3], EulerOrder::Zyx).rotate(&ex);
+    println!(
+        "Zyx(0.1, 0.2, 0.3) · eₓ = {:?}, angle to eₓ = {:.6}",
+        v,
+        angle_between(&v, &ex).unwrap()
+    );
+    println!("all quaternion checks passed");
+    Ok(())
+}
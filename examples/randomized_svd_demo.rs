@@ -0,0 +1,117 @@
+use lin_alg_lab::conditioning::MatrixNorm;
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul};
+use lin_alg_lab::random::Rng;
+use lin_alg_lab::svd::{RandomizedSvdOptions, randomized_svd, svd};
+
+fn difference(a: &Matrix, b: &Matrix) -> Result<Matrix, MatrixError> {
+    let data = a
+        .as_slice()
+        .iter()
+        .zip(b.as_slice())
+        .map(|(x, y)| x - y)
+        .collect();
+    Matrix::new(a.rows(), a.cols(), data)
+}
+
+/// QᵀQ = I
+fn assert_orthonormal_columns(q: &Matrix) -> Result<(), MatrixError> {
+    let qtq = matmul(&q.transpose()?, q)?;
+    let err = difference(&qtq, &Matrix::identity(q.cols()))?.norm(MatrixNorm::Max);
+    assert!(err < 1e-12, "‖QᵀQ - I‖ = {err:e}");
+    Ok(())
+}
+
+fn main() -> Result<(), MatrixError> {
+    // 1. 完整 SVD：小矩阵，检查 A = U Σ Vᵀ
+    let a = Matrix::new(3, 2, vec![3.0, 2.0, 2.0, 3.0, 2.0, -2.0])?;
+    let full = svd(&a)?;
+    println!("singular values = {:?}", full.singular_values);
+    assert!((full.singular_values[0] - 5.0).abs() < 1e-12);
+    assert!((full.singular_values[1] - 3.0).abs() < 1e-12);
+    let err = difference(&full.reconstruct()?, &a)?.norm(MatrixNorm::Max);
+    assert!(err < 1e-12);
+
+    // 2. 构造 400×120、奇异值按 σ_i = 2^{-i} 衰减的矩阵 A = X diag(σ) Yᵀ
+    let (m, n) = (400, 120);
+    let mut rng = Rng::new(42);
+    let gaussian = |rows: usize, cols: usize, rng: &mut Rng| {
+        Matrix::new(
+            rows,
+            cols,
+            (0..rows * cols).map(|_| rng.next_gaussian()).collect(),
+        )
+    };
+    let x = svd(&gaussian(m, n, &mut rng)?)?.u;
+    let y = svd(&gaussian(n, n, &mut rng)?)?.u;
+    let mut xs = x.clone();
+    for j in 0..n {
+        let s = 0.5_f64.powi(j as i32);
+        for i in 0..m {
+            xs.set(i, j, x.get(i, j)? * s);
+        }
+    }
+    let big = matmul(&xs, &y.transpose()?)?;
+
+    // 3. 随机 SVD 求前 20 个奇异值
+    let k = 20;
+    let options = RandomizedSvdOptions::new(k);
+    let rsvd = randomized_svd(&big, &options)?;
+    for (i, s) in rsvd.singular_values.iter().enumerate() {
+        let exact = 0.5_f64.powi(i as i32);
+        assert!(
+            (s - exact).abs() < 1e-10 * exact.max(1e-6) + 1e-14,
+            "σ_{} = {} vs {}",
+            i,
+            s,
+            exact
+        );
+    }
+    println!("top singular values = {:?}", &rsvd.singular_values[..5]);
+
+    // 4. 低秩近似误差应接近最优值 σ_{k+1} = 2^{-20}
+    let approx = big.low_rank_approx(k)?;
+    assert_eq!(approx.left.cols(), k);
+    assert_eq!(approx.right.rows(), k);
+    let err = difference(&approx.to_matrix()?, &big)?.norm(MatrixNorm::Frobenius);
+    let optimal: f64 = (k..n).map(|j| 0.25_f64.powi(j as i32)).sum::<f64>().sqrt();
+    println!("‖A - A_k‖_F = {:e} (optimal {:e})", err, optimal);
+    assert!(err < 1.01 * optimal);
+
+    // 5. 同一个种子结果完全相同；换种子结果不同但同样准确
+    let again = randomized_svd(&big, &options)?;
+    assert_eq!(again.singular_values, rsvd.singular_values);
+    assert_eq!(again.u.as_slice(), rsvd.u.as_slice());
+    let other = randomized_svd(&big, &RandomizedSvdOptions { seed: 7, ..options })?;
+    assert!((other.singular_values[0] - rsvd.singular_values[0]).abs() < 1e-12);
+
+    // 6. rank(A) < k：补零奇异值，形状仍是 m × k / k × n，奇异向量仍然正交归一
+    let rank_one = Matrix::new(
+        4,
+        3,
+        vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0, 4.0, 8.0, 12.0],
+    )?;
+    let short = randomized_svd(&rank_one, &RandomizedSvdOptions::new(2))?;
+    assert_eq!(short.singular_values.len(), 2);
+    assert!((short.singular_values[0] - 30f64.sqrt() * 14f64.sqrt()).abs() < 1e-10);
+    assert_eq!(short.singular_values[1], 0.0);
+    assert_orthonormal_columns(&short.u)?;
+    assert_orthonormal_columns(&short.vt.transpose()?)?;
+    let err = difference(&short.reconstruct()?, &rank_one)?.norm(MatrixNorm::Frobenius);
+    assert!(err < 1e-10);
+    let approx = rank_one.low_rank_approx(2)?;
+    assert_eq!((approx.left.rows(), approx.left.cols()), (4, 2));
+    assert_eq!((approx.right.rows(), approx.right.cols()), (2, 3));
+
+    let zero = Matrix::zeros(4, 3);
+    let empty = randomized_svd(&zero, &RandomizedSvdOptions::new(3))?;
+    assert_eq!(empty.singular_values, vec![0.0; 3]);
+    assert_orthonormal_columns(&empty.u)?;
+    assert_orthonormal_columns(&empty.vt.transpose()?)?;
+    let approx = zero.low_rank_approx(2)?;
+    assert_eq!((approx.left.rows(), approx.left.cols()), (4, 2));
+    assert_eq!((approx.right.rows(), approx.right.cols()), (2, 3));
+    assert!(approx.to_matrix()?.as_slice().iter().all(|&x| x == 0.0));
+
+    println!("Randomized SVD demo passed.");
+    Ok(())
+}
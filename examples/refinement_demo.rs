@@ -0,0 +1,63 @@
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matvec};
+use lin_alg_lab::refinement::RefinementOptions;
+
+fn main() -> Result<(), MatrixError> {
+    // 对角占优的 5×5 矩阵，真解 x = [1, 2, 3, 4, 5]
+    let n = 5;
+    let mut data = Vec::with_capacity(n * n);
+    for i in 0..n {
+        for j in 0..n {
+            let v = if i == j {
+                10.0 + i as f64
+            } else {
+                1.0 / (1.0 + (i as f64 - j as f64).abs())
+            };
+            data.push(v);
+        }
+    }
+    let a = Matrix::new(n, n, data)?;
+    let x_true: Vec<f64> = (1..=n).map(|k| k as f64).collect();
+    let b = matvec(&a, &x_true)?;
+
+    let options = RefinementOptions::default();
+
+    // 1. f64 LU + 迭代精化
+    let (l, u) = a.lu_decomposition()?;
+    let refined = a.solve_refined(&l, &u, &b, &options)?;
+    println!(
+        "f64 LU: iterations = {}, backward error = {:e}",
+        refined.iterations, refined.backward_error
+    );
+    assert!(refined.converged);
+    for (xi, ti) in refined.x.iter().zip(&x_true) {
+        assert!((xi - ti).abs() < 1e-13);
+    }
+
+    // 2. f32 分解，f64 精化：初始解只有单精度，精化后恢复到双精度
+    let mixed = a.solve_mixed_precision(&b, &options)?;
+    println!(
+        "f32 LU: iterations = {}, backward error = {:e}",
+        mixed.iterations, mixed.backward_error
+    );
+    assert!(mixed.iterations >= 1);
+    assert!(mixed.backward_error < 1e-15);
+    for (xi, ti) in mixed.x.iter().zip(&x_true) {
+        assert!((xi - ti).abs() < 1e-13);
+    }
+
+    // 3. 残差用补偿求和计算：1e16 + 1 - 1e16 朴素累加会得到 0，真实值是 1
+    let cancel = Matrix::new(1, 3, vec![1e16, 1.0, -1e16])?;
+    let r = cancel.residual(&[1.0, 1.0, 1.0], &[0.0])?;
+    println!("residual with cancellation = {:?}", r);
+    assert_eq!(r, vec![-1.0]);
+
+    // 4. 奇异矩阵在单精度分解阶段就会报错
+    let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0])?;
+    assert!(matches!(
+        singular.solve_mixed_precision(&[1.0, 2.0], &options),
+        Err(MatrixError::Singular)
+    ));
+
+    println!("Iterative refinement demo passed.");
+    Ok(())
+}
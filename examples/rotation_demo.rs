@@ -7,6 +7,7 @@ fn main() {
     // 旋转 90 度（π/2）
     let rot90 = Matrix2x2::rotation(std::f64::consts::FRAC_PI_2);
     let v_rot90 = rot90 * v;
+    #[allow(clippy::op_ref)] // 有意演示 Mul<&Vector2> 的实现
     let v_rot90_ref = rot90 * &v;
 
     println!("v           = {:?}", v);
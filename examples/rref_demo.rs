@@ -0,0 +1,78 @@
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matvec};
+use lin_alg_lab::matrix_dense_rref::LinearSolution;
+
+fn main() -> Result<(), MatrixError> {
+    // 秩为 2 的 3×4 矩阵：第三行 = 第一行 + 第二行
+    //
+    // [ 1  2  0  3 ]
+    // [ 2  4  1  7 ]
+    // [ 3  6  1 10 ]
+    let a = Matrix::new(
+        3,
+        4,
+        vec![1.0, 2.0, 0.0, 3.0, 2.0, 4.0, 1.0, 7.0, 3.0, 6.0, 1.0, 10.0],
+    )?;
+
+    // 1. RREF 与主元列
+    let rref = a.rref(1e-12)?;
+    println!("rref(A) = {:?}", rref.matrix);
+    println!("pivots = {:?}", rref.pivots);
+    assert_eq!(rref.pivots, vec![0, 2]);
+    assert_eq!(a.rank(1e-12)?, 2);
+
+    // 2. 零空间：A * n = 0
+    let null = a.null_space(1e-12)?;
+    println!("null space basis = {:?}", null);
+    assert_eq!(null.rows(), 4);
+    assert_eq!(null.cols(), 2);
+    for k in 0..null.cols() {
+        let v: Vec<f64> = (0..null.rows())
+            .map(|i| null.get(i, k))
+            .collect::<Result<_, _>>()?;
+        let av = matvec(&a, &v)?;
+        assert!(av.iter().all(|x| x.abs() < 1e-10));
+    }
+
+    // 3. 列空间：取原矩阵的第 0、2 列
+    let col_space = a.column_space(1e-12)?;
+    assert_eq!(col_space.cols(), 2);
+    assert!((col_space.get(1, 0)? - 2.0).abs() < 1e-12);
+    assert!((col_space.get(1, 1)? - 1.0).abs() < 1e-12);
+
+    // 4. 相容方程组：b 在列空间里，有无穷多解
+    let b = vec![4.0, 9.0, 13.0];
+    match a.solve_general(&b, 1e-12)? {
+        LinearSolution::Infinite {
+            particular,
+            null_space,
+        } => {
+            println!("particular = {:?}", particular);
+            let ax = matvec(&a, &particular)?;
+            for (axi, bi) in ax.iter().zip(&b) {
+                assert!((axi - bi).abs() < 1e-10);
+            }
+            assert_eq!(null_space.cols(), 2);
+        }
+        other => panic!("expected infinitely many solutions, got {:?}", other),
+    }
+
+    // 5. 不相容方程组：第三个方程和前两个矛盾
+    let b_bad = vec![4.0, 9.0, 0.0];
+    assert!(matches!(
+        a.solve_general(&b_bad, 1e-12)?,
+        LinearSolution::NoSolution
+    ));
+
+    // 6. 可逆方阵：唯一解
+    let sq = Matrix::new(2, 2, vec![4.0, 3.0, 6.0, 3.0])?;
+    match sq.solve_general(&[7.0, 9.0], 1e-12)? {
+        LinearSolution::Unique(x) => {
+            assert!((x[0] - 1.0).abs() < 1e-10);
+            assert!((x[1] - 1.0).abs() < 1e-10);
+        }
+        other => panic!("expected a unique solution, got {:?}", other),
+    }
+
+    println!("RREF demo passed.");
+    Ok(())
+}
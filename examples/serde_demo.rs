@@ -0,0 +1,66 @@
+use lin_alg_lab::expr::{Expr, eval, to_infix};
+use lin_alg_lab::matrix::Matrix2x2;
+use lin_alg_lab::matrix_dense::Matrix;
+use lin_alg_lab::units::{LengthUnit, Quantity};
+use lin_alg_lab::vector::Vector2;
+use std::collections::HashMap;
+
+fn main() -> Result<(), serde_json::Error> {
+    // Vector2 / Matrix2x2：按字段名序列化
+    let v = Vector2::new(1.5, -2.0);
+    let json = serde_json::to_string(&v)?;
+    assert_eq!(json, r#"{"x":1.5,"y":-2.0}"#);
+    let v2: Vector2 = serde_json::from_str(&json)?;
+    assert_eq!((v2.x(), v2.y()), (1.5, -2.0));
+
+    let m: Matrix2x2 = serde_json::from_str(r#"{"m11":1,"m12":2,"m21":3,"m22":4}"#)?;
+    assert_eq!((m.m11(), m.m12(), m.m21(), m.m22()), (1.0, 2.0, 3.0, 4.0));
+    assert!(serde_json::from_str::<Matrix2x2>(r#"{"m11":1,"m12":2}"#).is_err());
+
+    // 稠密矩阵：rows、cols、行优先 data
+    let a = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    let json = serde_json::to_string(&a)?;
+    println!("matrix: {}", json);
+    assert_eq!(
+        json,
+        r#"{"rows":2,"cols":3,"data":[1.0,2.0,3.0,4.0,5.0,6.0]}"#
+    );
+    let b: Matrix = serde_json::from_str(&json)?;
+    assert_eq!((b.rows(), b.cols()), (2, 3));
+    assert_eq!(b.as_slice(), a.as_slice());
+
+    // data 长度与 rows × cols 不符时和 Matrix::new 一样被拒绝
+    let err = serde_json::from_str::<Matrix>(r#"{"rows":2,"cols":2,"data":[1,2,3]}"#).unwrap_err();
+    println!("bad shape: {}", err);
+    assert!(err.to_string().contains("dimension mismatch"));
+    // rows × cols 溢出 usize 时同样被拒绝，而不是 panic 或回绕成 0
+    let err = serde_json::from_str::<Matrix>(r#"{"rows":4294967296,"cols":4294967296,"data":[]}"#)
+        .unwrap_err();
+    assert!(err.to_string().contains("dimension mismatch"));
+
+    // 带单位的物理量
+    let q = Quantity::new(25.0, LengthUnit::Centimeter);
+    let json = serde_json::to_string(&q)?;
+    assert_eq!(json, r#"{"value":25.0,"unit":"Centimeter"}"#);
+    let q2: Quantity = serde_json::from_str(&json)?;
+    assert_eq!(q2.value(), 25.0);
+    assert!(serde_json::from_str::<Quantity>(r#"{"value":1,"unit":"Furlong"}"#).is_err());
+
+    // 表达式树：枚举按外部标签表示
+    let e = Expr::Add(
+        Box::new(Expr::Mul(
+            Box::new(Expr::Const(2.0)),
+            Box::new(Expr::Var("x".to_string())),
+        )),
+        Box::new(Expr::Neg(Box::new(Expr::Const(1.0)))),
+    );
+    let json = serde_json::to_string(&e)?;
+    println!("expr: {}", json);
+    let e2: Expr = serde_json::from_str(&json)?;
+    assert_eq!(to_infix(&e2), to_infix(&e));
+    let env = HashMap::from([("x".to_string(), 3.0)]);
+    assert_eq!(eval(&e2, &env).unwrap(), 5.0);
+
+    println!("all serde checks passed");
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use lin_alg_lab::matrix::Matrix2x2;
+use lin_alg_lab::matrix_dense::Matrix;
+use lin_alg_lab::random::Rng;
+use lin_alg_lab::serialize::{BinaryFormat, ByteOrder, ObjectKind, SerializeError, crc32};
+use lin_alg_lab::vector::Vector2;
+
+fn main() -> Result<(), SerializeError> {
+    // CRC-32 标准测试向量
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+
+    // 文件往返：逐位相同（包括 -0.0、NaN、无穷大、次正规数）
+    let mut rng = Rng::new(39);
+    let mut data: Vec<f64> = (0..300 * 7).map(|_| rng.next_gaussian()).collect();
+    data[..5].copy_from_slice(&[
+        -0.0,
+        f64::NAN,
+        f64::INFINITY,
+        f64::MIN_POSITIVE / 8.0,
+        f64::MAX,
+    ]);
+    let a = Matrix::new(300, 7, data)?;
+    let path = std::env::temp_dir().join(format!("lin_alg_lab_{}.lalb", std::process::id()));
+    a.save(&path)?;
+    let size = std::fs::metadata(&path)?.len();
+    assert_eq!(size, 32 + 300 * 7 * 8 + 4);
+    let back = Matrix::load(&path)?;
+    assert_eq!((back.rows(), back.cols()), (300, 7));
+    assert!(bits_equal(a.as_slice(), back.as_slice()));
+    std::fs::remove_file(&path)?;
+    println!(
+        "300×7 matrix: {} bytes on disk, round trip is bit-exact",
+        size
+    );
+
+    // 流式接口 + 大端字节序
+    let mut buf = Vec::new();
+    a.write_to_with_order(&mut buf, ByteOrder::Big)?;
+    assert_eq!(buf[6], 1);
+    assert!(bits_equal(
+        Matrix::read_from(&mut buf.as_slice())?.as_slice(),
+        a.as_slice()
+    ));
+
+    // 多个对象可以连续写进同一个流
+    let v = Vector2::new(3.5, -1.25);
+    let m = Matrix2x2::new(1.0, 2.0, 3.0, 4.0);
+    let empty = Matrix::new(0, 4, vec![])?;
+    let mut stream = Vec::new();
+    v.write_to(&mut stream)?;
+    m.write_to(&mut stream)?;
+    empty.write_to(&mut stream)?;
+    let mut reader = stream.as_slice();
+    let v2 = Vector2::read_from(&mut reader)?;
+    let m2 = Matrix2x2::read_from(&mut reader)?;
+    let e2 = Matrix::read_from(&mut reader)?;
+    assert!(reader.is_empty());
+    assert_eq!((v2.x(), v2.y()), (3.5, -1.25));
+    assert_eq!(
+        (m2.m11(), m2.m12(), m2.m21(), m2.m22()),
+        (1.0, 2.0, 3.0, 4.0)
+    );
+    assert_eq!((e2.rows(), e2.cols()), (0, 4));
+
+    // 损坏检测
+    let small = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0])?;
+    let mut good = Vec::new();
+    small.write_to(&mut good)?;
+
+    // 任意一个字节翻转一位都会被发现（魔数、头部各字段、数据、校验和本身）
+    for i in 0..good.len() {
+        for bit in 0..8 {
+            let mut bad = good.clone();
+            bad[i] ^= 1 << bit;
+            assert!(
+                Matrix::read_from(&mut bad.as_slice()).is_err(),
+                "byte {} bit {}",
+                i,
+                bit
+            );
+        }
+    }
+    let mut bad = good.clone();
+    bad[40] ^= 0x01;
+    let err = Matrix::read_from(&mut bad.as_slice()).unwrap_err();
+    assert!(matches!(err, SerializeError::DataChecksum { .. }));
+    println!("flipped payload byte: {}", err);
+
+    let mut bad = good.clone();
+    bad[12] ^= 0x01; // 行数被改写：在分配内存之前由头部校验和拦下
+    assert!(matches!(
+        Matrix::read_from(&mut bad.as_slice()),
+        Err(SerializeError::HeaderChecksum { .. })
+    ));
+
+    // 保留字节非 0：即使头部校验和是重新算过的（不是传输损坏），也要拒绝
+    let mut bad = good.clone();
+    bad[10] = 0x7f;
+    let crc = crc32(&bad[..28]);
+    bad[28..32].copy_from_slice(&crc.to_le_bytes());
+    let err = Matrix::read_from(&mut bad.as_slice()).unwrap_err();
+    assert!(matches!(err, SerializeError::NonZeroReserved([0, 0x7f, 0])));
+    println!("reserved bytes: {}", err);
+
+    let mut bad = good.clone();
+    bad[0] = b'X';
+    assert!(matches!(
+        Matrix::read_from(&mut bad.as_slice()),
+        Err(SerializeError::BadMagic)
+    ));
+
+    // 截断：任意长度的前缀都报 Truncated
+    for len in 0..good.len() {
+        assert!(matches!(
+            Matrix::read_from(&mut &good[..len]),
+            Err(SerializeError::Truncated)
+        ));
+    }
+
+    // 类型不符
+    let err = Vector2::read_from(&mut good.as_slice()).unwrap_err();
+    assert!(matches!(
+        err,
+        SerializeError::WrongKind {
+            expected: ObjectKind::Vector2,
+            found: 1
+        }
+    ));
+    println!("wrong kind: {}", err);
+
+    // 读不存在的文件是普通的 I/O 错误
+    assert!(matches!(
+        Matrix::load("/nonexistent/dir/x.lalb"),
+        Err(SerializeError::Io(_))
+    ));
+
+    println!("all serialization checks passed");
+    Ok(())
+}
+
+fn bits_equal(a: &[f64], b: &[f64]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.to_bits() == y.to_bits())
+}
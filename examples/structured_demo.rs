@@ -0,0 +1,132 @@
+use lin_alg_lab::eigen_iterative::{IterationOptions, LinearOperator, power_iteration};
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matvec};
+use lin_alg_lab::random::Rng;
+use lin_alg_lab::structured::{Circulant, Hankel, Toeplitz, Vandermonde, levinson_durbin};
+
+fn max_diff(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).fold(0.0, |m, (x, y)| m.max((x - y).abs()))
+}
+
+fn main() -> Result<(), MatrixError> {
+    let mut rng = Rng::new(34);
+
+    // 1. Toeplitz：结构化 matvec 与稠密结果一致（长矩阵走 FFT）
+    for (m, n) in [(5, 3), (200, 150)] {
+        let col: Vec<f64> = (0..m).map(|_| rng.next_gaussian()).collect();
+        let mut row: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+        row[0] = col[0];
+        let t = Toeplitz::new(col, row)?;
+        let x: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+        let dense = t.to_dense();
+        assert!(max_diff(&t.matvec(&x)?, &matvec(&dense, &x)?) < 1e-12);
+        assert_eq!(t.get(3, 1)?, dense.get(3, 1)?);
+    }
+
+    // 2. 非对称 Toeplitz 方程组：Levinson 递推
+    let t = Toeplitz::new(vec![4.0, 1.0, 0.5, 0.2], vec![4.0, -1.0, 0.3, 0.1])?;
+    let b = vec![1.0, 2.0, 3.0, 4.0];
+    let x = t.solve(&b)?;
+    println!("Toeplitz solve x = {:?}", x);
+    assert!(max_diff(&t.matvec(&x)?, &b) < 1e-13);
+    // 顺序主子式奇异（t0 = 0）时 Levinson 无法进行
+    let zero_diag = Toeplitz::new(vec![0.0, 1.0], vec![0.0, 1.0])?;
+    assert!(matches!(
+        zero_diag.solve(&[1.0, 1.0]),
+        Err(MatrixError::Singular)
+    ));
+
+    // 3. Yule–Walker：AR(2) 过程 x_t = 0.6 x_{t-1} - 0.2 x_{t-2} + e_t 的理论自相关
+    let (phi1, phi2) = (0.6, -0.2);
+    let rho1 = phi1 / (1.0 - phi2);
+    let rho2 = phi1 * rho1 + phi2;
+    let rho3 = phi1 * rho2 + phi2 * rho1;
+    let yw = levinson_durbin(&[1.0, rho1, rho2, rho3], 3)?;
+    println!("AR coefficients = {:?}", yw.coefficients);
+    assert!(max_diff(&yw.coefficients, &[phi1, phi2, 0.0]) < 1e-14);
+    assert!(yw.reflection[2].abs() < 1e-14);
+    // 与对称 Toeplitz 求解一致
+    let r = Toeplitz::symmetric(vec![1.0, rho1, rho2])?;
+    let direct = r.solve(&[rho1, rho2, rho3])?;
+    assert!(max_diff(&direct, &yw.coefficients) < 1e-14);
+    assert!(matches!(
+        levinson_durbin(&[1.0, 2.0], 1),
+        Err(MatrixError::NotPositiveDefinite)
+    ));
+
+    // 4. 循环矩阵：DFT 对角化求解，n 为 2 的幂（FFT）和非 2 的幂（直接 DFT）
+    for n in [8, 7] {
+        let c = Circulant::new(
+            (0..n)
+                .map(|k| if k == 0 { 5.0 } else { 1.0 / k as f64 })
+                .collect(),
+        )?;
+        let b: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+        let x = c.solve(&b)?;
+        assert!(max_diff(&c.matvec(&x)?, &b) < 1e-12);
+        assert!(max_diff(&matvec(&c.to_dense(), &x)?, &b) < 1e-12);
+        // 特征值之和等于迹
+        let trace: f64 = c.eigenvalues().iter().map(|(re, _)| re).sum();
+        assert!((trace - 5.0 * n as f64).abs() < 1e-12);
+    }
+    let all_ones = Circulant::new(vec![1.0; 4])?;
+    assert!(matches!(
+        all_ones.solve(&[1.0; 4]),
+        Err(MatrixError::Singular)
+    ));
+    assert!(matches!(
+        Circulant::new(vec![]),
+        Err(MatrixError::DimensionMismatch)
+    ));
+
+    // 5. Vandermonde：多项式插值，Björck–Pereyra
+    let nodes = vec![-1.0, -0.5, 0.0, 0.5, 1.0, 1.5];
+    let coeffs = vec![1.0, -2.0, 0.5, 3.0, 0.0, -1.0];
+    let v = Vandermonde::square(nodes.clone());
+    let f = v.matvec(&coeffs)?;
+    assert!(max_diff(&f, &matvec(&v.to_dense(), &coeffs)?) < 1e-13);
+    let a = v.solve(&f)?;
+    println!("interpolating coefficients = {:?}", a);
+    assert!(max_diff(&a, &coeffs) < 1e-12);
+    let repeated = Vandermonde::square(vec![0.0, 1.0, 1.0]);
+    assert!(matches!(
+        repeated.solve(&[1.0, 2.0, 3.0]),
+        Err(MatrixError::Singular)
+    ));
+    // 长方形 Vandermonde（最小二乘拟合的设计矩阵）只提供 matvec 和 to_dense
+    let design = Vandermonde::new(nodes, 3);
+    let dense: Matrix = design.to_dense();
+    assert_eq!((dense.rows(), dense.cols()), (6, 3));
+
+    // 6. Hankel：H[i][j] = h[i + j]
+    for (m, n) in [(3, 4), (120, 90)] {
+        let col: Vec<f64> = (0..m).map(|_| rng.next_gaussian()).collect();
+        let mut row: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+        row[0] = col[m - 1];
+        let h = Hankel::new(col, row)?;
+        assert_eq!((h.rows(), h.cols()), (m, n));
+        let x: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+        assert!(max_diff(&h.matvec(&x)?, &matvec(&h.to_dense(), &x)?) < 1e-12);
+    }
+    assert!(Hankel::new(vec![1.0, 2.0], vec![3.0, 4.0]).is_err());
+
+    // 7. 作为算子：三对角 Toeplitz (1, 2, 1) 的最大特征值是 2 + 2cos(π/(n+1))
+    let n = 5;
+    let mut col = vec![0.0; n];
+    col[0] = 2.0;
+    col[1] = 1.0;
+    let t = Toeplitz::new(col.clone(), col)?;
+    let top = power_iteration(&t.as_operator()?, &IterationOptions::default())?;
+    let exact = 2.0 + 2.0 * (std::f64::consts::PI / (n + 1) as f64).cos();
+    assert!((top.value - exact).abs() < 1e-8);
+    let h = Hankel::new(vec![1.0, 2.0, 3.0], vec![3.0, 4.0, 5.0])?;
+    let mut y = vec![0.0; 3];
+    h.as_operator()?.apply(&[1.0, 1.0, 1.0], &mut y);
+    assert_eq!(y, vec![6.0, 9.0, 12.0]);
+    // 不是方阵时不能当作算子
+    let wide = Toeplitz::new(vec![1.0, 2.0, 3.0], vec![1.0, 4.0, 5.0, 6.0, 7.0])?;
+    assert!(matches!(wide.as_operator(), Err(MatrixError::NotSquare)));
+    let tall = Hankel::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], vec![5.0, 6.0, 7.0])?;
+    assert!(matches!(tall.as_operator(), Err(MatrixError::NotSquare)));
+
+    Ok(())
+}
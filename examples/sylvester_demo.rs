@@ -0,0 +1,84 @@
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul};
+use lin_alg_lab::schur::{real_schur, schur_eigenvalues};
+use lin_alg_lab::sylvester::{solve_discrete_lyapunov, solve_lyapunov, solve_sylvester};
+
+fn max_abs(a: &Matrix) -> f64 {
+    a.as_slice().iter().fold(0.0, |m, x| m.max(x.abs()))
+}
+
+fn add(a: &Matrix, b: &Matrix, alpha: f64) -> Result<Matrix, MatrixError> {
+    let data = a
+        .as_slice()
+        .iter()
+        .zip(b.as_slice())
+        .map(|(x, y)| x + alpha * y)
+        .collect();
+    Matrix::new(a.rows(), a.cols(), data)
+}
+
+fn main() -> Result<(), MatrixError> {
+    // 1. 实 Schur 分解：A 有一对复特征值 (±2i 附近) 和两个实特征值
+    let a = Matrix::new(
+        4,
+        4,
+        vec![
+            -1.0, 2.0, 0.5, 0.0, //
+            -2.0, -1.0, 0.0, 0.3, //
+            0.1, 0.0, -3.0, 1.0, //
+            0.0, 0.2, 0.0, -4.0,
+        ],
+    )?;
+    let (q, t) = real_schur(&a)?;
+    let qtq = matmul(&q.transpose()?, &q)?;
+    assert!(max_abs(&add(&qtq, &Matrix::identity(4), -1.0)?) < 1e-13);
+    let back = matmul(&matmul(&q, &t)?, &q.transpose()?)?;
+    assert!(max_abs(&add(&back, &a, -1.0)?) < 1e-12);
+    for i in 0..4_usize {
+        for j in 0..i.saturating_sub(1) {
+            assert_eq!(t.get(i, j)?, 0.0);
+        }
+    }
+    let eig = schur_eigenvalues(&t)?;
+    println!("eigenvalues of A = {:?}", eig);
+    assert_eq!(eig.iter().filter(|(_, im)| *im != 0.0).count(), 2);
+    let trace: f64 = eig.iter().map(|(re, _)| re).sum();
+    assert!((trace + 9.0).abs() < 1e-12);
+
+    // 2. Sylvester：AX + XB = C
+    let b = Matrix::new(3, 3, vec![2.0, 1.0, 0.0, -1.0, 3.0, 0.5, 0.0, 0.0, 5.0])?;
+    let c = Matrix::new(4, 3, (0..12).map(|k| (k as f64 * 0.7).sin()).collect())?;
+    let x = solve_sylvester(&a, &b, &c)?;
+    let lhs = add(&matmul(&a, &x)?, &matmul(&x, &b)?, 1.0)?;
+    let res = max_abs(&add(&lhs, &c, -1.0)?);
+    println!("Sylvester residual = {:e}", res);
+    assert!(res < 1e-12);
+
+    // 3. 连续 Lyapunov：A 稳定，Q = I，解是对称正定的
+    let x = solve_lyapunov(&a, &Matrix::identity(4))?;
+    let lhs = add(&matmul(&a, &x)?, &matmul(&x, &a.transpose()?)?, 1.0)?;
+    let res = max_abs(&add(&lhs, &Matrix::identity(4), 1.0)?);
+    println!("continuous Lyapunov residual = {:e}", res);
+    assert!(res < 1e-12);
+    assert!(x.is_symmetric(0.0)?);
+    assert!((0..4).all(|i| x.get(i, i).map(|v| v > 0.0).unwrap_or(false)));
+
+    // 4. 离散 Lyapunov：A 的谱半径 < 1
+    let ad = Matrix::new(3, 3, vec![0.5, 0.4, 0.0, -0.4, 0.5, 0.1, 0.0, 0.2, -0.3])?;
+    let qd = Matrix::new(3, 3, vec![2.0, 0.5, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 1.0])?;
+    let x = solve_discrete_lyapunov(&ad, &qd)?;
+    let axa = matmul(&matmul(&ad, &x)?, &ad.transpose()?)?;
+    let res = max_abs(&add(&add(&axa, &x, -1.0)?, &qd, 1.0)?);
+    println!("discrete Lyapunov residual = {:e}", res);
+    assert!(res < 1e-12);
+
+    // 5. A 与 -B 有公共特征值时没有唯一解
+    let a1 = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 2.0])?;
+    let b1 = Matrix::new(2, 2, vec![-1.0, 0.0, 0.0, 5.0])?;
+    assert!(matches!(
+        solve_sylvester(&a1, &b1, &Matrix::identity(2)),
+        Err(MatrixError::Singular)
+    ));
+
+    println!("Sylvester / Lyapunov demo passed.");
+    Ok(())
+}
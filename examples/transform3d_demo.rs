@@ -0,0 +1,236 @@
+use lin_alg_lab::affine::Affine3;
+use lin_alg_lab::matrix::Matrix4x4;
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul};
+use lin_alg_lab::quaternion::Quaternion;
+use lin_alg_lab::vector3::Vector3;
+use lin_alg_lab::{assert_approx_eq, assert_approx_ne};
+use std::f64::consts::FRAC_PI_2;
+
+fn main() -> Result<(), MatrixError> {
+    let ex = Vector3::new(1.0, 0.0, 0.0);
+    let ey = Vector3::new(0.0, 1.0, 0.0);
+    let ez = Vector3::new(0.0, 0.0, 1.0);
+    let origin = Vector3::zero();
+
+    // 点与方向：平移只影响点
+    let t = Matrix4x4::translation(&Vector3::new(1.0, 2.0, 3.0));
+    let p = Vector3::new(1.0, 1.0, 1.0);
+    assert_approx_eq!(t.transform_point(&p).unwrap(), Vector3::new(2.0, 3.0, 4.0));
+    assert_approx_eq!(t.transform_vector(&p), p);
+
+    // TRS：先缩放 (2, 2, 2)，再绕 z 轴转 90°，最后平移 (1, 2, 3)
+    // ex → (2, 0, 0) → (0, 2, 0) → (1, 4, 3)
+    let qz = Quaternion::from_axis_angle(&ez, FRAC_PI_2).unwrap();
+    let trs = Matrix4x4::from_trs(
+        &Vector3::new(1.0, 2.0, 3.0),
+        &qz,
+        &Vector3::new(2.0, 2.0, 2.0),
+    );
+    assert_approx_eq!(
+        trs,
+        Matrix4x4::new([
+            [0.0, -2.0, 0.0, 1.0],
+            [2.0, 0.0, 0.0, 2.0],
+            [0.0, 0.0, 2.0, 3.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]),
+        abs = 1e-15
+    );
+    assert_approx_eq!(
+        trs.transform_point(&ex).unwrap(),
+        Vector3::new(1.0, 4.0, 3.0),
+        abs = 1e-15
+    );
+    assert_approx_eq!(
+        trs.transform_vector(&ex),
+        Vector3::new(0.0, 2.0, 0.0),
+        abs = 1e-15
+    );
+    assert_approx_eq!(trs.determinant(), 8.0);
+
+    // 逆矩阵、转置、与稠密矩阵互转
+    let inv = trs.inverse()?;
+    assert_approx_eq!(trs * inv, Matrix4x4::identity(), abs = 1e-15);
+    assert_approx_eq!(
+        inv.transform_point(&Vector3::new(1.0, 4.0, 3.0)).unwrap(),
+        ex,
+        abs = 1e-15
+    );
+    let dense = Matrix::from(trs);
+    assert_approx_eq!(
+        Matrix4x4::try_from(&matmul(&dense, &Matrix::from(inv))?)?,
+        Matrix4x4::identity(),
+        abs = 1e-15
+    );
+    assert_approx_eq!(Matrix::from(trs.transpose()), dense.transpose()?);
+    assert!(matches!(
+        Matrix4x4::try_from(&Matrix::identity(3)),
+        Err(MatrixError::DimensionMismatch)
+    ));
+    assert!(matches!(
+        Matrix4x4::scaling(&Vector3::new(1.0, 0.0, 1.0)).inverse(),
+        Err(MatrixError::Singular)
+    ));
+
+    // look_at：相机在 (0, 0, 5) 看向原点，只是平移 (0, 0, -5)
+    let view = Matrix4x4::look_at(&Vector3::new(0.0, 0.0, 5.0), &origin, &ey).unwrap();
+    assert_approx_eq!(view, Matrix4x4::translation(&Vector3::new(0.0, 0.0, -5.0)));
+    // 相机在 (1, 0, 0) 看向原点：右 = (0, 0, -1)，上 = (0, 1, 0)，后 = (1, 0, 0)
+    let view = Matrix4x4::look_at(&ex, &origin, &ey).unwrap();
+    assert_approx_eq!(
+        view,
+        Matrix4x4::new([
+            [0.0, 0.0, -1.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, -1.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    );
+    // 目标点落在相机前方 -z 方向，距离为 1
+    assert_approx_eq!(
+        view.transform_point(&origin).unwrap(),
+        Vector3::new(0.0, 0.0, -1.0)
+    );
+    assert!(Matrix4x4::look_at(&origin, &origin, &ey).is_none());
+    assert!(Matrix4x4::look_at(&ey, &origin, &ey).is_none());
+
+    // 透视：fov 90°、aspect 1、near 1、far 3
+    //   f = 1 / tan 45° = 1，(f + n) / (n - f) = -2，2fn / (n - f) = -3
+    let proj = Matrix4x4::perspective(FRAC_PI_2, 1.0, 1.0, 3.0).unwrap();
+    assert_approx_eq!(
+        proj,
+        Matrix4x4::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -2.0, -3.0],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    );
+    // 近、远平面映射到 NDC 的 -1 和 1；视锥的角落映射到 (±1, ±1)
+    assert_approx_eq!(
+        proj.transform_point(&Vector3::new(0.0, 0.0, -1.0)).unwrap(),
+        Vector3::new(0.0, 0.0, -1.0)
+    );
+    assert_approx_eq!(
+        proj.transform_point(&Vector3::new(3.0, -3.0, -3.0))
+            .unwrap(),
+        Vector3::new(1.0, -1.0, 1.0)
+    );
+    // 中间的 z = -2 不在线性中点上：(−2·−2 − 3) / 2 = 0.5
+    assert_approx_eq!(
+        proj.transform_point(&Vector3::new(0.0, 0.0, -2.0))
+            .unwrap()
+            .z(),
+        0.5
+    );
+    assert_approx_eq!(
+        proj.transform_homogeneous([1.0, 2.0, -2.0, 1.0]).to_vec(),
+        vec![1.0, 2.0, 1.0, 2.0]
+    );
+    // 相机所在平面 z = 0 上的点没有投影
+    assert!(proj.transform_point(&Vector3::new(1.0, 1.0, 0.0)).is_none());
+    let wide = Matrix4x4::perspective(FRAC_PI_2, 2.0, 1.0, 3.0).unwrap();
+    assert_approx_eq!(wide.get(0, 0), 0.5);
+    assert!(Matrix4x4::perspective(FRAC_PI_2, 1.0, 0.0, 3.0).is_none());
+    assert!(Matrix4x4::perspective(FRAC_PI_2, 1.0, 3.0, 1.0).is_none());
+
+    // 正交投影：盒子 [-2, 2] × [-1, 1] × [-10, -0.5]
+    let ortho = Matrix4x4::orthographic(-2.0, 2.0, -1.0, 1.0, 0.5, 10.0).unwrap();
+    assert_approx_eq!(ortho.get(0, 0), 0.5);
+    assert_approx_eq!(ortho.get(2, 2), -2.0 / 9.5);
+    assert_approx_eq!(ortho.get(2, 3), -10.5 / 9.5);
+    assert_approx_eq!(
+        ortho
+            .transform_point(&Vector3::new(2.0, -1.0, -0.5))
+            .unwrap(),
+        Vector3::new(1.0, -1.0, -1.0)
+    );
+    assert_approx_eq!(
+        ortho
+            .transform_point(&Vector3::new(-2.0, 1.0, -10.0))
+            .unwrap(),
+        Vector3::new(-1.0, 1.0, 1.0)
+    );
+    assert!(Matrix4x4::orthographic(1.0, 1.0, -1.0, 1.0, 0.5, 10.0).is_none());
+
+    // 整条流水线：模型 → 观察 → 投影
+    let model = Matrix4x4::from_trs(
+        &Vector3::new(0.0, 0.0, -2.0),
+        &Quaternion::identity(),
+        &Vector3::new(1.0, 1.0, 1.0),
+    );
+    let camera = Matrix4x4::look_at(
+        &Vector3::new(0.0, 0.0, 0.0),
+        &Vector3::new(0.0, 0.0, -1.0),
+        &ey,
+    )
+    .unwrap();
+    let mvp = proj * camera * model;
+    // 模型原点 → 世界 (0, 0, -2) → 相机 (0, 0, -2) → NDC z = 0.5
+    assert_approx_eq!(
+        mvp.transform_point(&origin).unwrap(),
+        Vector3::new(0.0, 0.0, 0.5)
+    );
+
+    // Affine3：与 Matrix4x4 一致的仿射子集
+    let q = Quaternion::from_axis_angle(&Vector3::new(1.0, 2.0, -1.0), 0.8).unwrap();
+    let a = Affine3::from_trs(
+        Vector3::new(3.0, -1.0, 2.0),
+        &q,
+        Vector3::new(1.0, 2.0, 0.5),
+    );
+    assert_approx_eq!(
+        a.to_matrix4(),
+        Matrix4x4::from_trs(
+            &Vector3::new(3.0, -1.0, 2.0),
+            &q,
+            &Vector3::new(1.0, 2.0, 0.5)
+        ),
+        abs = 1e-15
+    );
+    let v = Vector3::new(0.3, -0.7, 1.1);
+    assert_approx_eq!(
+        a.transform_point(&v),
+        a.to_matrix4().transform_point(&v).unwrap(),
+        abs = 1e-15
+    );
+    assert_approx_eq!(
+        a.transform_vector(&v),
+        a.to_matrix4().transform_vector(&v),
+        abs = 1e-15
+    );
+    assert_approx_eq!(a.determinant(), 1.0);
+    let ai = a.inverse()?;
+    assert_approx_eq!(a * ai, Affine3::identity(), abs = 1e-14);
+    assert_approx_eq!(ai.to_matrix4(), a.to_matrix4().inverse()?, abs = 1e-14);
+    assert!(matches!(
+        Affine3::scaling(Vector3::new(1.0, 1.0, 0.0)).inverse(),
+        Err(MatrixError::Singular)
+    ));
+    // 合成顺序
+    let r = Affine3::rotation(&qz);
+    let tr = Affine3::translation(ex);
+    assert_approx_eq!(
+        r.then(&tr).transform_point(&ex),
+        Vector3::new(1.0, 1.0, 0.0),
+        abs = 1e-15
+    );
+    assert_approx_eq!(
+        tr.then(&r).transform_point(&ex),
+        Vector3::new(0.0, 2.0, 0.0),
+        abs = 1e-15
+    );
+    assert_approx_ne!(r.then(&tr), tr.then(&r));
+    // 观察变换是刚体运动
+    let cam = Affine3::look_at(&Vector3::new(4.0, 3.0, 2.0), &origin, &ez).unwrap();
+    assert_approx_eq!(cam.determinant(), 1.0);
+    assert_approx_eq!(
+        cam.transform_point(&Vector3::new(4.0, 3.0, 2.0)),
+        origin,
+        abs = 1e-15
+    );
+
+    println!("MVP = {:?}", mvp);
+    println!("all 3D transform checks passed");
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matmul, matvec};
+use lin_alg_lab::triangular::{LowerTriangular, UpperTriangular};
+
+fn max_diff(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).fold(0.0, |m, (x, y)| m.max((x - y).abs()))
+}
+
+fn main() -> Result<(), MatrixError> {
+    let a = Matrix::new(
+        3,
+        3,
+        vec![
+            2.0, 9.0, 9.0, // 对角线以上的元素会被清零
+            -1.0, 3.0, 9.0, //
+            4.0, 0.5, -2.0,
+        ],
+    )?;
+    let l = LowerTriangular::new(a.clone())?;
+    let u = UpperTriangular::new(a.transpose()?)?;
+    assert_eq!(l.get(0, 1)?, 0.0);
+    assert_eq!(l.determinant(), 2.0 * 3.0 * -2.0);
+    assert_eq!(u.determinant(), l.determinant());
+    assert!(LowerTriangular::new(Matrix::zeros(2, 3)).is_err());
+
+    // solve / solve_transpose
+    let b = vec![1.0, 2.0, 3.0];
+    let x = l.solve(&b)?;
+    assert!(max_diff(&matvec(l.as_matrix(), &x)?, &b) < 1e-14);
+    let x = l.solve_transpose(&b)?;
+    assert!(max_diff(&matvec(&l.as_matrix().transpose()?, &x)?, &b) < 1e-14);
+    let x = u.solve(&b)?;
+    assert!(max_diff(&matvec(u.as_matrix(), &x)?, &b) < 1e-14);
+    assert!(max_diff(&u.solve_transpose(&b)?, &l.solve(&b)?) < 1e-14);
+
+    // 多右端项
+    let rhs = Matrix::new(3, 2, vec![1.0, 0.0, 2.0, 1.0, 3.0, -1.0])?;
+    let x = u.solve_matrix(&rhs)?;
+    assert!(max_diff(matmul(u.as_matrix(), &x)?.as_slice(), rhs.as_slice()) < 1e-14);
+
+    // 逆：仍是下三角，且 L L⁻¹ = I
+    let inv = l.inverse()?;
+    assert_eq!(inv.get(0, 2)?, 0.0);
+    let prod = matmul(l.as_matrix(), inv.as_matrix())?;
+    assert!(max_diff(prod.as_slice(), Matrix::identity(3).as_slice()) < 1e-14);
+    assert!((inv.determinant() * l.determinant() - 1.0).abs() < 1e-14);
+
+    // 单位三角：对角元恒为 1，行列式为 1
+    let unit = LowerTriangular::unit(a.clone())?;
+    assert!(unit.is_unit_diagonal() && unit.inverse()?.is_unit_diagonal());
+    assert_eq!(unit.determinant(), 1.0);
+    assert_eq!(unit.get(2, 2)?, 1.0);
+
+    // 对角元为 0 时无法求解
+    let singular = UpperTriangular::new(Matrix::new(2, 2, vec![1.0, 2.0, 0.0, 0.0])?)?;
+    assert!(matches!(
+        singular.solve(&[1.0, 1.0]),
+        Err(MatrixError::Singular)
+    ));
+    assert_eq!(singular.determinant(), 0.0);
+
+    // lu_decomposition 直接返回三角类型：det(A) = det(U)
+    let m = Matrix::new(3, 3, vec![4.0, 3.0, 2.0, 6.0, 3.0, 1.0, 2.0, 5.0, 7.0])?;
+    let (l, u) = m.lu_decomposition()?;
+    assert!(l.is_unit_diagonal());
+    println!("det(A) = {}", u.determinant());
+    assert!((u.determinant() - (-8.0)).abs() < 1e-12);
+    let x = Matrix::solve_lu(&l, &u, &b)?;
+    assert!(max_diff(&matvec(&m, &x)?, &b) < 1e-13);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use lin_alg_lab::units::{
-    LengthUnit, Quantity, QuantityError, add, convert, to_meters, unit_to_meter_factor,
+    LengthUnit, Quantity, QuantityError, add, convert, to_meters,
 };
 
 fn main() -> Result<(), QuantityError> {
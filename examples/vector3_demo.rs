@@ -0,0 +1,65 @@
+use lin_alg_lab::assert_approx_eq;
+use lin_alg_lab::vector3::{
+    Vector3, angle_between, cross, distance, dot, norm, project_onto, triple_product,
+};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+fn main() {
+    let e1 = Vector3::new(1.0, 0.0, 0.0);
+    let e2 = Vector3::new(0.0, 1.0, 0.0);
+    let e3 = Vector3::new(0.0, 0.0, 1.0);
+
+    // 运算符
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::from((4.0, -5.0, 6.0));
+    assert_approx_eq!(a + b, Vector3::new(5.0, -3.0, 9.0));
+    assert_approx_eq!(a - b, Vector3::new(-3.0, 7.0, -3.0));
+    assert_approx_eq!(-a, Vector3::new(-1.0, -2.0, -3.0));
+    assert_approx_eq!(a * 2.0, 2.0 * a);
+    println!("a = {:?}, b = {:?}", a, b);
+
+    // 叉积：右手定则、反交换、与两个因子都垂直
+    assert_approx_eq!(cross(&e1, &e2), e3);
+    assert_approx_eq!(cross(&e2, &e3), e1);
+    assert_approx_eq!(cross(&e3, &e1), e2);
+    let c = cross(&a, &b);
+    println!("a × b = {:?}", c);
+    assert_approx_eq!(c, -cross(&b, &a));
+    assert_approx_eq!(dot(&c, &a), 0.0);
+    assert_approx_eq!(dot(&c, &b), 0.0);
+    // ‖a × b‖² + (a · b)² = ‖a‖²‖b‖²（Lagrange 恒等式）
+    assert_approx_eq!(
+        c.length_sq() + dot(&a, &b).powi(2),
+        a.length_sq() * b.length_sq()
+    );
+
+    // 范数、距离、归一化
+    assert_approx_eq!(norm(&Vector3::new(2.0, 3.0, 6.0)), 7.0);
+    assert_approx_eq!(distance(&a, &b), norm(&(a - b)));
+    let u = b.normalize().unwrap();
+    assert_approx_eq!(norm(&u), 1.0);
+    assert!(Vector3::zero().normalize().is_none());
+
+    // 投影：余量与 basis 垂直；零 basis 返回零向量
+    let p = project_onto(&a, &b);
+    assert_approx_eq!(dot(&(a - p), &b), 0.0, abs = 1e-12);
+    assert_approx_eq!(project_onto(&a, &Vector3::zero()), Vector3::zero());
+
+    // 三重积 = 行列式 = 有向体积
+    assert_approx_eq!(triple_product(&e1, &e2, &e3), 1.0);
+    assert_approx_eq!(triple_product(&e2, &e1, &e3), -1.0);
+    assert_approx_eq!(triple_product(&a, &b, &(a + b)), 0.0, abs = 1e-12);
+    assert_approx_eq!(triple_product(&a, &b, &c), c.length_sq());
+
+    // 夹角
+    assert_approx_eq!(angle_between(&e1, &e2).unwrap(), FRAC_PI_2);
+    assert_approx_eq!(angle_between(&e1, &(e1 + e2)).unwrap(), FRAC_PI_4);
+    assert_approx_eq!(angle_between(&e1, &(-e1 * 3.0)).unwrap(), PI);
+    // 极小夹角：acos 公式在这里只剩 8 位左右有效数字
+    let tiny = angle_between(&e1, &Vector3::new(1.0, 1e-9, 0.0)).unwrap();
+    assert_approx_eq!(tiny, 1e-9, rel = 1e-12);
+    assert!(angle_between(&e1, &Vector3::zero()).is_none());
+    println!("angle(a, b) = {:.6} rad", angle_between(&a, &b).unwrap());
+
+    println!("all Vector3 checks passed");
+}
@@ -0,0 +1,110 @@
+use lin_alg_lab::assert_approx_eq;
+use lin_alg_lab::matrix::Matrix2x2;
+use lin_alg_lab::matrix_dense::{Matrix, MatrixError, matvec};
+use lin_alg_lab::path::{cumulative_lengths, polyline_length};
+use lin_alg_lab::vector::Vector2;
+use lin_alg_lab::vector_n::{
+    VectorN, component_max, component_min, distance, dot, lerp, norm, project_onto,
+};
+use lin_alg_lab::vector3::Vector3;
+
+fn main() -> Result<(), MatrixError> {
+    let a = VectorN::new([1.0, 2.0, 3.0, 4.0]);
+    let b = VectorN::from([4.0, -3.0, 2.0, -1.0]);
+
+    // 运算符（含复合赋值）
+    assert_eq!(a + b, VectorN::new([5.0, -1.0, 5.0, 3.0]));
+    assert_eq!(a - b, VectorN::new([-3.0, 5.0, 1.0, 5.0]));
+    assert_eq!(-a, a * -1.0);
+    assert_eq!(2.0 * a, VectorN::new([2.0, 4.0, 6.0, 8.0]));
+    assert_eq!(a / 2.0, a * 0.5);
+    let mut c = a;
+    c += b;
+    c -= a;
+    c *= 3.0;
+    c /= 3.0;
+    assert_eq!(c, b);
+
+    // 点积、范数、距离、归一化、投影
+    assert_eq!(dot(&a, &b), 0.0);
+    assert_approx_eq!(norm(&a), 30.0_f64.sqrt());
+    assert_approx_eq!(distance(&a, &b), norm(&(a - b)));
+    assert_approx_eq!(norm(&b.normalize().unwrap()), 1.0);
+    assert!(VectorN::<7>::zero().normalize().is_none());
+    assert_approx_eq!(project_onto(&a, &b), VectorN::zero());
+    let p = project_onto(&a, &(a + b));
+    assert_approx_eq!(dot(&(a - p), &(a + b)), 0.0, abs = 1e-12);
+
+    // 逐分量 min/max、插值
+    assert_eq!(component_min(&a, &b), VectorN::new([1.0, -3.0, 2.0, -1.0]));
+    assert_eq!(component_max(&a, &b), VectorN::new([4.0, 2.0, 3.0, 4.0]));
+    assert_eq!(lerp(&a, &b, 0.0), a);
+    assert_eq!(lerp(&a, &b, 1.0), b);
+    assert_approx_eq!(lerp(&a, &b, 0.25), a * 0.75 + b * 0.25);
+
+    // 索引与迭代
+    let mut d = VectorN::<5>::from_fn(|i| i as f64);
+    d[0] = 10.0;
+    assert_eq!(d[0] + d[4], 14.0);
+    assert_eq!(d.iter().sum::<f64>(), 20.0);
+    for x in d.iter_mut() {
+        *x *= 2.0;
+    }
+    assert_eq!(
+        d.into_iter().collect::<Vec<_>>(),
+        vec![20.0, 2.0, 4.0, 6.0, 8.0]
+    );
+    assert_eq!((&d).into_iter().count(), d.dim());
+    assert_eq!(VectorN::<3>::unit(1), Some(VectorN::new([0.0, 1.0, 0.0])));
+    assert_eq!(VectorN::<3>::unit(3), None);
+
+    // 与切片、Vector2/Vector3 的转换
+    assert!(VectorN::<3>::try_from(&[1.0, 2.0, 3.0][..]).is_ok());
+    assert!(matches!(
+        VectorN::<3>::try_from(&[1.0, 2.0][..]),
+        Err(MatrixError::DimensionMismatch)
+    ));
+    let v2 = Vector2::new(3.0, 4.0);
+    let n2: VectorN<2> = v2.into();
+    assert_eq!(norm(&n2), 5.0);
+    let back: Vector2 = n2.into();
+    assert_eq!((back.x(), back.y()), (3.0, 4.0));
+    let n3: VectorN<3> = Vector3::new(1.0, 2.0, 2.0).into();
+    assert_eq!(Vector3::from(n3).z(), 2.0);
+
+    // Matrix2x2 作用在 VectorN<2> 上与作用在 Vector2 上一致
+    let r = Matrix2x2::rotation(0.7);
+    assert_approx_eq!(Vector2::from(r * n2), r * v2);
+
+    // 稠密矩阵通过切片接口使用 VectorN
+    let m = Matrix::new(2, 4, vec![1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0])?;
+    let y: VectorN<2> = matvec(&m, a.as_slice())?.as_slice().try_into()?;
+    assert_eq!(y, VectorN::new([5.0, 5.0]));
+
+    // path 中的函数对任意维数的点都能用
+    let square = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, 1.0),
+    ];
+    assert_approx_eq!(polyline_length(&square), 2.0);
+    let space = [
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 2.0, 2.0),
+        Vector3::new(1.0, 2.0, 6.0),
+    ];
+    assert_approx_eq!(cumulative_lengths(&space), vec![0.0, 3.0, 7.0]);
+    let hyper = [
+        VectorN::<4>::zero(),
+        VectorN::splat(1.0),
+        VectorN::splat(2.0),
+    ];
+    assert_approx_eq!(polyline_length(&hyper), 4.0);
+    println!(
+        "4D polyline cumulative lengths = {:?}",
+        cumulative_lengths(&hyper)
+    );
+
+    println!("all VectorN checks passed");
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use crate::matrix::{Matrix2x2, Matrix4x4};
+use crate::matrix_dense::{Matrix, MatrixError};
+use crate::quaternion::Quaternion;
+use crate::vector::Vector2;
+use crate::vector3::Vector3;
+use std::ops::Mul;
+
+/// 二维仿射变换 p ↦ L p + t，L 是 2 × 2 线性部分，t 是平移。
+///
+/// 等价于齐次坐标下的 3 × 3 矩阵
+///
+/// [ L  t ]
+/// [ 0  1 ]
+///
+/// 合成用 `*`：`a * b` 表示先做 b 再做 a，与矩阵乘法的顺序一致；
+/// 也可以用 `then` 按“先后”顺序书写。
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Affine2 {
+    linear: Matrix2x2,
+    translation: Vector2,
+}
+
+/// `Affine2::decompose` 的结果：A = T(translation) · R(rotation) · H(shear) · S(scale)，
+/// 其中 H = [1 shear; 0 1] 是沿 x 方向的错切，S = diag(scale)。
+///
+/// 没有错切和反射的变换（刚体运动加正的缩放）分解出来 shear = 0、scale 两个分量都为正；
+/// 含反射时 scale.y() < 0。
+#[derive(Debug, Clone, Copy)]
+pub struct AffineDecomposition {
+    pub translation: Vector2,
+    pub rotation: f64,
+    pub shear: f64,
+    pub scale: Vector2,
+}
+
+impl Affine2 {
+    pub fn new(linear: Matrix2x2, translation: Vector2) -> Self {
+        Self {
+            linear,
+            translation,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(Matrix2x2::identity(), Vector2::new(0.0, 0.0))
+    }
+
+    /// 平移 p ↦ p + t。
+    pub fn translation(t: Vector2) -> Self {
+        Self::new(Matrix2x2::identity(), t)
+    }
+
+    /// 绕原点逆时针旋转 theta 弧度。
+    pub fn rotation(theta: f64) -> Self {
+        Self::from_linear(Matrix2x2::rotation(theta))
+    }
+
+    /// 绕 center 旋转：T(c) · R(θ) · T(-c)。
+    pub fn rotation_about(center: Vector2, theta: f64) -> Self {
+        Self::translation(center) * Self::rotation(theta) * Self::translation(-center)
+    }
+
+    /// 沿坐标轴缩放 (sx, sy)。
+    pub fn scaling(sx: f64, sy: f64) -> Self {
+        Self::from_linear(Matrix2x2::scaling(sx, sy))
+    }
+
+    /// 错切：x' = x + kx·y，y' = ky·x + y。
+    pub fn shear(kx: f64, ky: f64) -> Self {
+        Self::from_linear(Matrix2x2::shear(kx, ky))
+    }
+
+    /// 关于过原点、方向为 direction 的直线做镜像；direction 太接近零向量时返回 None。
+    pub fn reflection(direction: Vector2) -> Option<Self> {
+        Matrix2x2::reflection(direction).map(Self::from_linear)
+    }
+
+    fn from_linear(linear: Matrix2x2) -> Self {
+        Self::new(linear, Vector2::new(0.0, 0.0))
+    }
+
+    pub fn linear(&self) -> Matrix2x2 {
+        self.linear
+    }
+
+    pub fn translation_part(&self) -> Vector2 {
+        self.translation
+    }
+
+    /// 先做 self 再做 next，即 next * self。
+    pub fn then(&self, next: &Affine2) -> Affine2 {
+        *next * *self
+    }
+
+    /// 变换一个点：L p + t。
+    pub fn transform_point(&self, p: &Vector2) -> Vector2 {
+        self.linear * p + self.translation
+    }
+
+    /// 变换一个方向（位移）向量：只作用线性部分，平移对方向没有意义。
+    pub fn transform_vector(&self, v: &Vector2) -> Vector2 {
+        self.linear * v
+    }
+
+    /// 线性部分的行列式：面积的缩放倍数，负数表示包含反射。
+    pub fn determinant(&self) -> f64 {
+        self.linear.determinant()
+    }
+
+    /// 逆变换 p ↦ L⁻¹ (p - t)；L 奇异时返回 `MatrixError::Singular`。
+    pub fn inverse(&self) -> Result<Affine2, MatrixError> {
+        let inv = self.linear.inverse()?;
+        Ok(Self::new(inv, -(inv * self.translation)))
+    }
+
+    /// 分解为平移、旋转、错切和缩放，见 `AffineDecomposition`；L 奇异时返回 `MatrixError::Singular`。
+    ///
+    /// 做法是对 L 做 2 × 2 的 QR 分解：L = R(θ) · [sx  k·sy; 0  sy]。
+    /// 第一列决定 θ 和 sx，剩下的上三角部分给出错切与 y 方向的缩放。
+    pub fn decompose(&self) -> Result<AffineDecomposition, MatrixError> {
+        let l = &self.linear;
+        let sx = l.m11().hypot(l.m21());
+        if sx == 0.0 {
+            return Err(MatrixError::Singular);
+        }
+        let (c, s) = (l.m11() / sx, l.m21() / sx);
+        // Rᵀ L 的第二列 = (k·sy, sy)
+        let r12 = c * l.m12() + s * l.m22();
+        let sy = -s * l.m12() + c * l.m22();
+        if sy.abs() <= 1e-12 * sx.max(r12.abs()) {
+            return Err(MatrixError::Singular);
+        }
+        Ok(AffineDecomposition {
+            translation: self.translation,
+            rotation: s.atan2(c),
+            shear: r12 / sy,
+            scale: Vector2::new(sx, sy),
+        })
+    }
+
+    /// 由分解结果重新组合：T · R · H · S。
+    pub fn compose(parts: &AffineDecomposition) -> Affine2 {
+        Self::translation(parts.translation)
+            * Self::rotation(parts.rotation)
+            * Self::shear(parts.shear, 0.0)
+            * Self::scaling(parts.scale.x(), parts.scale.y())
+    }
+
+    /// 齐次坐标下的 3 × 3 矩阵。
+    pub fn to_homogeneous(&self) -> Matrix {
+        let (l, t) = (&self.linear, &self.translation);
+        Matrix::new(
+            3,
+            3,
+            vec![
+                l.m11(),
+                l.m12(),
+                t.x(),
+                l.m21(),
+                l.m22(),
+                t.y(),
+                0.0,
+                0.0,
+                1.0,
+            ],
+        )
+        .expect("3 × 3 data")
+    }
+}
+
+/// 合成：(a * b)(p) = a(b(p))。
+impl Mul for Affine2 {
+    type Output = Affine2;
+
+    fn mul(self, rhs: Affine2) -> Self::Output {
+        Affine2::new(
+            self.linear * rhs.linear,
+            self.linear * rhs.translation + self.translation,
+        )
+    }
+}
+
+/// 三维仿射变换 p ↦ L p + t，L 是 3 × 3 线性部分（按行存放），t 是平移。
+///
+/// 与 `Affine2` 的约定相同：`a * b` 先做 b 再做 a，`then` 按先后顺序书写。
+/// 需要投影时用 `to_matrix4` 转成 `Matrix4x4` 再与投影矩阵相乘。
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Affine3 {
+    linear: [[f64; 3]; 3],
+    translation: Vector3,
+}
+
+impl Affine3 {
+    pub fn new(linear: [[f64; 3]; 3], translation: Vector3) -> Self {
+        Self {
+            linear,
+            translation,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::from_linear([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// 平移 p ↦ p + t。
+    pub fn translation(t: Vector3) -> Self {
+        Self::new(Self::identity().linear, t)
+    }
+
+    /// 单位四元数 q 对应的旋转。
+    pub fn rotation(q: &Quaternion) -> Self {
+        let r = q.to_rotation_matrix();
+        let d = r.as_slice();
+        Self::from_linear(std::array::from_fn(|i| {
+            std::array::from_fn(|j| d[i * 3 + j])
+        }))
+    }
+
+    /// 沿坐标轴缩放 (sx, sy, sz)。
+    pub fn scaling(s: Vector3) -> Self {
+        Self::from_linear([[s.x(), 0.0, 0.0], [0.0, s.y(), 0.0], [0.0, 0.0, s.z()]])
+    }
+
+    /// T · R · S：先缩放，再旋转，最后平移。
+    pub fn from_trs(t: Vector3, r: &Quaternion, s: Vector3) -> Self {
+        Self::translation(t) * Self::rotation(r) * Self::scaling(s)
+    }
+
+    /// 观察变换（刚体运动），含义与 `Matrix4x4::look_at` 相同；退化时返回 None。
+    pub fn look_at(eye: &Vector3, target: &Vector3, up: &Vector3) -> Option<Self> {
+        let m = Matrix4x4::look_at(eye, target, up)?;
+        Some(Self::new(
+            std::array::from_fn(|i| std::array::from_fn(|j| m.get(i, j))),
+            Vector3::new(m.get(0, 3), m.get(1, 3), m.get(2, 3)),
+        ))
+    }
+
+    fn from_linear(linear: [[f64; 3]; 3]) -> Self {
+        Self::new(linear, Vector3::zero())
+    }
+
+    pub fn linear(&self) -> [[f64; 3]; 3] {
+        self.linear
+    }
+
+    pub fn translation_part(&self) -> Vector3 {
+        self.translation
+    }
+
+    /// 先做 self 再做 next，即 next * self。
+    pub fn then(&self, next: &Affine3) -> Affine3 {
+        *next * *self
+    }
+
+    /// 变换一个点：L p + t。
+    pub fn transform_point(&self, p: &Vector3) -> Vector3 {
+        self.transform_vector(p) + self.translation
+    }
+
+    /// 变换一个方向向量：只作用线性部分。
+    pub fn transform_vector(&self, v: &Vector3) -> Vector3 {
+        let l = &self.linear;
+        let row = |i: usize| l[i][0] * v.x() + l[i][1] * v.y() + l[i][2] * v.z();
+        Vector3::new(row(0), row(1), row(2))
+    }
+
+    /// 线性部分的行列式：体积的缩放倍数，负数表示包含反射。
+    pub fn determinant(&self) -> f64 {
+        let l = &self.linear;
+        l[0][0] * (l[1][1] * l[2][2] - l[1][2] * l[2][1])
+            - l[0][1] * (l[1][0] * l[2][2] - l[1][2] * l[2][0])
+            + l[0][2] * (l[1][0] * l[2][1] - l[1][1] * l[2][0])
+    }
+
+    /// 逆变换 p ↦ L⁻¹ (p - t)；L 奇异时返回 `MatrixError::Singular`。
+    ///
+    /// L⁻¹ = adj(L) / det(L)，adj(L) 的第 j 列是 L 的另外两行的叉积。
+    pub fn inverse(&self) -> Result<Affine3, MatrixError> {
+        let det = self.determinant();
+        let l = &self.linear;
+        let scale = l.iter().flatten().fold(0.0_f64, |s, x| s.max(x.abs()));
+        if det.abs() <= 1e-12 * scale.powi(3) {
+            return Err(MatrixError::Singular);
+        }
+        let inv: [[f64; 3]; 3] = std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                // (L⁻¹)ᵢⱼ = Cⱼᵢ / det，Cⱼᵢ 是 L 第 j 行第 i 列的代数余子式
+                let (r1, r2) = ((j + 1) % 3, (j + 2) % 3);
+                let (c1, c2) = ((i + 1) % 3, (i + 2) % 3);
+                (l[r1][c1] * l[r2][c2] - l[r1][c2] * l[r2][c1]) / det
+            })
+        });
+        let linear_inv = Self::from_linear(inv);
+        Ok(Self::new(
+            inv,
+            -linear_inv.transform_vector(&self.translation),
+        ))
+    }
+
+    /// 对应的 4 × 4 齐次矩阵（最后一行为 0 0 0 1）。
+    pub fn to_matrix4(&self) -> Matrix4x4 {
+        let (l, t) = (&self.linear, &self.translation);
+        Matrix4x4::new([
+            [l[0][0], l[0][1], l[0][2], t.x()],
+            [l[1][0], l[1][1], l[1][2], t.y()],
+            [l[2][0], l[2][1], l[2][2], t.z()],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+/// 合成：(a * b)(p) = a(b(p))。
+impl Mul for Affine3 {
+    type Output = Affine3;
+
+    fn mul(self, rhs: Affine3) -> Self::Output {
+        let (a, b) = (&self.linear, &rhs.linear);
+        Affine3::new(
+            std::array::from_fn(|i| {
+                std::array::from_fn(|j| (0..3).map(|k| a[i][k] * b[k][j]).sum())
+            }),
+            self.transform_point(&rhs.translation),
+        )
+    }
+}
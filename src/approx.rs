@@ -0,0 +1,385 @@
+use crate::affine::{Affine2, Affine3};
+use crate::matrix::{Matrix2x2, Matrix4x4};
+use crate::matrix_dense::Matrix;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector2;
+use crate::vector_n::VectorN;
+use crate::vector3::Vector3;
+
+/// 浮点比较的容差：三个判据满足任意一个就视为相等。
+///
+/// - abs: |a - b| <= abs，适合期望值接近 0 的情况；
+/// - rel: |a - b| <= rel · max(|a|, |b|)，适合数量级不确定的情况；
+/// - ulps: a、b 之间相隔的可表示浮点数个数 <= ulps，最严格，适合“理论上应逐位相等”的结果。
+///
+/// NaN 与任何值（包括 NaN）都不相等；同号的无穷大彼此相等。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    pub abs: f64,
+    pub rel: f64,
+    pub ulps: u64,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            abs: 1e-12,
+            rel: 1e-12,
+            ulps: 4,
+        }
+    }
+}
+
+impl Tolerance {
+    /// 只用绝对误差判断。
+    pub fn absolute(abs: f64) -> Self {
+        Self {
+            abs,
+            rel: 0.0,
+            ulps: 0,
+        }
+    }
+
+    /// 只用相对误差判断。
+    pub fn relative(rel: f64) -> Self {
+        Self {
+            abs: 0.0,
+            rel,
+            ulps: 0,
+        }
+    }
+
+    /// 只用 ULP 距离判断。
+    pub fn ulps(ulps: u64) -> Self {
+        Self {
+            abs: 0.0,
+            rel: 0.0,
+            ulps,
+        }
+    }
+
+    /// 两个标量在这个容差下是否相等。
+    pub fn is_close(&self, a: f64, b: f64) -> bool {
+        if a == b {
+            return true;
+        }
+        if a.is_nan() || b.is_nan() || a.is_infinite() || b.is_infinite() {
+            return false;
+        }
+        let diff = (a - b).abs();
+        diff <= self.abs
+            || diff <= self.rel * a.abs().max(b.abs())
+            || ulp_distance(a, b) <= self.ulps
+    }
+}
+
+/// a 和 b 之间相隔多少个可表示的 f64（+0.0 与 -0.0 的距离为 0）。
+pub fn ulp_distance(a: f64, b: f64) -> u64 {
+    if a.is_nan() || b.is_nan() {
+        return u64::MAX;
+    }
+    // 把位模式映射成单调的整数：负数翻转，使整数顺序与浮点数顺序一致
+    let ordered = |x: f64| {
+        let bits = x.to_bits() as i64;
+        if bits < 0 { i64::MIN - bits } else { bits }
+    };
+    let d = (ordered(a) as i128 - ordered(b) as i128).unsigned_abs();
+    d.min(u64::MAX as u128) as u64
+}
+
+/// 近似比较失败时的详细信息。
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mismatch {
+    /// 形状不同（矩阵维数、切片长度），无法逐元素比较
+    Shape { left: String, right: String },
+    /// 误差最大的那个元素
+    Value {
+        location: String,
+        left: f64,
+        right: f64,
+    },
+}
+
+impl std::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mismatch::Shape { left, right } => write!(f, "shape {} vs {}", left, right),
+            Mismatch::Value {
+                location,
+                left,
+                right,
+            } => write!(
+                f,
+                "{}{}: {:?} vs {:?} (|diff| = {:e}, {} ulps)",
+                if location.is_empty() { "value" } else { "at " },
+                location,
+                left,
+                right,
+                (left - right).abs(),
+                ulp_distance(*left, *right),
+            ),
+        }
+    }
+}
+
+/// 带容差的相等比较。
+///
+/// 只需要实现 `worst_mismatch`：返回误差最大的不相等元素，全部相等时返回 None。
+pub trait ApproxEq {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch>;
+
+    fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool {
+        self.worst_mismatch(other, tol).is_none()
+    }
+}
+
+impl<T: ApproxEq + ?Sized> ApproxEq for &T {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        (**self).worst_mismatch(*other, tol)
+    }
+}
+
+impl ApproxEq for f64 {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        worst(std::iter::once((String::new(), *self, *other)), tol)
+    }
+}
+
+impl ApproxEq for [f64] {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        if self.len() != other.len() {
+            return Some(Mismatch::Shape {
+                left: format!("[{}]", self.len()),
+                right: format!("[{}]", other.len()),
+            });
+        }
+        worst(
+            self.iter()
+                .zip(other)
+                .enumerate()
+                .map(|(i, (a, b))| (format!("[{}]", i), *a, *b)),
+            tol,
+        )
+    }
+}
+
+impl ApproxEq for Vec<f64> {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        self.as_slice().worst_mismatch(other.as_slice(), tol)
+    }
+}
+
+impl ApproxEq for Vector2 {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        worst(
+            [
+                ("x".to_string(), self.x(), other.x()),
+                ("y".to_string(), self.y(), other.y()),
+            ],
+            tol,
+        )
+    }
+}
+
+impl ApproxEq for Vector3 {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        worst(
+            [
+                ("x".to_string(), self.x(), other.x()),
+                ("y".to_string(), self.y(), other.y()),
+                ("z".to_string(), self.z(), other.z()),
+            ],
+            tol,
+        )
+    }
+}
+
+impl<const N: usize> ApproxEq for VectorN<N> {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        self.as_slice().worst_mismatch(other.as_slice(), tol)
+    }
+}
+
+impl ApproxEq for Matrix2x2 {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        worst(
+            [
+                ("m11".to_string(), self.m11(), other.m11()),
+                ("m12".to_string(), self.m12(), other.m12()),
+                ("m21".to_string(), self.m21(), other.m21()),
+                ("m22".to_string(), self.m22(), other.m22()),
+            ],
+            tol,
+        )
+    }
+}
+
+impl ApproxEq for Quaternion {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        worst(
+            [
+                ("w".to_string(), self.w(), other.w()),
+                ("x".to_string(), self.x(), other.x()),
+                ("y".to_string(), self.y(), other.y()),
+                ("z".to_string(), self.z(), other.z()),
+            ],
+            tol,
+        )
+    }
+}
+
+impl ApproxEq for Affine2 {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        let (l, r) = (self.linear(), other.linear());
+        let (t, u) = (self.translation_part(), other.translation_part());
+        worst(
+            [
+                ("m11".to_string(), l.m11(), r.m11()),
+                ("m12".to_string(), l.m12(), r.m12()),
+                ("m21".to_string(), l.m21(), r.m21()),
+                ("m22".to_string(), l.m22(), r.m22()),
+                ("tx".to_string(), t.x(), u.x()),
+                ("ty".to_string(), t.y(), u.y()),
+            ],
+            tol,
+        )
+    }
+}
+
+impl ApproxEq for Matrix4x4 {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        let (a, b) = (self.to_array(), other.to_array());
+        worst(
+            (0..16).map(|k| {
+                let (i, j) = (k / 4, k % 4);
+                (format!("({}, {})", i, j), a[i][j], b[i][j])
+            }),
+            tol,
+        )
+    }
+}
+
+impl ApproxEq for Affine3 {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        self.to_matrix4().worst_mismatch(&other.to_matrix4(), tol)
+    }
+}
+
+impl ApproxEq for Matrix {
+    fn worst_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        if self.rows() != other.rows() || self.cols() != other.cols() {
+            return Some(Mismatch::Shape {
+                left: format!("{}×{}", self.rows(), self.cols()),
+                right: format!("{}×{}", other.rows(), other.cols()),
+            });
+        }
+        let cols = self.cols().max(1);
+        worst(
+            self.as_slice()
+                .iter()
+                .zip(other.as_slice())
+                .enumerate()
+                .map(|(k, (a, b))| (format!("({}, {})", k / cols, k % cols), *a, *b)),
+            tol,
+        )
+    }
+}
+
+/// 在所有不满足容差的元素里挑出 |a - b| 最大的一个（NaN 视为最大）。
+fn worst(
+    entries: impl IntoIterator<Item = (String, f64, f64)>,
+    tol: Tolerance,
+) -> Option<Mismatch> {
+    let mut result: Option<(f64, Mismatch)> = None;
+    for (location, left, right) in entries {
+        if tol.is_close(left, right) {
+            continue;
+        }
+        let diff = (left - right).abs();
+        let diff = if diff.is_nan() { f64::INFINITY } else { diff };
+        if result.as_ref().is_none_or(|(d, _)| diff > *d) {
+            result = Some((
+                diff,
+                Mismatch::Value {
+                    location,
+                    left,
+                    right,
+                },
+            ));
+        }
+    }
+    result.map(|(_, m)| m)
+}
+
+/// 断言两个值在容差内相等，失败时报告误差最大的元素。
+///
+/// ```
+/// use lin_alg_lab::assert_approx_eq;
+/// use lin_alg_lab::approx::Tolerance;
+///
+/// assert_approx_eq!(0.1 + 0.2, 0.3);
+/// assert_approx_eq!(vec![1.0, 2.0], vec![1.0, 2.0 + 1e-9], abs = 1e-8);
+/// assert_approx_eq!(1.0_f64, 1.0 + f64::EPSILON, Tolerance::ulps(1));
+/// ```
+///
+/// 容差可以省略（使用 `Tolerance::default()`），也可以写成 `abs = ..`、`rel = ..`、`ulps = ..`，
+/// 或直接传一个 `Tolerance`。
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::approx::Tolerance::default())
+    };
+    ($left:expr, $right:expr, abs = $tol:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::approx::Tolerance::absolute($tol))
+    };
+    ($left:expr, $right:expr, rel = $tol:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::approx::Tolerance::relative($tol))
+    };
+    ($left:expr, $right:expr, ulps = $tol:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::approx::Tolerance::ulps($tol))
+    };
+    ($left:expr, $right:expr, $tol:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Some(m) = $crate::approx::ApproxEq::worst_mismatch(left, right, $tol) {
+                    panic!(
+                        "assertion `left ≈ right` failed\n  left: {}\n right: {}\n worst: {}",
+                        stringify!($left),
+                        stringify!($right),
+                        m
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// 断言两个值在容差下不相等（参数形式与 `assert_approx_eq!` 相同）。
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_ne!($left, $right, $crate::approx::Tolerance::default())
+    };
+    ($left:expr, $right:expr, abs = $tol:expr $(,)?) => {
+        $crate::assert_approx_ne!($left, $right, $crate::approx::Tolerance::absolute($tol))
+    };
+    ($left:expr, $right:expr, rel = $tol:expr $(,)?) => {
+        $crate::assert_approx_ne!($left, $right, $crate::approx::Tolerance::relative($tol))
+    };
+    ($left:expr, $right:expr, ulps = $tol:expr $(,)?) => {
+        $crate::assert_approx_ne!($left, $right, $crate::approx::Tolerance::ulps($tol))
+    };
+    ($left:expr, $right:expr, $tol:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if $crate::approx::ApproxEq::approx_eq(left, right, $tol) {
+                    panic!(
+                        "assertion `left ≉ right` failed\n  left: {}\n right: {}",
+                        stringify!($left),
+                        stringify!($right)
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,442 @@
+//! BLAS 风格的 1/2/3 级计算内核。
+//!
+//! 约定与参考 BLAS 一致：
+//! - 结果原地写回输出参数，内核本身不分配内存；
+//! - gemv/gemm/syrk 的 `beta == 0.0` 时不读取输出的旧值（旧值里的 NaN 不会传播）；
+//! - 三角求解不检查病态，只在遇到精确为 0 的对角元时返回 `MatrixError::Singular`。
+//!
+//! `Matrix` 按行优先存储，所以"不转置"时按行访问是连续的，内核的循环顺序也按此安排。
+
+use crate::matrix_dense::{Matrix, MatrixError};
+
+/// 是否对矩阵参数取转置：op(A) = A 或 Aᵀ。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transpose {
+    No,
+    Yes,
+}
+
+/// 三角矩阵使用上三角还是下三角部分（另一半不会被读取）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpLo {
+    Upper,
+    Lower,
+}
+
+/// 三角矩阵的对角元是否视为 1（单位三角，对角元不会被读取）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diag {
+    NonUnit,
+    Unit,
+}
+
+/// trsm 中三角矩阵在未知量的左边（op(A) X = αB）还是右边（X op(A) = αB）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Transpose {
+    fn flip(self) -> Self {
+        match self {
+            Transpose::No => Transpose::Yes,
+            Transpose::Yes => Transpose::No,
+        }
+    }
+}
+
+/// y ← αx + y
+pub fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) -> Result<(), MatrixError> {
+    if x.len() != y.len() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    axpy_unchecked(alpha, x, y);
+    Ok(())
+}
+
+/// 不检查长度的 `axpy`（按较短的一段计算），供 crate 内部维数已经确定的地方使用。
+pub(crate) fn axpy_unchecked(alpha: f64, x: &[f64], y: &mut [f64]) {
+    if alpha != 0.0 {
+        for (yi, xi) in y.iter_mut().zip(x) {
+            *yi += alpha * xi;
+        }
+    }
+}
+
+/// x ← αx
+pub fn scal(alpha: f64, x: &mut [f64]) {
+    for xi in x {
+        *xi *= alpha;
+    }
+}
+
+/// xᵀy
+pub fn dot(x: &[f64], y: &[f64]) -> Result<f64, MatrixError> {
+    if x.len() != y.len() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    Ok(dot_unchecked(x, y))
+}
+
+/// 不检查长度的 `dot`（按较短的一段计算），供 crate 内部维数已经确定的地方使用。
+pub(crate) fn dot_unchecked(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+/// ‖x‖₂，边累加边缩放（LAPACK dnrm2 的做法），元素很大或很小时也不会上溢/下溢。
+pub fn nrm2(x: &[f64]) -> f64 {
+    let mut scale = 0.0_f64;
+    let mut ssq = 1.0_f64;
+    for &v in x {
+        if v != 0.0 {
+            let a = v.abs();
+            if scale < a {
+                ssq = 1.0 + ssq * (scale / a) * (scale / a);
+                scale = a;
+            } else {
+                ssq += (a / scale) * (a / scale);
+            }
+        }
+    }
+    scale * ssq.sqrt()
+}
+
+/// y ← α op(A) x + βy
+pub fn gemv(
+    trans: Transpose,
+    alpha: f64,
+    a: &Matrix,
+    x: &[f64],
+    beta: f64,
+    y: &mut [f64],
+) -> Result<(), MatrixError> {
+    let (m, n) = op_shape(trans, a);
+    if x.len() != n || y.len() != m {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    scale_output(beta, y);
+    if alpha == 0.0 || n == 0 {
+        return Ok(());
+    }
+
+    let data = a.as_slice();
+    match trans {
+        Transpose::No => {
+            for (yi, row) in y.iter_mut().zip(data.chunks_exact(n)) {
+                *yi += alpha * row.iter().zip(x).map(|(a, b)| a * b).sum::<f64>();
+            }
+        }
+        Transpose::Yes => {
+            // y += α Σ_k x_k · (A 的第 k 行)
+            for (xk, row) in x.iter().zip(data.chunks_exact(m.max(1))) {
+                axpy(alpha * xk, row, y)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A ← αxyᵀ + A（秩一更新）
+pub fn ger(alpha: f64, x: &[f64], y: &[f64], a: &mut Matrix) -> Result<(), MatrixError> {
+    if x.len() != a.rows() || y.len() != a.cols() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    let n = a.cols();
+    if n == 0 {
+        return Ok(());
+    }
+    for (xi, row) in x.iter().zip(a.as_mut_slice().chunks_exact_mut(n)) {
+        axpy(alpha * xi, y, row)?;
+    }
+    Ok(())
+}
+
+/// 解三角方程组 op(A) x = b，b 以 x 传入并被解覆盖。
+pub fn trsv(
+    uplo: UpLo,
+    trans: Transpose,
+    diag: Diag,
+    a: &Matrix,
+    x: &mut [f64],
+) -> Result<(), MatrixError> {
+    let n = a.rows();
+    if a.cols() != n {
+        return Err(MatrixError::NotSquare);
+    }
+    if x.len() != n {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    let data = a.as_slice();
+    let at = |i: usize, j: usize| data[i * n + j];
+    let divide = |v: f64, i: usize| -> Result<f64, MatrixError> {
+        match diag {
+            Diag::Unit => Ok(v),
+            Diag::NonUnit if at(i, i) == 0.0 => Err(MatrixError::Singular),
+            Diag::NonUnit => Ok(v / at(i, i)),
+        }
+    };
+
+    match (uplo, trans) {
+        // 按行的前代/回代：每个 x_i 用 A 第 i 行的连续片段做内积
+        (UpLo::Lower, Transpose::No) => {
+            for i in 0..n {
+                let sum: f64 = data[i * n..i * n + i]
+                    .iter()
+                    .zip(&x[..i])
+                    .map(|(a, b)| a * b)
+                    .sum();
+                x[i] = divide(x[i] - sum, i)?;
+            }
+        }
+        (UpLo::Upper, Transpose::No) => {
+            for i in (0..n).rev() {
+                let sum: f64 = data[i * n + i + 1..(i + 1) * n]
+                    .iter()
+                    .zip(&x[i + 1..])
+                    .map(|(a, b)| a * b)
+                    .sum();
+                x[i] = divide(x[i] - sum, i)?;
+            }
+        }
+        // 转置时按列消元：求出 x_j 后立即用 A 第 j 行（= Aᵀ 第 j 列）更新其余分量
+        (UpLo::Lower, Transpose::Yes) => {
+            for j in (0..n).rev() {
+                x[j] = divide(x[j], j)?;
+                let xj = x[j];
+                for (xi, aji) in x[..j].iter_mut().zip(&data[j * n..j * n + j]) {
+                    *xi -= aji * xj;
+                }
+            }
+        }
+        (UpLo::Upper, Transpose::Yes) => {
+            for j in 0..n {
+                x[j] = divide(x[j], j)?;
+                let xj = x[j];
+                for (xi, aji) in x[j + 1..].iter_mut().zip(&data[j * n + j + 1..(j + 1) * n]) {
+                    *xi -= aji * xj;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 解多右端项的三角方程组，B 被解 X 覆盖：
+/// - `Side::Left`:  op(A) X = αB，A 为 m × m
+/// - `Side::Right`: X op(A) = αB，A 为 n × n
+pub fn trsm(
+    side: Side,
+    uplo: UpLo,
+    trans: Transpose,
+    diag: Diag,
+    alpha: f64,
+    a: &Matrix,
+    b: &mut Matrix,
+) -> Result<(), MatrixError> {
+    if a.rows() != a.cols() {
+        return Err(MatrixError::NotSquare);
+    }
+    let (m, n) = (b.rows(), b.cols());
+    let k = match side {
+        Side::Left => m,
+        Side::Right => n,
+    };
+    if a.rows() != k {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    if alpha != 1.0 {
+        scal(alpha, b.as_mut_slice());
+    }
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+
+    match side {
+        // X op(A) = B ⇔ op(A)ᵀ xᵢᵀ = bᵢᵀ：B 的每一行独立做一次 trsv
+        Side::Right => {
+            for row in b.as_mut_slice().chunks_exact_mut(n) {
+                trsv(uplo, trans.flip(), diag, a, row)?;
+            }
+        }
+        // op(A) X = B：按行做块前代/回代，每次用已求出的整行 X_k 更新当前行
+        Side::Left => {
+            let data = a.as_slice();
+            let op = |i: usize, j: usize| match trans {
+                Transpose::No => data[i * m + j],
+                Transpose::Yes => data[j * m + i],
+            };
+            let lower = (uplo == UpLo::Lower) == (trans == Transpose::No);
+            let rows = b.as_mut_slice();
+            // 第 step 个求解的行：下三角从上往下，上三角从下往上
+            let nth = |step: usize| if lower { step } else { m - 1 - step };
+            for step in 0..m {
+                let i = nth(step);
+                for k in (0..step).map(nth) {
+                    let factor = op(i, k);
+                    if factor != 0.0 {
+                        let (src, dst) = two_rows(rows, n, k, i);
+                        axpy(-factor, src, dst)?;
+                    }
+                }
+                if diag == Diag::NonUnit {
+                    let d = op(i, i);
+                    if d == 0.0 {
+                        return Err(MatrixError::Singular);
+                    }
+                    scal(1.0 / d, &mut rows[i * n..(i + 1) * n]);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 对称秩 k 更新，只更新 C 的 `uplo` 三角部分（另一半保持不变）：
+/// - `Transpose::No`:  C ← αAAᵀ + βC，A 为 n × k
+/// - `Transpose::Yes`: C ← αAᵀA + βC，A 为 k × n
+pub fn syrk(
+    uplo: UpLo,
+    trans: Transpose,
+    alpha: f64,
+    a: &Matrix,
+    beta: f64,
+    c: &mut Matrix,
+) -> Result<(), MatrixError> {
+    let (n, k) = op_shape(trans, a);
+    if c.rows() != n || c.cols() != n {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    let range = |i: usize| match uplo {
+        UpLo::Upper => i..n,
+        UpLo::Lower => 0..i + 1,
+    };
+    let cd = c.as_mut_slice();
+    for i in 0..n {
+        scale_output(beta, &mut cd[i * n..(i + 1) * n][range(i)]);
+    }
+    if alpha == 0.0 || k == 0 || n == 0 {
+        return Ok(());
+    }
+
+    let data = a.as_slice();
+    match trans {
+        Transpose::No => {
+            for i in 0..n {
+                let ai = &data[i * k..(i + 1) * k];
+                for j in range(i) {
+                    let aj = &data[j * k..(j + 1) * k];
+                    cd[i * n + j] += alpha * ai.iter().zip(aj).map(|(x, y)| x * y).sum::<f64>();
+                }
+            }
+        }
+        Transpose::Yes => {
+            for row in data.chunks_exact(n) {
+                for i in 0..n {
+                    let ali = alpha * row[i];
+                    if ali == 0.0 {
+                        continue;
+                    }
+                    let r = range(i);
+                    let start = r.start;
+                    axpy(ali, &row[r.clone()], &mut cd[i * n + start..i * n + r.end])?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// C ← α op(A) op(B) + βC
+pub fn gemm(
+    trans_a: Transpose,
+    trans_b: Transpose,
+    alpha: f64,
+    a: &Matrix,
+    b: &Matrix,
+    beta: f64,
+    c: &mut Matrix,
+) -> Result<(), MatrixError> {
+    let (m, k) = op_shape(trans_a, a);
+    let (kb, n) = op_shape(trans_b, b);
+    if k != kb || c.rows() != m || c.cols() != n {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    scale_output(beta, c.as_mut_slice());
+    if alpha == 0.0 || m == 0 || n == 0 || k == 0 {
+        return Ok(());
+    }
+
+    let (ad, bd) = (a.as_slice(), b.as_slice());
+    let cd = c.as_mut_slice();
+    match (trans_a, trans_b) {
+        // C 的第 i 行 += α Σ_p A[i][p] · (B 的第 p 行)：最内层循环是连续的 axpy
+        (Transpose::No, Transpose::No) => {
+            for (ci, ai) in cd.chunks_exact_mut(n).zip(ad.chunks_exact(k)) {
+                for (aip, bp) in ai.iter().zip(bd.chunks_exact(n)) {
+                    if *aip != 0.0 {
+                        axpy(alpha * aip, bp, ci)?;
+                    }
+                }
+            }
+        }
+        (Transpose::Yes, Transpose::No) => {
+            for (ap, bp) in ad.chunks_exact(m).zip(bd.chunks_exact(n)) {
+                for (ci, api) in cd.chunks_exact_mut(n).zip(ap) {
+                    if *api != 0.0 {
+                        axpy(alpha * api, bp, ci)?;
+                    }
+                }
+            }
+        }
+        // C[i][j] += α (A 的第 i 行)·(B 的第 j 行)
+        (Transpose::No, Transpose::Yes) => {
+            for (ci, ai) in cd.chunks_exact_mut(n).zip(ad.chunks_exact(k)) {
+                for (cij, bj) in ci.iter_mut().zip(bd.chunks_exact(k)) {
+                    *cij += alpha * ai.iter().zip(bj).map(|(x, y)| x * y).sum::<f64>();
+                }
+            }
+        }
+        (Transpose::Yes, Transpose::Yes) => {
+            for (i, ci) in cd.chunks_exact_mut(n).enumerate() {
+                for (cij, bj) in ci.iter_mut().zip(bd.chunks_exact(k)) {
+                    let sum: f64 = bj
+                        .iter()
+                        .enumerate()
+                        .map(|(p, bjp)| ad[p * m + i] * bjp)
+                        .sum();
+                    *cij += alpha * sum;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// op(A) 的形状 (行, 列)。
+fn op_shape(trans: Transpose, a: &Matrix) -> (usize, usize) {
+    match trans {
+        Transpose::No => (a.rows(), a.cols()),
+        Transpose::Yes => (a.cols(), a.rows()),
+    }
+}
+
+/// y ← βy；β = 0 时直接清零，不读旧值。
+fn scale_output(beta: f64, y: &mut [f64]) {
+    if beta == 0.0 {
+        y.fill(0.0);
+    } else if beta != 1.0 {
+        scal(beta, y);
+    }
+}
+
+/// 同时借出行优先数据里的第 src 行（只读）和第 dst 行（可写），src != dst。
+fn two_rows(data: &mut [f64], n: usize, src: usize, dst: usize) -> (&[f64], &mut [f64]) {
+    if src < dst {
+        let (head, tail) = data.split_at_mut(dst * n);
+        (&head[src * n..(src + 1) * n], &mut tail[..n])
+    } else {
+        let (head, tail) = data.split_at_mut(src * n);
+        (&tail[..n], &mut head[dst * n..(dst + 1) * n])
+    }
+}
@@ -0,0 +1,654 @@
+use crate::blas::{axpy_unchecked, dot_unchecked, nrm2};
+use crate::conditioning::MatrixNorm;
+use crate::eigen::symmetric_eigen;
+use crate::matrix_dense::{Matrix, MatrixError};
+use crate::schur::{real_schur, schur_eigenvalues};
+
+/// 矩阵无关（matrix-free）的线性算子：只需要会计算 y = A x。
+///
+/// 大型稀疏矩阵、隐式定义的矩阵（比如图 Laplacian 作用在向量上）都可以实现这个 trait，
+/// 而不必真的存下一个 n × n 的稠密矩阵。
+pub trait LinearOperator {
+    /// 算子的维数 n（作用在长度为 n 的向量上，结果也是长度 n）。
+    fn dim(&self) -> usize;
+
+    /// 计算 y = A x，x 和 y 的长度都是 `dim()`。
+    fn apply(&self, x: &[f64], y: &mut [f64]);
+}
+
+/// 稠密矩阵直接作为算子使用（要求是方阵）。
+impl LinearOperator for Matrix {
+    fn dim(&self) -> usize {
+        self.rows()
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        for (yi, row) in y.iter_mut().zip(self.as_slice().chunks(self.cols().max(1))) {
+            *yi = row.iter().zip(x).map(|(a, b)| a * b).sum();
+        }
+    }
+}
+
+/// 用闭包 `f(x, y)`（把 A x 写进 y）定义的算子。
+pub struct FnOperator<F> {
+    dim: usize,
+    f: F,
+}
+
+impl<F> FnOperator<F>
+where
+    F: Fn(&[f64], &mut [f64]),
+{
+    pub fn new(dim: usize, f: F) -> Self {
+        Self { dim, f }
+    }
+}
+
+impl<F> LinearOperator for FnOperator<F>
+where
+    F: Fn(&[f64], &mut [f64]),
+{
+    fn dim(&self) -> usize {
+        self.dim
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        (self.f)(x, y)
+    }
+}
+
+/// 一个实特征对 (λ, x)，x 是单位向量，`residual` = ‖A x - λ x‖₂。
+#[derive(Debug, Clone)]
+pub struct Eigenpair {
+    pub value: f64,
+    pub vector: Vec<f64>,
+    pub residual: f64,
+}
+
+/// 一般实矩阵的（可能是复数的）特征对：λ = re + i·im，x = vector_re + i·vector_im。
+///
+/// 实特征值时 im = 0、vector_im 全为 0。x 按复向量的 2-范数归一化，
+/// `residual` = ‖A x - λ x‖₂。
+#[derive(Debug, Clone)]
+pub struct ComplexEigenpair {
+    pub re: f64,
+    pub im: f64,
+    pub vector_re: Vec<f64>,
+    pub vector_im: Vec<f64>,
+    pub residual: f64,
+}
+
+/// 想要求的是哪一端的特征值。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EigenTarget {
+    /// 模最大（谱半径、主导模态）
+    LargestMagnitude,
+    /// 实部最大
+    LargestReal,
+    /// 实部最小（例如图 Laplacian 的最小特征值）
+    SmallestReal,
+}
+
+/// 单向量迭代（幂迭代、反迭代、Rayleigh 商迭代）的控制参数。
+#[derive(Debug, Clone, Copy)]
+pub struct IterationOptions {
+    pub max_iter: usize,
+    /// 相对残差 ‖Ax - λx‖ / scale 降到这个值以下即认为收敛
+    pub tol: f64,
+}
+
+impl Default for IterationOptions {
+    fn default() -> Self {
+        Self {
+            max_iter: 1000,
+            tol: 1e-10,
+        }
+    }
+}
+
+/// Krylov 子空间方法（Lanczos、Arnoldi）的控制参数。
+#[derive(Debug, Clone, Copy)]
+pub struct KrylovOptions {
+    /// Krylov 子空间的最大维数（会被截断到 n；中途找到不变子空间时会扩展到 n）
+    pub max_dim: usize,
+    /// 相对残差估计降到这个值以下即认为 Ritz 对收敛
+    pub tol: f64,
+    pub target: EigenTarget,
+}
+
+impl Default for KrylovOptions {
+    fn default() -> Self {
+        Self {
+            max_dim: 100,
+            tol: 1e-10,
+            target: EigenTarget::LargestMagnitude,
+        }
+    }
+}
+
+/// 幂迭代：求模最大的特征值及其特征向量。
+///
+/// 反复计算 x ← A x / ‖A x‖，用 Rayleigh 商 xᵀ A x 估计特征值。
+/// 收敛速度取决于 |λ₂ / λ₁|；模最大的特征值不唯一（例如一对共轭复特征值）时不会收敛，
+/// 此时返回 `MatrixError::NoConvergence`。
+pub fn power_iteration<A: LinearOperator>(
+    op: &A,
+    options: &IterationOptions,
+) -> Result<Eigenpair, MatrixError> {
+    let n = op.dim();
+    if n == 0 {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    let mut x = start_vector(n);
+    let mut y = vec![0.0; n];
+    for _ in 0..options.max_iter {
+        op.apply(&x, &mut y);
+        let lambda = dot_unchecked(&x, &y);
+        let residual = residual_norm(&y, lambda, &x);
+        if residual <= options.tol * lambda.abs() || residual == 0.0 {
+            return Ok(Eigenpair {
+                value: lambda,
+                vector: x,
+                residual,
+            });
+        }
+        let len = nrm2(&y);
+        x.iter_mut().zip(&y).for_each(|(xi, yi)| *xi = yi / len);
+    }
+    Err(MatrixError::NoConvergence)
+}
+
+/// 反迭代：求离 `shift` 最近的特征值及其特征向量。
+///
+/// 对 A - σI 做一次带选主元的 LU 分解，然后反复解 (A - σI) y = x 并归一化，
+/// 等价于对 (A - σI)⁻¹ 做幂迭代。σ 恰好是特征值时会把它轻微扰动后重试。
+pub fn inverse_iteration(
+    a: &Matrix,
+    shift: f64,
+    options: &IterationOptions,
+) -> Result<Eigenpair, MatrixError> {
+    if a.rows() != a.cols() {
+        return Err(MatrixError::NotSquare);
+    }
+    let n = a.rows();
+    let scale = a.norm(MatrixNorm::Frobenius).max(f64::MIN_POSITIVE);
+
+    let mut sigma = shift;
+    let mut factors = None;
+    for _ in 0..5 {
+        match shifted(a, sigma)?.lu_partial_pivot() {
+            Ok(f) => {
+                factors = Some(f);
+                break;
+            }
+            Err(MatrixError::Singular) => sigma += 1e-10 * scale,
+            Err(e) => return Err(e),
+        }
+    }
+    let (l, u, perm) = factors.ok_or(MatrixError::Singular)?;
+
+    let mut x = start_vector(n);
+    let mut ax = vec![0.0; n];
+    for _ in 0..options.max_iter {
+        let y = Matrix::solve_lu_pivoted(&l, &u, &perm, &x)?;
+        let len = nrm2(&y);
+        x.iter_mut().zip(&y).for_each(|(xi, yi)| *xi = yi / len);
+
+        a.apply(&x, &mut ax);
+        let lambda = dot_unchecked(&x, &ax);
+        let residual = residual_norm(&ax, lambda, &x);
+        if residual <= options.tol * scale {
+            return Ok(Eigenpair {
+                value: lambda,
+                vector: x,
+                residual,
+            });
+        }
+    }
+    Err(MatrixError::NoConvergence)
+}
+
+/// Rayleigh 商迭代：从 `x0` 出发，每一步都用当前的 Rayleigh 商作为位移做一次反迭代。
+///
+/// 对对称矩阵是三次收敛的，通常几步就能到机器精度；
+/// 收敛到哪个特征对取决于初始向量。
+pub fn rayleigh_quotient_iteration(
+    a: &Matrix,
+    x0: &[f64],
+    options: &IterationOptions,
+) -> Result<Eigenpair, MatrixError> {
+    if a.rows() != a.cols() {
+        return Err(MatrixError::NotSquare);
+    }
+    let n = a.rows();
+    if x0.len() != n {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    let x0_norm = nrm2(x0);
+    if x0_norm == 0.0 {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    let scale = a.norm(MatrixNorm::Frobenius).max(f64::MIN_POSITIVE);
+
+    let mut x: Vec<f64> = x0.iter().map(|v| v / x0_norm).collect();
+    let mut ax = vec![0.0; n];
+    a.apply(&x, &mut ax);
+    let mut lambda = dot_unchecked(&x, &ax);
+
+    for _ in 0..options.max_iter {
+        let residual = residual_norm(&ax, lambda, &x);
+        if residual <= options.tol * scale {
+            return Ok(Eigenpair {
+                value: lambda,
+                vector: x,
+                residual,
+            });
+        }
+
+        let y = match shifted(a, lambda)?.lu_partial_pivot() {
+            Ok((l, u, perm)) => Matrix::solve_lu_pivoted(&l, &u, &perm, &x)?,
+            // 位移已经（在数值上）等于特征值：再扰动一点点继续求特征向量
+            Err(MatrixError::Singular) => {
+                let (l, u, perm) = shifted(a, lambda + 1e-10 * scale)?.lu_partial_pivot()?;
+                Matrix::solve_lu_pivoted(&l, &u, &perm, &x)?
+            }
+            Err(e) => return Err(e),
+        };
+        let len = nrm2(&y);
+        x.iter_mut().zip(&y).for_each(|(xi, yi)| *xi = yi / len);
+        a.apply(&x, &mut ax);
+        lambda = dot_unchecked(&x, &ax);
+    }
+    Err(MatrixError::NoConvergence)
+}
+
+/// Lanczos 方法：求对称算子的 k 个端部特征对。
+///
+/// 逐步构造 Krylov 子空间的标准正交基 V 和三对角矩阵 T = Vᵀ A V（带完全重正交化，
+/// 防止 Ritz 值出现“鬼影”重复），每隔几步用 `symmetric_eigen` 求 T 的特征对（Ritz 对）。
+/// 残差估计 |β_m s_{m,i}| 对所有想要的 Ritz 对都足够小时停止。
+/// 如果中途找到了不变子空间（β_m = 0），就换一个正交的新方向继续，
+/// 并一直扩展到 n 维，这样重特征值的每一重都能找到。
+///
+/// 返回的特征对按 `options.target` 的顺序排列，`residual` 是实际计算的 ‖Ax - λx‖₂。
+/// 算子必须是对称的（这里无法检查）。
+pub fn lanczos<A: LinearOperator>(
+    op: &A,
+    k: usize,
+    options: &KrylovOptions,
+) -> Result<Vec<Eigenpair>, MatrixError> {
+    let n = op.dim();
+    if k > n {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+    let max_dim = options.max_dim.min(n).max(k);
+
+    let mut basis: Vec<Vec<f64>> = vec![normalized(start_vector(n))];
+    let mut alpha: Vec<f64> = Vec::new();
+    let mut beta: Vec<f64> = Vec::new();
+    let mut w = vec![0.0; n];
+    let mut broken = false;
+
+    for j in 0..n {
+        op.apply(&basis[j], &mut w);
+        alpha.push(dot_unchecked(&basis[j], &w));
+        orthogonalize_against(&mut w, &basis);
+        let b = nrm2(&w);
+        let m = j + 1;
+
+        let scale = alpha.iter().fold(0.0_f64, |s, a| s.max(a.abs())).max(b);
+        let breakdown = b <= f64::EPSILON * scale.max(f64::MIN_POSITIVE);
+        // 中断说明找到了不变子空间。Krylov 子空间里每个重特征值只出现一次，
+        // 残差估计看不出还缺几重，所以此后不再按残差停止，而是一直扩展到张满整个空间
+        broken |= breakdown;
+        let limit = if broken { n } else { max_dim };
+
+        if m >= k && (m == limit || (!broken && m % 5 == 0)) {
+            let mut t = Matrix::zeros(m, m);
+            for i in 0..m {
+                t.set(i, i, alpha[i]);
+                if i + 1 < m {
+                    t.set(i, i + 1, beta[i]);
+                    t.set(i + 1, i, beta[i]);
+                }
+            }
+            let (theta, s) = symmetric_eigen(&t)?;
+            let wanted = select(
+                &theta.iter().map(|&v| (v, 0.0)).collect::<Vec<_>>(),
+                k,
+                options.target,
+            );
+            let ritz_scale = theta
+                .iter()
+                .fold(0.0_f64, |s, v| s.max(v.abs()))
+                .max(f64::MIN_POSITIVE);
+
+            let mut converged = true;
+            for &i in &wanted {
+                if b * s.get(m - 1, i)?.abs() > options.tol * ritz_scale {
+                    converged = false;
+                }
+            }
+            if converged || m == limit {
+                if !converged && !broken {
+                    return Err(MatrixError::NoConvergence);
+                }
+                let mut pairs = Vec::with_capacity(k);
+                for &i in &wanted {
+                    let mut x = vec![0.0; n];
+                    for (c, v) in basis.iter().enumerate() {
+                        axpy_unchecked(s.get(c, i)?, v, &mut x);
+                    }
+                    let x = normalized(x);
+                    op.apply(&x, &mut w);
+                    let residual = residual_norm(&w, theta[i], &x);
+                    pairs.push(Eigenpair {
+                        value: theta[i],
+                        vector: x,
+                        residual,
+                    });
+                }
+                return Ok(pairs);
+            }
+        }
+
+        if breakdown {
+            // 找到了不变子空间但特征对还不够：换一个与已有基正交的新方向继续
+            beta.push(0.0);
+            basis.push(fresh_direction(&basis, n)?);
+        } else {
+            beta.push(b);
+            basis.push(w.iter().map(|v| v / b).collect());
+        }
+    }
+    Err(MatrixError::NoConvergence)
+}
+
+/// Arnoldi 方法：求一般（非对称）实算子的 k 个端部特征对（可能是复数）。
+///
+/// 逐步构造标准正交基 V 和上 Hessenberg 矩阵 H = Vᵀ A V，
+/// 用 `real_schur` 求 H 的特征值（Ritz 值），再对 H - θI 做反迭代得到 Ritz 向量 y，
+/// 残差估计为 h_{m+1,m} |y_m|。所有想要的 Ritz 对收敛后返回 x = V y。
+/// 找到不变子空间时的处理与 `lanczos` 相同。
+///
+/// 共轭复特征值成对出现，结果里每一对只用虚部为正的那个代表，
+/// 因此返回的个数可能少于 k。
+pub fn arnoldi<A: LinearOperator>(
+    op: &A,
+    k: usize,
+    options: &KrylovOptions,
+) -> Result<Vec<ComplexEigenpair>, MatrixError> {
+    let n = op.dim();
+    if k > n {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+    let max_dim = options.max_dim.min(n).max(k);
+
+    let mut basis: Vec<Vec<f64>> = vec![normalized(start_vector(n))];
+    // h[i][j]：Hessenberg 矩阵按列存放，第 j 列长度为 j + 2
+    let mut h: Vec<Vec<f64>> = Vec::new();
+    let mut w = vec![0.0; n];
+    let mut broken = false;
+
+    for j in 0..n {
+        op.apply(&basis[j], &mut w);
+        let mut col = vec![0.0; j + 2];
+        for _ in 0..2 {
+            for (i, v) in basis.iter().enumerate() {
+                let c = dot_unchecked(v, &w);
+                axpy_unchecked(-c, v, &mut w);
+                col[i] += c;
+            }
+        }
+        let b = nrm2(&w);
+        col[j + 1] = b;
+        h.push(col);
+        let m = j + 1;
+
+        let mut hm = Matrix::zeros(m, m);
+        for (c, hc) in h.iter().enumerate() {
+            for (r, v) in hc.iter().enumerate().take(m) {
+                hm.set(r, c, *v);
+            }
+        }
+        let scale = hm.norm(MatrixNorm::Frobenius).max(b).max(f64::MIN_POSITIVE);
+        let breakdown = b <= f64::EPSILON * scale;
+        // 与 lanczos 相同：中断后重特征值的重数看不出来，一直扩展到张满整个空间
+        broken |= breakdown;
+        let limit = if broken { n } else { max_dim };
+
+        if m >= k && (m == limit || (!broken && m % 5 == 0)) {
+            let (_, t) = real_schur(&hm)?;
+            let values = schur_eigenvalues(&t)?;
+            let wanted = conjugate_representatives(&values, select(&values, k, options.target));
+
+            let mut ritz = Vec::with_capacity(wanted.len());
+            let mut converged = true;
+            for &i in &wanted {
+                let (re, im) = values[i];
+                let (yr, yi) = shifted_null_vector(&hm, re, im)?;
+                let estimate = b * yr[m - 1].hypot(yi[m - 1]);
+                if estimate > options.tol * scale {
+                    converged = false;
+                }
+                ritz.push((re, im, yr, yi));
+            }
+
+            if converged || m == limit {
+                if !converged && !broken {
+                    return Err(MatrixError::NoConvergence);
+                }
+                let mut pairs = Vec::with_capacity(ritz.len());
+                for (re, im, yr, yi) in ritz {
+                    let mut xr = vec![0.0; n];
+                    let mut xi = vec![0.0; n];
+                    for (c, v) in basis.iter().take(m).enumerate() {
+                        axpy_unchecked(yr[c], v, &mut xr);
+                        axpy_unchecked(yi[c], v, &mut xi);
+                    }
+                    let len = nrm2(&xr).hypot(nrm2(&xi));
+                    xr.iter_mut().for_each(|v| *v /= len);
+                    xi.iter_mut().for_each(|v| *v /= len);
+
+                    let mut axr = vec![0.0; n];
+                    let mut axi = vec![0.0; n];
+                    op.apply(&xr, &mut axr);
+                    op.apply(&xi, &mut axi);
+                    let mut res_sq = 0.0;
+                    for idx in 0..n {
+                        let rr = axr[idx] - re * xr[idx] + im * xi[idx];
+                        let ri = axi[idx] - re * xi[idx] - im * xr[idx];
+                        res_sq += rr * rr + ri * ri;
+                    }
+                    pairs.push(ComplexEigenpair {
+                        re,
+                        im,
+                        vector_re: xr,
+                        vector_im: xi,
+                        residual: res_sq.sqrt(),
+                    });
+                }
+                return Ok(pairs);
+            }
+        }
+
+        if breakdown {
+            if let Some(last) = h.last_mut() {
+                last[j + 1] = 0.0;
+            }
+            basis.push(fresh_direction(&basis, n)?);
+        } else {
+            basis.push(w.iter().map(|v| v / b).collect());
+        }
+    }
+    Err(MatrixError::NoConvergence)
+}
+
+/// 按目标挑出 k 个特征值的下标。
+fn select(values: &[(f64, f64)], k: usize, target: EigenTarget) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    match target {
+        EigenTarget::LargestMagnitude => order.sort_by(|&i, &j| {
+            let mi = values[i].0.hypot(values[i].1);
+            let mj = values[j].0.hypot(values[j].1);
+            mj.total_cmp(&mi)
+        }),
+        EigenTarget::LargestReal => order.sort_by(|&i, &j| values[j].0.total_cmp(&values[i].0)),
+        EigenTarget::SmallestReal => order.sort_by(|&i, &j| values[i].0.total_cmp(&values[j].0)),
+    }
+    order.truncate(k);
+    order
+}
+
+/// 共轭复特征值对只保留虚部为正的那个作为代表（保持原有顺序，去掉重复）。
+fn conjugate_representatives(values: &[(f64, f64)], wanted: Vec<usize>) -> Vec<usize> {
+    let mut result: Vec<usize> = Vec::with_capacity(wanted.len());
+    for i in wanted {
+        let (re, im) = values[i];
+        let rep = if im < 0.0 {
+            values
+                .iter()
+                .position(|&(r, m)| r == re && m == -im)
+                .unwrap_or(i)
+        } else {
+            i
+        };
+        if !result.contains(&rep) {
+            result.push(rep);
+        }
+    }
+    result
+}
+
+/// 求 (H - (re + i·im) I) y ≈ 0 的单位向量 y（实部、虚部分开返回）。
+///
+/// 用几步反迭代实现；复位移时把复方程组写成 2m × 2m 的实方程组
+/// [H - re·I, im·I; -im·I, H - re·I] [y_r; y_i] = [z_r; z_i]。
+fn shifted_null_vector(h: &Matrix, re: f64, im: f64) -> Result<(Vec<f64>, Vec<f64>), MatrixError> {
+    let m = h.rows();
+    let size = if im == 0.0 { m } else { 2 * m };
+    let mut a = vec![0.0; size * size];
+    for r in 0..m {
+        for c in 0..m {
+            let v = h.get(r, c)? - if r == c { re } else { 0.0 };
+            a[r * size + c] = v;
+            if im != 0.0 {
+                a[(r + m) * size + c + m] = v;
+            }
+        }
+        if im != 0.0 {
+            a[r * size + r + m] = im;
+            a[(r + m) * size + r] = -im;
+        }
+    }
+
+    let mut z = vec![1.0; size];
+    for _ in 0..3 {
+        z = solve_perturbed(a.clone(), z, size);
+        let len = nrm2(&z);
+        z.iter_mut().for_each(|v| *v /= len);
+    }
+
+    if im == 0.0 {
+        Ok((z, vec![0.0; m]))
+    } else {
+        let yi = z.split_off(m);
+        Ok((z, yi))
+    }
+}
+
+/// 部分选主元的 Gauss 消元；反迭代中的矩阵几乎奇异，过小的主元被替换成 ε·‖A‖ 而不是报错。
+fn solve_perturbed(mut a: Vec<f64>, mut b: Vec<f64>, n: usize) -> Vec<f64> {
+    let tiny = f64::EPSILON
+        * a.iter()
+            .fold(0.0_f64, |m, x| m.max(x.abs()))
+            .max(f64::MIN_POSITIVE);
+    for col in 0..n {
+        let p = (col..n)
+            .max_by(|&i, &j| a[i * n + col].abs().total_cmp(&a[j * n + col].abs()))
+            .unwrap_or(col);
+        if p != col {
+            for c in 0..n {
+                a.swap(col * n + c, p * n + c);
+            }
+            b.swap(col, p);
+        }
+        if a[col * n + col].abs() < tiny {
+            a[col * n + col] = tiny;
+        }
+        for r in (col + 1)..n {
+            let factor = a[r * n + col] / a[col * n + col];
+            for c in col..n {
+                a[r * n + c] -= factor * a[col * n + c];
+            }
+            b[r] -= factor * b[col];
+        }
+    }
+    for i in (0..n).rev() {
+        let sum: f64 = ((i + 1)..n).map(|j| a[i * n + j] * b[j]).sum();
+        b[i] = (b[i] - sum) / a[i * n + i];
+    }
+    b
+}
+
+/// 确定性的初始向量：分量在 [0.5, 1.5) 之间变化，避免恰好与某个特征向量正交。
+fn start_vector(n: usize) -> Vec<f64> {
+    const GOLDEN: f64 = 0.618_033_988_749_895;
+    (0..n)
+        .map(|i| 0.5 + ((i + 1) as f64 * GOLDEN).fract())
+        .collect()
+}
+
+/// 依次尝试单位向量 e_0, e_1, …，返回第一个与 basis 正交化后不为零的方向。
+///
+/// 找不到时（basis 在数值上已经张满整个空间）返回 `NoConvergence`。
+fn fresh_direction(basis: &[Vec<f64>], n: usize) -> Result<Vec<f64>, MatrixError> {
+    for i in 0..n {
+        let mut e = vec![0.0; n];
+        e[i] = 1.0;
+        orthogonalize_against(&mut e, basis);
+        if nrm2(&e) > 1e-8 {
+            return Ok(normalized(e));
+        }
+    }
+    Err(MatrixError::NoConvergence)
+}
+
+/// 对 basis 做两遍 Gram–Schmidt 正交化（“twice is enough”）。
+fn orthogonalize_against(w: &mut [f64], basis: &[Vec<f64>]) {
+    for _ in 0..2 {
+        for v in basis {
+            let c = dot_unchecked(v, w);
+            axpy_unchecked(-c, v, w);
+        }
+    }
+}
+
+fn shifted(a: &Matrix, sigma: f64) -> Result<Matrix, MatrixError> {
+    let mut s = a.clone();
+    for i in 0..a.rows() {
+        s.set(i, i, a.get(i, i)? - sigma);
+    }
+    Ok(s)
+}
+
+fn residual_norm(ax: &[f64], lambda: f64, x: &[f64]) -> f64 {
+    ax.iter()
+        .zip(x)
+        .map(|(a, b)| (a - lambda * b) * (a - lambda * b))
+        .sum::<f64>()
+        .sqrt()
+}
+
+fn normalized(mut v: Vec<f64>) -> Vec<f64> {
+    let len = nrm2(&v);
+    v.iter_mut().for_each(|x| *x /= len);
+    v
+}
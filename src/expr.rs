@@ -7,6 +7,7 @@ use std::collections::HashMap;
 ///   - eval(expr, env) -> Result<f64, EvalError>
 ///   - to_infix(expr) -> String
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Expr {
     Const(f64),
     Var(String),
@@ -0,0 +1,528 @@
+use crate::conditioning::MatrixNorm;
+use crate::eigen::symmetric_eigen;
+use crate::matrix_dense::{Matrix, MatrixError, matmul};
+use crate::qr::{givens, rotate_cols, rotate_rows};
+use crate::schur::diagonal_blocks;
+
+/// 每个特征值允许的平均 QZ 迭代次数上限。
+const MAX_ITER_PER_EIGENVALUE: usize = 30;
+
+/// 广义实 Schur 分解（QZ 分解）：A = Q S Zᵀ，B = Q T Zᵀ。
+///
+/// - Q、Z: 正交矩阵
+/// - S: 拟上三角（1×1 块对应实特征值，2×2 块对应共轭复特征值对）
+/// - T: 上三角
+#[derive(Debug, Clone)]
+pub struct GeneralizedSchur {
+    pub q: Matrix,
+    pub z: Matrix,
+    pub s: Matrix,
+    pub t: Matrix,
+}
+
+/// 广义特征值 λ = α / β，其中 α = alpha_re + i·alpha_im。
+///
+/// β = 0 表示无穷特征值（B 奇异时会出现），所以不直接存 λ。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeneralizedEigenvalue {
+    pub alpha_re: f64,
+    pub alpha_im: f64,
+    pub beta: f64,
+}
+
+impl GeneralizedEigenvalue {
+    pub fn is_infinite(&self) -> bool {
+        self.beta == 0.0
+    }
+
+    /// 有限特征值返回 (实部, 虚部)，无穷特征值返回 None。
+    pub fn value(&self) -> Option<(f64, f64)> {
+        if self.is_infinite() {
+            None
+        } else {
+            Some((self.alpha_re / self.beta, self.alpha_im / self.beta))
+        }
+    }
+}
+
+/// 对称-正定广义特征值问题 A x = λ B x（A 对称，B 对称正定）。
+///
+/// 用 B 的 Cholesky 分解 B = L Lᵀ 把问题化为标准对称特征值问题：
+///   C = L⁻¹ A L⁻ᵀ，C y = λ y，x = L⁻ᵀ y
+/// 再调用 `symmetric_eigen`。
+///
+/// 返回 (values, vectors)：values 从小到大，vectors 的列满足 Xᵀ B X = I（B-正交归一）。
+pub fn symmetric_definite_eigen(a: &Matrix, b: &Matrix) -> Result<(Vec<f64>, Matrix), MatrixError> {
+    if a.rows() != a.cols() {
+        return Err(MatrixError::NotSquare);
+    }
+    if b.rows() != a.rows() || b.cols() != a.cols() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    if !a.is_symmetric(1e-12 * a.norm(MatrixNorm::Max).max(1.0))? {
+        return Err(MatrixError::NotSymmetric);
+    }
+
+    let n = a.rows();
+    let l = b.cholesky()?;
+
+    // W = L⁻¹ A，C = L⁻¹ Wᵀ = L⁻¹ A L⁻ᵀ（利用 A 对称）
+    let w = lower_solve_columns(&l, a)?;
+    let mut c = lower_solve_columns(&l, &w.transpose()?)?;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let avg = 0.5 * (c.get(i, j)? + c.get(j, i)?);
+            c.set(i, j, avg);
+            c.set(j, i, avg);
+        }
+    }
+
+    let (values, y) = symmetric_eigen(&c)?;
+
+    // X = L⁻ᵀ Y：逐列解 Lᵀ x = y（回代）
+    let mut x = Matrix::zeros(n, n);
+    for k in 0..n {
+        let yk = y.col(k)?;
+        let mut xk = vec![0.0; n];
+        for i in (0..n).rev() {
+            let mut sum = yk[i];
+            for (j, xj) in xk.iter().enumerate().skip(i + 1) {
+                sum -= l.get(j, i)? * xj;
+            }
+            xk[i] = sum / l.get(i, i)?;
+        }
+        x.set_col(k, &xk)?;
+    }
+    Ok((values, x))
+}
+
+/// 广义奇异值：矩阵对 (A, B) 的 σᵢ = sqrt(λᵢ)，λᵢ 是 AᵀA x = λ BᵀB x 的特征值。
+///
+/// 要求 A、B 列数相同，且 B 列满秩（BᵀB 正定）。结果从小到大排列。
+///
+/// 这里通过对称-正定广义特征值问题计算，显式形成了 AᵀA 和 BᵀB，
+/// 会把条件数平方；需要很小广义奇异值的高精度时应改用基于 CS 分解的 GSVD。
+pub fn generalized_singular_values(a: &Matrix, b: &Matrix) -> Result<Vec<f64>, MatrixError> {
+    if a.cols() != b.cols() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    let ata = matmul(&a.transpose()?, a)?;
+    let btb = matmul(&b.transpose()?, b)?;
+    let (values, _) = symmetric_definite_eigen(&ata, &btb)?;
+    Ok(values.iter().map(|v| v.max(0.0).sqrt()).collect())
+}
+
+/// QZ 算法求广义实 Schur 分解，适用于一般（非对称、B 可以奇异）的矩阵对。
+///
+/// 1. 用 Givens 旋转把 B 化为上三角，同时把 A 化为上 Hessenberg（Hessenberg–三角约化）；
+/// 2. 对 (H, T) 做隐式双位移 QZ 迭代：等价于对 H T⁻¹ 做 Francis 双位移 QR，但从不显式求逆；
+/// 3. T 的对角元变成 0 时，把这个 0 “追赶”到当前窗口的最下端，分离出一个无穷特征值。
+///
+/// 实特征值对应的 2×2 块会被进一步分裂成上三角，因此 S 中剩下的 2×2 块都对应复特征值对。
+pub fn qz(a: &Matrix, b: &Matrix) -> Result<GeneralizedSchur, MatrixError> {
+    if a.rows() != a.cols() {
+        return Err(MatrixError::NotSquare);
+    }
+    if b.rows() != a.rows() || b.cols() != a.cols() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    let n = a.rows();
+    let identity = Matrix::identity(n);
+    let mut p = Pencil {
+        n,
+        h: a.as_slice().to_vec(),
+        t: b.as_slice().to_vec(),
+        q: identity.as_slice().to_vec(),
+        z: identity.as_slice().to_vec(),
+    };
+    p.hessenberg_triangular();
+    p.iterate()?;
+
+    Ok(GeneralizedSchur {
+        q: Matrix::new(n, n, p.q)?,
+        z: Matrix::new(n, n, p.z)?,
+        s: Matrix::new(n, n, p.h)?,
+        t: Matrix::new(n, n, p.t)?,
+    })
+}
+
+/// 一般矩阵对 (A, B) 的全部广义特征值（A x = λ B x）。
+///
+/// 如果某个 α 和 β 同时为（数值）0，说明 det(A - λB) 恒为 0（奇异矩阵束），
+/// 此时特征值没有意义，返回 `MatrixError::Singular`。
+pub fn generalized_eigenvalues(
+    a: &Matrix,
+    b: &Matrix,
+) -> Result<Vec<GeneralizedEigenvalue>, MatrixError> {
+    let schur = qz(a, b)?;
+    let (s, t) = (&schur.s, &schur.t);
+    let tol_s = f64::EPSILON * s.norm(MatrixNorm::Frobenius).max(f64::MIN_POSITIVE) * 10.0;
+    let tol_t = f64::EPSILON * t.norm(MatrixNorm::Frobenius).max(f64::MIN_POSITIVE) * 10.0;
+
+    let mut values = Vec::with_capacity(a.rows());
+    for (k, size) in diagonal_blocks(s)? {
+        if size == 1 {
+            let alpha = s.get(k, k)?;
+            let beta = t.get(k, k)?;
+            if alpha.abs() <= tol_s && beta.abs() <= tol_t {
+                return Err(MatrixError::Singular);
+            }
+            values.push(GeneralizedEigenvalue {
+                alpha_re: alpha,
+                alpha_im: 0.0,
+                beta: if beta.abs() <= tol_t { 0.0 } else { beta },
+            });
+        } else {
+            let (qa, qb, qc) = block_quadratic(s, t, k)?;
+            let disc = qb * qb - 4.0 * qa * qc;
+            let re = -qb / (2.0 * qa);
+            let im = (-disc).max(0.0).sqrt() / (2.0 * qa.abs());
+            for sign in [1.0, -1.0] {
+                values.push(GeneralizedEigenvalue {
+                    alpha_re: re,
+                    alpha_im: sign * im,
+                    beta: 1.0,
+                });
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// 2×2 块 det(S₂ - λ T₂) = a λ² + b λ + c 的系数。
+fn block_quadratic(s: &Matrix, t: &Matrix, k: usize) -> Result<(f64, f64, f64), MatrixError> {
+    let (h00, h01, h10, h11) = (
+        s.get(k, k)?,
+        s.get(k, k + 1)?,
+        s.get(k + 1, k)?,
+        s.get(k + 1, k + 1)?,
+    );
+    let (t00, t01, t11) = (t.get(k, k)?, t.get(k, k + 1)?, t.get(k + 1, k + 1)?);
+    Ok((
+        t00 * t11,
+        -(h00 * t11 + h11 * t00 - h10 * t01),
+        h00 * h11 - h01 * h10,
+    ))
+}
+
+/// 逐列解 L X = B（L 下三角）。
+fn lower_solve_columns(l: &Matrix, b: &Matrix) -> Result<Matrix, MatrixError> {
+    let n = l.rows();
+    let mut x = Matrix::zeros(n, b.cols());
+    for k in 0..b.cols() {
+        let bk = b.col(k)?;
+        let mut xk = vec![0.0; n];
+        for i in 0..n {
+            let mut sum = bk[i];
+            for (j, xj) in xk.iter().enumerate().take(i) {
+                sum -= l.get(i, j)? * xj;
+            }
+            xk[i] = sum / l.get(i, i)?;
+        }
+        x.set_col(k, &xk)?;
+    }
+    Ok(x)
+}
+
+/// QZ 迭代的工作区：H、T 以及累积的 Q、Z，都按行优先存放。
+///
+/// 始终保持 Qᵀ A Z = H、Qᵀ B Z = T。
+struct Pencil {
+    n: usize,
+    h: Vec<f64>,
+    t: Vec<f64>,
+    q: Vec<f64>,
+    z: Vec<f64>,
+}
+
+impl Pencil {
+    fn h(&self, i: usize, j: usize) -> f64 {
+        self.h[i * self.n + j]
+    }
+
+    fn t(&self, i: usize, j: usize) -> f64 {
+        self.t[i * self.n + j]
+    }
+
+    fn set_h(&mut self, i: usize, j: usize, v: f64) {
+        self.h[i * self.n + j] = v;
+    }
+
+    fn set_t(&mut self, i: usize, j: usize, v: f64) {
+        self.t[i * self.n + j] = v;
+    }
+
+    /// 对 H、T 的第 i、k 行左乘旋转：row_i ← c·row_i + s·row_k，row_k ← -s·row_i + c·row_k；
+    /// 同时 Q ← Q Gᵀ。
+    fn rotate_rows(&mut self, i: usize, k: usize, c: f64, s: f64) {
+        let n = self.n;
+        rotate_rows(&mut self.h, n, i, k, c, s);
+        rotate_rows(&mut self.t, n, i, k, c, s);
+        rotate_cols(&mut self.q, n, i, k, c, s);
+    }
+
+    /// 对 H、T 的第 i、k 列右乘旋转：col_i ← c·col_i + s·col_k，col_k ← -s·col_i + c·col_k；
+    /// 同时 Z 做同样的列变换。
+    fn rotate_cols(&mut self, i: usize, k: usize, c: f64, s: f64) {
+        let n = self.n;
+        rotate_cols(&mut self.h, n, i, k, c, s);
+        rotate_cols(&mut self.t, n, i, k, c, s);
+        rotate_cols(&mut self.z, n, i, k, c, s);
+    }
+
+    /// 用第 i 行消去第 k 行的 (k, col) 元素（行旋转）。
+    fn zero_by_rows(&mut self, use_t: bool, i: usize, k: usize, col: usize) {
+        let (f, g) = if use_t {
+            (self.t(i, col), self.t(k, col))
+        } else {
+            (self.h(i, col), self.h(k, col))
+        };
+        let (c, s, _) = givens(f, g);
+        self.rotate_rows(i, k, c, s);
+        if use_t {
+            self.set_t(k, col, 0.0);
+        } else {
+            self.set_h(k, col, 0.0);
+        }
+    }
+
+    /// 用第 k 列消去第 i 列的 (row, i) 元素（列旋转）。
+    fn zero_by_cols(&mut self, use_t: bool, row: usize, i: usize, k: usize) {
+        let (f, g) = if use_t {
+            (self.t(row, k), self.t(row, i))
+        } else {
+            (self.h(row, k), self.h(row, i))
+        };
+        let (c, s, _) = givens(f, -g);
+        self.rotate_cols(i, k, c, s);
+        if use_t {
+            self.set_t(row, i, 0.0);
+        } else {
+            self.set_h(row, i, 0.0);
+        }
+    }
+
+    fn hessenberg_triangular(&mut self) {
+        let n = self.n;
+        // B = QR：T 化为上三角
+        for j in 0..n {
+            for i in ((j + 1)..n).rev() {
+                if self.t(i, j) != 0.0 {
+                    self.zero_by_rows(true, i - 1, i, j);
+                }
+            }
+        }
+        // 逐列把 H 化为 Hessenberg，每次行旋转破坏的 T 三角结构立刻用列旋转修复
+        for j in 0..n.saturating_sub(2) {
+            for i in ((j + 2)..n).rev() {
+                if self.h(i, j) == 0.0 {
+                    continue;
+                }
+                self.zero_by_rows(false, i - 1, i, j);
+                if self.t(i, i - 1) != 0.0 {
+                    self.zero_by_cols(true, i, i - 1, i);
+                }
+            }
+        }
+    }
+
+    fn iterate(&mut self) -> Result<(), MatrixError> {
+        let n = self.n;
+        if n == 0 {
+            return Ok(());
+        }
+        let eps = f64::EPSILON;
+        let norm_h = self
+            .h
+            .iter()
+            .map(|x| x * x)
+            .sum::<f64>()
+            .sqrt()
+            .max(f64::MIN_POSITIVE);
+        let norm_t = self
+            .t
+            .iter()
+            .map(|x| x * x)
+            .sum::<f64>()
+            .sqrt()
+            .max(f64::MIN_POSITIVE);
+
+        let mut hi = n - 1;
+        let mut iter = 0;
+        let mut total_iter = 0;
+
+        loop {
+            // 找到当前未约化窗口 [lo, hi]
+            let mut lo = hi;
+            while lo > 0 {
+                let mut scale = self.h(lo - 1, lo - 1).abs() + self.h(lo, lo).abs();
+                if scale == 0.0 {
+                    scale = norm_h;
+                }
+                if self.h(lo, lo - 1).abs() <= eps * scale {
+                    self.set_h(lo, lo - 1, 0.0);
+                    break;
+                }
+                lo -= 1;
+            }
+
+            // T 的对角元为 0：把 0 追赶到 hi，然后分离出无穷特征值
+            if let Some(j) = (lo..=hi).find(|&j| self.t(j, j).abs() <= eps * norm_t) {
+                self.set_t(j, j, 0.0);
+                for jch in j..hi {
+                    self.zero_by_rows(true, jch, jch + 1, jch + 1);
+                    if jch > lo {
+                        self.zero_by_cols(false, jch + 1, jch - 1, jch);
+                    }
+                }
+                if hi > lo {
+                    self.zero_by_cols(false, hi, hi - 1, hi);
+                }
+                if hi == 0 {
+                    break;
+                }
+                hi -= 1;
+                iter = 0;
+                continue;
+            }
+
+            if lo == hi {
+                if hi == 0 {
+                    break;
+                }
+                hi -= 1;
+                iter = 0;
+                continue;
+            }
+            if lo + 1 == hi {
+                self.split_2x2(lo);
+                if hi < 2 {
+                    break;
+                }
+                hi -= 2;
+                iter = 0;
+                continue;
+            }
+
+            iter += 1;
+            total_iter += 1;
+            if total_iter > MAX_ITER_PER_EIGENVALUE * n {
+                return Err(MatrixError::NoConvergence);
+            }
+            self.double_shift_step(lo, hi, iter % 10 == 0);
+        }
+        Ok(())
+    }
+
+    /// 窗口 [lo, hi]（至少 3×3）上的一次隐式双位移 QZ 步。
+    fn double_shift_step(&mut self, lo: usize, hi: usize, exceptional: bool) {
+        // M = H T⁻¹ 的右下角 2×2 决定两个位移（用迹 s 和行列式 d 表示）
+        let p = hi - 2;
+        let (a, b, c) = (self.t(p, p), self.t(p, p + 1), self.t(p, p + 2));
+        let (d, e, f) = (
+            self.t(p + 1, p + 1),
+            self.t(p + 1, p + 2),
+            self.t(p + 2, p + 2),
+        );
+        let j01 = -b / (a * d);
+        let j02 = (b * e - c * d) / (a * d * f);
+        let j11 = 1.0 / d;
+        let j12 = -e / (d * f);
+        let j22 = 1.0 / f;
+        let m11 = self.h(p + 1, p) * j01 + self.h(p + 1, p + 1) * j11;
+        let m12 = self.h(p + 1, p) * j02 + self.h(p + 1, p + 1) * j12 + self.h(p + 1, p + 2) * j22;
+        let m21 = self.h(p + 2, p + 1) * j11;
+        let m22 = self.h(p + 2, p + 1) * j12 + self.h(p + 2, p + 2) * j22;
+
+        let (s, det) = if exceptional {
+            let ex = (self.h(hi, hi - 1) / self.t(hi - 1, hi - 1)).abs()
+                + (self.h(hi - 1, hi - 2) / self.t(hi - 2, hi - 2)).abs();
+            (1.5 * ex, ex * ex)
+        } else {
+            (m11 + m22, m11 * m22 - m12 * m21)
+        };
+
+        // (M² - sM + det·I) e₁ 的前三个分量
+        let i00 = 1.0 / self.t(lo, lo);
+        let i11 = 1.0 / self.t(lo + 1, lo + 1);
+        let i01 = -self.t(lo, lo + 1) * i00 * i11;
+        let n00 = self.h(lo, lo) * i00;
+        let n10 = self.h(lo + 1, lo) * i00;
+        let n01 = self.h(lo, lo) * i01 + self.h(lo, lo + 1) * i11;
+        let n11 = self.h(lo + 1, lo) * i01 + self.h(lo + 1, lo + 1) * i11;
+        let n21 = self.h(lo + 2, lo + 1) * i11;
+        let x = n00 * n00 + n01 * n10 - s * n00 + det;
+        let y = n10 * (n00 + n11 - s);
+        let z = n10 * n21;
+
+        for k in lo..(hi - 1) {
+            if k == lo {
+                let (c1, s1, r) = givens(y, z);
+                self.rotate_rows(k + 1, k + 2, c1, s1);
+                let (c2, s2, _) = givens(x, r);
+                self.rotate_rows(k, k + 1, c2, s2);
+            } else {
+                self.zero_by_rows(false, k + 1, k + 2, k - 1);
+                self.zero_by_rows(false, k, k + 1, k - 1);
+            }
+            // 修复 T 在第 k..k+2 行产生的填充
+            self.zero_by_cols(true, k + 2, k + 1, k + 2);
+            self.zero_by_cols(true, k + 2, k, k + 2);
+            self.zero_by_cols(true, k + 1, k, k + 1);
+        }
+        self.zero_by_rows(false, hi - 1, hi, hi - 2);
+        self.zero_by_cols(true, hi, hi - 1, hi);
+    }
+
+    /// 把 (k, k) 处的 2×2 块在有两个实特征值时分裂成上三角。
+    fn split_2x2(&mut self, k: usize) {
+        let (h00, h01, h10, h11) = (
+            self.h(k, k),
+            self.h(k, k + 1),
+            self.h(k + 1, k),
+            self.h(k + 1, k + 1),
+        );
+        let (t00, t01, t11) = (self.t(k, k), self.t(k, k + 1), self.t(k + 1, k + 1));
+        let qa = t00 * t11;
+        let qb = -(h00 * t11 + h11 * t00 - h10 * t01);
+        let qc = h00 * h11 - h01 * h10;
+        let disc = qb * qb - 4.0 * qa * qc;
+        if disc < 0.0 || qa == 0.0 {
+            return;
+        }
+        let root = -0.5 * (qb + qb.signum() * disc.sqrt());
+        let lambda = if root != 0.0 {
+            qc / root
+        } else {
+            -qb / (2.0 * qa)
+        };
+        if !lambda.is_finite() {
+            return;
+        }
+
+        // (H₂ - λT₂) v = 0 的零向量 v，作为列旋转的第一列
+        let r0 = (h00 - lambda * t00, h01 - lambda * t01);
+        let r1 = (h10, h11 - lambda * t11);
+        let row = if r0.0.hypot(r0.1) >= r1.0.hypot(r1.1) {
+            r0
+        } else {
+            r1
+        };
+        let len = row.0.hypot(row.1);
+        if len == 0.0 {
+            return;
+        }
+        let (v0, v1) = (-row.1 / len, row.0 / len);
+        self.rotate_cols(k, k + 1, v0, v1);
+
+        // 现在 H₂ e₁ 与 T₂ e₁ 平行，一个行旋转同时消去两者的 (k+1, k) 元素
+        if self.t(k, k).abs() + self.t(k + 1, k).abs() > 0.0 {
+            self.zero_by_rows(true, k, k + 1, k);
+        } else {
+            self.zero_by_rows(false, k, k + 1, k);
+        }
+        self.set_h(k + 1, k, 0.0);
+        self.set_t(k + 1, k, 0.0);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::blas::{axpy_unchecked, dot_unchecked, nrm2};
+use crate::matrix_dense::{Matrix, MatrixError};
+
+/// 判断“剩余部分已经可以忽略”的相对阈值：
+/// 如果正交化之后的列长度 <= DEPENDENCE_TOL * 原始列长度，就认为它和前面的列线性相关。
+const DEPENDENCE_TOL: f64 = 1e-12;
+
+/// 经典 Gram–Schmidt（CGS）正交化，得到 A = Q R。
+///
+/// - A: m × n，要求列满秩（n <= m）
+/// - Q: m × n，列向量两两正交且长度为 1
+/// - R: n × n 上三角
+///
+/// CGS 对第 j 列一次性减去它在 q_0..q_{j-1} 上的全部投影（投影系数都用原始列计算），
+/// 数值上会逐渐失去正交性；`reorthogonalize = true` 时再做一遍投影扣除
+/// （“twice is enough”），把正交性恢复到机器精度量级。
+///
+/// 某一列与前面的列线性相关时返回 `MatrixError::Singular`。
+pub fn classical_gram_schmidt(
+    a: &Matrix,
+    reorthogonalize: bool,
+) -> Result<(Matrix, Matrix), MatrixError> {
+    gram_schmidt(a, reorthogonalize, false)
+}
+
+/// 修正 Gram–Schmidt（MGS）正交化，得到 A = Q R。
+///
+/// 和 CGS 的区别：每扣除一个方向的投影后，立刻用更新后的向量去计算下一个投影系数。
+/// 数学上与 CGS 等价，但舍入误差积累小得多。
+/// `reorthogonalize` 的含义同 [`classical_gram_schmidt`]。
+pub fn modified_gram_schmidt(
+    a: &Matrix,
+    reorthogonalize: bool,
+) -> Result<(Matrix, Matrix), MatrixError> {
+    gram_schmidt(a, reorthogonalize, true)
+}
+
+/// 提取 A 的列空间的一组标准正交基，返回 m × r 矩阵（r 为数值秩）。
+///
+/// 使用带重正交化的 MGS；与前面的列线性相关（剩余长度 <= tol * 原长度）的列被直接跳过，
+/// 因此不要求 A 列满秩。
+pub fn orthonormal_basis(a: &Matrix, tol: f64) -> Result<Matrix, MatrixError> {
+    let mut basis: Vec<Vec<f64>> = Vec::new();
+
+    for j in 0..a.cols() {
+        let mut v = a.col(j)?;
+        let original = nrm2(&v);
+        if original == 0.0 {
+            continue;
+        }
+        for _ in 0..2 {
+            for q in &basis {
+                let r = dot_unchecked(q, &v);
+                axpy_unchecked(-r, q, &mut v);
+            }
+        }
+        let len = nrm2(&v);
+        if len <= tol * original {
+            continue;
+        }
+        v.iter_mut().for_each(|x| *x /= len);
+        basis.push(v);
+    }
+
+    let mut q = Matrix::zeros(a.rows(), basis.len());
+    for (k, v) in basis.iter().enumerate() {
+        q.set_col(k, v)?;
+    }
+    Ok(q)
+}
+
+fn gram_schmidt(
+    a: &Matrix,
+    reorthogonalize: bool,
+    modified: bool,
+) -> Result<(Matrix, Matrix), MatrixError> {
+    let (m, n) = (a.rows(), a.cols());
+    if n > m {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    let mut q_cols: Vec<Vec<f64>> = Vec::with_capacity(n);
+    let mut r = Matrix::zeros(n, n);
+    let passes = if reorthogonalize { 2 } else { 1 };
+
+    for j in 0..n {
+        let original = a.col(j)?;
+        let mut v = original.clone();
+
+        for _ in 0..passes {
+            if modified {
+                for (i, q) in q_cols.iter().enumerate() {
+                    let rij = dot_unchecked(q, &v);
+                    axpy_unchecked(-rij, q, &mut v);
+                    r.set(i, j, r.get(i, j)? + rij);
+                }
+            } else {
+                let coeffs: Vec<f64> = q_cols.iter().map(|q| dot_unchecked(q, &v)).collect();
+                for (i, (q, rij)) in q_cols.iter().zip(&coeffs).enumerate() {
+                    axpy_unchecked(-rij, q, &mut v);
+                    r.set(i, j, r.get(i, j)? + rij);
+                }
+            }
+        }
+
+        let len = nrm2(&v);
+        if len <= DEPENDENCE_TOL * nrm2(&original) || len == 0.0 {
+            return Err(MatrixError::Singular);
+        }
+        r.set(j, j, len);
+        v.iter_mut().for_each(|x| *x /= len);
+        q_cols.push(v);
+    }
+
+    let mut q = Matrix::zeros(m, n);
+    for (k, v) in q_cols.iter().enumerate() {
+        q.set_col(k, v)?;
+    }
+    Ok((q, r))
+}
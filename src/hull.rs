@@ -0,0 +1,383 @@
+//! 二维点集的形状分析：凸包、最小外接圆、轴对齐与最小面积有向包围盒、直径与宽度。
+//!
+//! 除最小外接圆外都先求凸包，再在凸包上用旋转卡壳（rotating calipers）在线性时间内完成。
+//! 重复点和共线点都是允许的：凸包会把它们去掉，退化的点集（单点、一条线）给出退化的结果。
+
+use crate::polygon::Polygon;
+use crate::primitives::{lex_cmp, orient2d};
+use crate::random::Rng;
+use crate::vector::{Vector2, distance, dot};
+use std::cmp::Ordering;
+
+/// Andrew 单调链求凸包：返回逆时针的凸多边形，从字典序最小的点开始。
+///
+/// 重复点与凸包边上的共线点都不会出现在结果中；所有点共线时只返回两个端点，
+/// 只有一个不同的点时返回一个顶点，空输入返回空多边形。
+/// 拐向判断用精确的 `orient2d`，所以结果不会因为舍入而丢掉真正的顶点或保留凹点。
+pub fn convex_hull(points: &[Vector2]) -> Polygon {
+    Polygon::new(hull_vertices(points))
+}
+
+fn hull_vertices(points: &[Vector2]) -> Vec<Vector2> {
+    let mut pts = points.to_vec();
+    pts.sort_by(lex_cmp);
+    pts.dedup_by(|a, b| lex_cmp(a, b) == Ordering::Equal);
+    if pts.len() < 3 {
+        return pts;
+    }
+    let mut hull: Vec<Vector2> = Vec::with_capacity(pts.len() + 1);
+    // 下凸链从左到右，只保留严格左转的点
+    for p in &pts {
+        while hull.len() >= 2 && orient2d(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+    // 上凸链从右到左，不能弹出下凸链的点
+    let lower_len = hull.len() + 1;
+    for p in pts.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && orient2d(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+    // 上凸链最后回到了起点
+    hull.pop();
+    hull
+}
+
+/// 圆：圆心和半径。
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Circle {
+    center: Vector2,
+    radius: f64,
+}
+
+impl Circle {
+    pub fn new(center: Vector2, radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn center(&self) -> Vector2 {
+        self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// p 是否在圆内或圆上（允许 1e-12 的相对误差，抵消求圆心时的舍入）。
+    pub fn contains(&self, p: &Vector2) -> bool {
+        distance(&self.center, p) <= self.radius * (1.0 + 1e-12) + 1e-300
+    }
+
+    /// 以 a、b 为直径的圆。
+    fn diameter(a: &Vector2, b: &Vector2) -> Self {
+        Self::new((*a + *b) * 0.5, distance(a, b) * 0.5)
+    }
+
+    /// 过 a、b、c 的外接圆；三点共线时退化为最远两点为直径的圆。
+    fn circumcircle(a: &Vector2, b: &Vector2, c: &Vector2) -> Self {
+        let d = 2.0 * orient2d(a, b, c);
+        if d == 0.0 {
+            return [(a, b), (b, c), (a, c)]
+                .into_iter()
+                .map(|(p, q)| Self::diameter(p, q))
+                .max_by(|x, y| x.radius.total_cmp(&y.radius))
+                .expect("three pairs");
+        }
+        // 以 a 为原点求解，减小坐标很大时的相消误差
+        let (b, c) = (*b - *a, *c - *a);
+        let (bb, cc) = (b.length_sq(), c.length_sq());
+        let u = Vector2::new((c.y() * bb - b.y() * cc) / d, (b.x() * cc - c.x() * bb) / d);
+        Self::new(*a + u, u.length_sq().sqrt())
+    }
+}
+
+/// Welzl 最小外接圆（随机增量的迭代写法），期望 O(n)；空输入返回 None。
+///
+/// 先用 rng 打乱点的顺序——期望线性时间依赖于这一步，最坏情况（例如按角度排好序的输入）
+/// 不打乱会退化成 O(n³)。最小外接圆是唯一的，所以结果与种子无关，只有运行时间有关。
+pub fn min_enclosing_circle(points: &[Vector2], rng: &mut Rng) -> Option<Circle> {
+    let mut pts = points.to_vec();
+    rng.shuffle(&mut pts);
+    let mut circle = Circle::new(*pts.first()?, 0.0);
+    for i in 1..pts.len() {
+        if circle.contains(&pts[i]) {
+            continue;
+        }
+        // pts[i] 一定在前 i + 1 个点的最小外接圆的边界上
+        circle = Circle::new(pts[i], 0.0);
+        for j in 0..i {
+            if circle.contains(&pts[j]) {
+                continue;
+            }
+            // pts[i]、pts[j] 都在边界上
+            circle = Circle::diameter(&pts[i], &pts[j]);
+            for k in 0..j {
+                if !circle.contains(&pts[k]) {
+                    circle = Circle::circumcircle(&pts[i], &pts[j], &pts[k]);
+                }
+            }
+        }
+    }
+    Some(circle)
+}
+
+/// 轴对齐包围盒 [min, max]。
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb {
+    min: Vector2,
+    max: Vector2,
+}
+
+impl Aabb {
+    /// 包住所有点的最小轴对齐矩形；空输入返回 None。
+    pub fn from_points(points: &[Vector2]) -> Option<Self> {
+        let first = *points.first()?;
+        Some(points.iter().fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |b, p| Self {
+                min: Vector2::new(b.min.x().min(p.x()), b.min.y().min(p.y())),
+                max: Vector2::new(b.max.x().max(p.x()), b.max.y().max(p.y())),
+            },
+        ))
+    }
+
+    pub fn min(&self) -> Vector2 {
+        self.min
+    }
+
+    pub fn max(&self) -> Vector2 {
+        self.max
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max.x() - self.min.x()
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y() - self.min.y()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    pub fn center(&self) -> Vector2 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// p 是否在盒内（含边界）。
+    pub fn contains(&self, p: &Vector2) -> bool {
+        (self.min.x()..=self.max.x()).contains(&p.x())
+            && (self.min.y()..=self.max.y()).contains(&p.y())
+    }
+}
+
+/// 有向包围盒：center + s·axes[0] + t·axes[1]，|s| <= half_extents[0]，|t| <= half_extents[1]。
+///
+/// 两个轴是正交的单位向量，axes[1] 由 axes[0] 逆时针转 90° 得到。
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrientedBox {
+    center: Vector2,
+    axes: [Vector2; 2],
+    half_extents: [f64; 2],
+}
+
+impl OrientedBox {
+    pub fn center(&self) -> Vector2 {
+        self.center
+    }
+
+    pub fn axes(&self) -> [Vector2; 2] {
+        self.axes
+    }
+
+    pub fn half_extents(&self) -> [f64; 2] {
+        self.half_extents
+    }
+
+    /// axes[0] 相对 x 轴的角度，范围 (-π, π]。
+    pub fn angle(&self) -> f64 {
+        f64::atan2(self.axes[0].y(), self.axes[0].x())
+    }
+
+    pub fn area(&self) -> f64 {
+        4.0 * self.half_extents[0] * self.half_extents[1]
+    }
+
+    /// 四个角，逆时针。
+    pub fn corners(&self) -> [Vector2; 4] {
+        let u = self.axes[0] * self.half_extents[0];
+        let v = self.axes[1] * self.half_extents[1];
+        let c = self.center;
+        [c - u - v, c + u - v, c + u + v, c - u + v]
+    }
+
+    pub fn to_polygon(&self) -> Polygon {
+        Polygon::new(self.corners().to_vec())
+    }
+
+    /// 以 u 为第一轴、包住 hull 中 [lo, hi] × [bottom, top] 范围的盒子（坐标相对 origin）。
+    fn from_extents(origin: Vector2, u: Vector2, lo: f64, hi: f64, bottom: f64, top: f64) -> Self {
+        let v = perp(&u);
+        Self {
+            center: origin + u * ((lo + hi) * 0.5) + v * ((bottom + top) * 0.5),
+            axes: [u, v],
+            half_extents: [(hi - lo) * 0.5, (top - bottom) * 0.5],
+        }
+    }
+}
+
+/// v 逆时针转 90°。
+fn perp(v: &Vector2) -> Vector2 {
+    Vector2::new(-v.y(), v.x())
+}
+
+/// 最小面积有向包围盒（旋转卡壳，O(n log n)，瓶颈在求凸包）。
+///
+/// 最优矩形必有一条边与凸包的某条边共线，所以对凸包的每条边，用三根“卡尺”
+/// 分别跟踪沿边方向最远、最近和离边最远的顶点；边绕一圈时三根卡尺都只单调前进，
+/// 整个过程是线性的。所有点共线时返回宽度为 0 的盒子，单点返回大小为 0 的盒子；空输入返回 None。
+pub fn min_area_bounding_box(points: &[Vector2]) -> Option<OrientedBox> {
+    let h = hull_vertices(points);
+    let n = h.len();
+    match n {
+        0 => return None,
+        1 => {
+            return Some(OrientedBox::from_extents(
+                h[0],
+                Vector2::new(1.0, 0.0),
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+            ));
+        }
+        2 => {
+            let d = h[1] - h[0];
+            let len = d.length_sq().sqrt();
+            return Some(OrientedBox::from_extents(
+                h[0],
+                d * (1.0 / len),
+                0.0,
+                len,
+                0.0,
+                0.0,
+            ));
+        }
+        _ => {}
+    }
+
+    let next = |i: usize| (i + 1) % n;
+    let edge_dir = |i: usize| {
+        let d = h[next(i)] - h[i];
+        d * (1.0 / d.length_sq().sqrt())
+    };
+    // 第 0 条边的三个极值点先整体扫描一遍
+    let u0 = edge_dir(0);
+    let argmax = |f: &dyn Fn(&Vector2) -> f64| {
+        (0..n)
+            .max_by(|&a, &b| f(&h[a]).total_cmp(&f(&h[b])))
+            .expect("non-empty hull")
+    };
+    let mut right = argmax(&|p| dot(p, &u0));
+    let mut top = argmax(&|p| dot(p, &perp(&u0)));
+    let mut left = argmax(&|p| -dot(p, &u0));
+
+    let mut best: Option<(f64, OrientedBox)> = None;
+    for i in 0..n {
+        let u = edge_dir(i);
+        let v = perp(&u);
+        let o = h[i];
+        // 卡尺沿逆时针方向前进，直到下一个顶点不再更远
+        let advance = |mut k: usize, f: &dyn Fn(&Vector2) -> f64| {
+            while f(&(h[next(k)] - o)) > f(&(h[k] - o)) {
+                k = next(k);
+            }
+            k
+        };
+        right = advance(right, &|p| dot(p, &u));
+        top = advance(top, &|p| dot(p, &v));
+        left = advance(left, &|p| -dot(p, &u));
+        let hi = dot(&(h[right] - o), &u);
+        let lo = dot(&(h[left] - o), &u);
+        let height = dot(&(h[top] - o), &v);
+        let area = (hi - lo) * height;
+        if best.as_ref().is_none_or(|(a, _)| area < *a) {
+            best = Some((area, OrientedBox::from_extents(o, u, lo, hi, 0.0, height)));
+        }
+    }
+    best.map(|(_, b)| b)
+}
+
+/// 凸包上所有对踵点对（旋转卡壳），凸包至少有 3 个顶点时使用。
+///
+/// 对每条边 (i, i + 1)，离它最远的顶点 j 随 i 单调前进；
+/// 最远点对和最小宽度都只需要在这些 (边, 顶点) 组合里找。
+fn antipodal_edges(h: &[Vector2]) -> Vec<(usize, usize)> {
+    let n = h.len();
+    let next = |i: usize| (i + 1) % n;
+    let mut j = 1;
+    let mut pairs = Vec::with_capacity(n);
+    for i in 0..n {
+        let (a, b) = (&h[i], &h[next(i)]);
+        while orient2d(a, b, &h[next(j)]) > orient2d(a, b, &h[j]) {
+            j = next(j);
+        }
+        pairs.push((i, j));
+    }
+    pairs
+}
+
+/// 点集中距离最远的两个点（旋转卡壳，O(n log n)）；空输入返回 None，单点返回 (p, p)。
+pub fn farthest_pair(points: &[Vector2]) -> Option<(Vector2, Vector2)> {
+    let h = hull_vertices(points);
+    match h.len() {
+        0 => return None,
+        1 => return Some((h[0], h[0])),
+        2 => return Some((h[0], h[1])),
+        _ => {}
+    }
+    let n = h.len();
+    antipodal_edges(&h)
+        .into_iter()
+        .flat_map(|(i, j)| {
+            // 有平行边时对踵关系是“边对边”，四个端点组合都要看
+            let (i1, j1) = ((i + 1) % n, (j + 1) % n);
+            [(h[i], h[j]), (h[i1], h[j]), (h[i], h[j1]), (h[i1], h[j1])]
+        })
+        .max_by(|(a1, b1), (a2, b2)| distance(a1, b1).total_cmp(&distance(a2, b2)))
+}
+
+/// 直径：点集中两点距离的最大值；少于两个点时为 0。
+pub fn diameter(points: &[Vector2]) -> f64 {
+    farthest_pair(points).map_or(0.0, |(a, b)| distance(&a, &b))
+}
+
+/// 宽度：夹住点集的两条平行线之间的最小距离；所有点共线（含少于三个点）时为 0。
+///
+/// 最优方向必与凸包的某条边平行，所以取每条边到其对踵顶点距离的最小值。
+pub fn width(points: &[Vector2]) -> f64 {
+    let h = hull_vertices(points);
+    if h.len() < 3 {
+        return 0.0;
+    }
+    let n = h.len();
+    antipodal_edges(&h)
+        .into_iter()
+        .map(|(i, j)| {
+            let (a, b) = (&h[i], &h[(i + 1) % n]);
+            orient2d(a, b, &h[j]) / distance(a, b)
+        })
+        .fold(f64::INFINITY, f64::min)
+}
@@ -19,18 +19,11 @@ impl<'a> WindowIter<'a> {
     /// - 如果 window_size == 0，可以直接返回一个“空迭代器”（后续 next() 永远返回 None）。
     /// - 如果 window_size > slice.len()，同样可以看作没有任何窗口。
     pub fn new(slice: &'a [f64], window_size: usize) -> Self {
-        if window_size == 0 || window_size > slice.len() {
-            return Self {
-                slice,
-                window_size,
-                pos: 0,
-            };
-        } else {
-            Self {
-                slice,
-                window_size,
-                pos: 0,
-            }
+        // 空窗口或窗口过大时，next() 会直接返回 None，这里无需特殊处理。
+        Self {
+            slice,
+            window_size,
+            pos: 0,
         }
     }
 }
@@ -45,11 +38,11 @@ impl<'a> Iterator for WindowIter<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.window_size == 0 || self.pos + self.window_size > self.slice.len() {
-            return None;
+            None
         } else {
             let window = &self.slice[self.pos..self.pos + self.window_size];
             self.pos += 1;
-            return Some(window);
+            Some(window)
         }
     }
 }
@@ -108,12 +101,11 @@ pub fn moving_variance(data: &[f64], window_size: usize) -> Vec<f64> {
     let data_sq = data.iter().map(|x| (*x) * (*x)).collect::<Vec<f64>>();
     let mean = moving_average(data, window_size);
     let mean_sq = moving_average(&data_sq, window_size);
-    let var = mean_sq
+    mean_sq
         .iter()
         .zip(mean.iter())
         .map(|(msq, mean)| *msq - *mean * *mean)
-        .collect::<Vec<f64>>();
-    var
+        .collect::<Vec<f64>>()
 }
 
 /// 窗口标准差：std = sqrt(var)
@@ -125,8 +117,7 @@ pub fn moving_stddev(data: &[f64], window_size: usize) -> Vec<f64> {
     }
 
     let var = moving_variance(data, window_size);
-    let stddev = var.iter().map(|x| x.sqrt()).collect::<Vec<f64>>();
-    stddev
+    var.iter().map(|x| x.sqrt()).collect::<Vec<f64>>()
 }
 
 /// 指数移动平均（Exponential Moving Average, EMA）
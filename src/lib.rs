@@ -1,14 +1,38 @@
 // lin_alg_lab/src/lib.rs
+pub mod affine;
+pub mod approx;
+pub mod blas;
+pub mod conditioning;
+pub mod eigen;
+pub mod eigen_iterative;
 pub mod expr;
+pub mod generalized_eigen;
+pub mod gram_schmidt;
+pub mod hull;
 pub mod iter_utils;
 pub mod matrix;
 pub mod matrix_dense;
 pub mod numeric;
 pub mod path;
+pub mod polygon;
+pub mod primitives;
+pub mod qr;
+pub mod quaternion;
+pub mod random;
+pub mod refinement;
+pub mod serialize;
 pub mod units;
 pub mod vector;
+pub mod vector3;
+pub mod vector_n;
 pub mod matrix_dense_access;
+pub mod matrix_dense_rref;
 pub mod root_finding;
+pub mod schur;
+pub mod structured;
+pub mod svd;
+pub mod sylvester;
+pub mod triangular;
 pub mod ode;
 
 pub fn add(left: u64, right: u64) -> u64 {
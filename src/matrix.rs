@@ -1,5 +1,8 @@
+use crate::matrix_dense::{Matrix, MatrixError};
+use crate::quaternion::Quaternion;
 use crate::vector::Vector2;
-use std::ops::Mul;
+use crate::vector3::{Vector3, cross, dot};
+use std::ops::{Add, Mul, Neg, Sub};
 
 // 练习目标：
 // - 定义一个 2x2 矩阵类型 Matrix2x2
@@ -9,6 +12,7 @@ use std::ops::Mul;
 //   - Matrix2x2::rotation(theta) 旋转矩阵
 //   - Matrix2x2 * Vector2 的乘法（实现 Mul<Vector2>）
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Matrix2x2 {
     m11: f64,
     m12: f64,
@@ -45,6 +49,293 @@ impl Matrix2x2 {
             f64::cos(theta),
         )
     }
+
+    pub fn m11(&self) -> f64 {
+        self.m11
+    }
+
+    pub fn m12(&self) -> f64 {
+        self.m12
+    }
+
+    pub fn m21(&self) -> f64 {
+        self.m21
+    }
+
+    pub fn m22(&self) -> f64 {
+        self.m22
+    }
+
+    /// 沿坐标轴缩放：diag(sx, sy)。
+    pub fn scaling(sx: f64, sy: f64) -> Self {
+        Self::new(sx, 0.0, 0.0, sy)
+    }
+
+    /// 错切：x' = x + kx·y，y' = ky·x + y。
+    pub fn shear(kx: f64, ky: f64) -> Self {
+        Self::new(1.0, kx, ky, 1.0)
+    }
+
+    /// 关于过原点、方向为 direction 的直线做镜像：2 d dᵀ - I（d 为单位方向）。
+    ///
+    /// direction 太接近零向量时返回 None。
+    pub fn reflection(direction: Vector2) -> Option<Self> {
+        let d = direction.normalize()?;
+        let (x, y) = (d.x(), d.y());
+        Some(Self::new(
+            2.0 * x * x - 1.0,
+            2.0 * x * y,
+            2.0 * x * y,
+            2.0 * y * y - 1.0,
+        ))
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::new(self.m11, self.m21, self.m12, self.m22)
+    }
+
+    /// 迹 m11 + m22 = 两个特征值之和。
+    pub fn trace(&self) -> f64 {
+        self.m11 + self.m22
+    }
+
+    /// 行列式 m11 m22 - m12 m21 = 两个特征值之积。
+    pub fn determinant(&self) -> f64 {
+        self.m11 * self.m22 - self.m12 * self.m21
+    }
+
+    /// 逆矩阵 adj(A) / det(A)。
+    ///
+    /// |det| 相对于元素大小的平方小于 1e-12 时视为奇异，返回 `MatrixError::Singular`。
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        let det = self.determinant();
+        let scale = self.m11.abs() + self.m12.abs() + self.m21.abs() + self.m22.abs();
+        if det.abs() <= 1e-12 * scale * scale {
+            return Err(MatrixError::Singular);
+        }
+        Ok(Self::new(
+            self.m22 / det,
+            -self.m12 / det,
+            -self.m21 / det,
+            self.m11 / det,
+        ))
+    }
+
+    /// 两个特征值，格式与 `schur::schur_eigenvalues` 相同：(实部, 虚部)。
+    ///
+    /// 实特征值按从大到小排列；复特征值按虚部先正后负排列。
+    pub fn eigenvalues(&self) -> [(f64, f64); 2] {
+        match self.eigen() {
+            Eigen2::Real { values, .. } => [(values[0], 0.0), (values[1], 0.0)],
+            Eigen2::Complex { re, im, .. } => [(re, im), (re, -im)],
+        }
+    }
+
+    /// 闭式求特征值和特征向量，见 `Eigen2`。
+    ///
+    /// 判别式写成 ((m11 - m22)/2)² + m12 m21，而不是 tr²/4 - det，
+    /// 这样对角元很接近时不会因为两个大数相减而丢失精度；
+    /// 较小的实特征值用 det / λ₁ 求，避免 tr/2 - √disc 的相消。
+    pub fn eigen(&self) -> Eigen2 {
+        let half_tr = self.trace() / 2.0;
+        let h = (self.m11 - self.m22) / 2.0;
+        let disc = h * h + self.m12 * self.m21;
+        if disc < 0.0 {
+            let im = (-disc).sqrt();
+            // (A - λI) 的第一行是 (m11 - λ, m12)，取 v = (m12, λ - m11)；复数情形下 m12 一定不为 0
+            let vr = Vector2::new(self.m12, half_tr - self.m11);
+            let vi = Vector2::new(0.0, im);
+            let n = (vr.length_sq() + vi.length_sq()).sqrt();
+            return Eigen2::Complex {
+                re: half_tr,
+                im,
+                vector_re: vr * (1.0 / n),
+                vector_im: vi * (1.0 / n),
+            };
+        }
+        let root = disc.sqrt();
+        let big = half_tr + root.copysign(half_tr);
+        let small = if big != 0.0 {
+            self.determinant() / big
+        } else {
+            half_tr - root
+        };
+        let (l1, l2) = if big >= small {
+            (big, small)
+        } else {
+            (small, big)
+        };
+        let (v1, v2) = (self.eigenvector(l1), self.eigenvector(l2));
+        // A = λI 时任何向量都是特征向量，取两条坐标轴
+        let (v1, v2) = match (v1, v2) {
+            (Some(a), Some(b)) => (a, b),
+            _ => (Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)),
+        };
+        Eigen2::Real {
+            values: [l1, l2],
+            vectors: [v1, v2],
+        }
+    }
+
+    /// 实特征值 λ 的单位特征向量：(A - λI) 两行各给出一个候选，取较长的那个。
+    fn eigenvector(&self, lambda: f64) -> Option<Vector2> {
+        let a = Vector2::new(self.m12, lambda - self.m11);
+        let b = Vector2::new(lambda - self.m22, self.m21);
+        let v = if a.length_sq() >= b.length_sq() { a } else { b };
+        let scale = self.m11.abs() + self.m12.abs() + self.m21.abs() + self.m22.abs();
+        if v.length_sq() <= (1e-14 * scale).powi(2) {
+            return None;
+        }
+        let n = v.length_sq().sqrt();
+        Some(v * (1.0 / n))
+    }
+
+    /// 闭式 SVD：A = U diag(σ₁, σ₂) Vᵀ，见 `Svd2`。
+    ///
+    /// 把 A 拆成“相似部分”和“反相似部分”：
+    ///   E = (m11 + m22)/2，H = (m21 - m12)/2，F = (m11 - m22)/2，G = (m21 + m12)/2，
+    /// 则 A = R(φ) diag(Q + R, Q - R) R(θ)，其中 Q = ‖(E, H)‖，R = ‖(F, G)‖，
+    /// φ ± θ 分别是 (E, H) 与 (F, G) 的辐角。
+    /// 不需要迭代，也不需要单独处理 A = 0、秩 1 或两个奇异值相等的情况（此时 atan2(0, 0) = 0）。
+    /// 较小的奇异值用 det / σ₁ 求，避免 Q - R 在 A 接近奇异时的相消。
+    pub fn svd(&self) -> Svd2 {
+        let (s1, s2_signed, phi, theta) = self.rotation_scale_rotation();
+        let mut u = Matrix2x2::rotation(phi);
+        if s2_signed < 0.0 {
+            // det A < 0：把符号放进 U 的第二列，U 成为镜像
+            u = u * Matrix2x2::scaling(1.0, -1.0);
+        }
+        Svd2 {
+            u,
+            singular_values: [s1, s2_signed.abs()],
+            vt: Matrix2x2::rotation(theta),
+        }
+    }
+
+    /// 极分解 A = R S：R 是旋转（det R = 1），S 是对称的伸缩矩阵，见 `Polar2`。
+    pub fn polar(&self) -> Polar2 {
+        let (s1, s2, phi, theta) = self.rotation_scale_rotation();
+        // A = R(φ) D R(θ) = R(φ + θ) · R(θ)ᵀ D R(θ)
+        let r_theta = Matrix2x2::rotation(theta);
+        Polar2 {
+            rotation: Matrix2x2::rotation(phi + theta),
+            angle: phi + theta,
+            stretch: r_theta.transpose() * Matrix2x2::scaling(s1, s2) * r_theta,
+            principal_stretches: [s1, s2],
+            principal_angle: -theta,
+        }
+    }
+
+    /// 极分解中旋转部分的角度：atan2(m21 - m12, m11 + m22)，范围 (-π, π]。
+    pub fn rotation_angle(&self) -> f64 {
+        (self.m21 - self.m12).atan2(self.m11 + self.m22)
+    }
+
+    /// A = R(φ) diag(s1, s2) R(θ)，s1 >= |s2|，s2 与 det A 同号。返回 (s1, s2, φ, θ)。
+    ///
+    /// 先乘以 2 的整数次幂把最大元素缩放到 1 附近再计算，否则 1e±200 量级的矩阵在求 det 时就会上溢或下溢；
+    /// 用 2 的幂是为了让缩放本身没有舍入误差（接近奇异时 det 对元素的微小扰动非常敏感）。
+    fn rotation_scale_rotation(&self) -> (f64, f64, f64, f64) {
+        let scale = self
+            .m11
+            .abs()
+            .max(self.m12.abs())
+            .max(self.m21.abs())
+            .max(self.m22.abs());
+        if scale == 0.0 {
+            return (0.0, 0.0, 0.0, 0.0);
+        }
+        let k = scale.log2().round().clamp(-1022.0, 1023.0) as i32;
+        let a = *self * 2f64.powi(-k);
+        let scale = 2f64.powi(k);
+        let e = (a.m11 + a.m22) / 2.0;
+        let f = (a.m11 - a.m22) / 2.0;
+        let g = (a.m21 + a.m12) / 2.0;
+        let h = (a.m21 - a.m12) / 2.0;
+        // 缩放后最大元素在 [1/√2, √2] 内，s1 >= 最大元素 / √2 > 0
+        let s1 = e.hypot(h) + f.hypot(g);
+        let s2 = a.determinant() / s1;
+        let a1 = g.atan2(f);
+        let a2 = h.atan2(e);
+        (s1 * scale, s2 * scale, (a2 + a1) / 2.0, (a2 - a1) / 2.0)
+    }
+}
+
+/// `Matrix2x2::svd` 的结果：A = U Σ Vᵀ，字段含义与 `svd::Svd` 相同。
+///
+/// - singular_values: σ₁ >= σ₂ >= 0
+/// - vt 是旋转；u 在 det A >= 0 时是旋转，det A < 0 时是镜像
+#[derive(Debug, Clone, Copy)]
+pub struct Svd2 {
+    pub u: Matrix2x2,
+    pub singular_values: [f64; 2],
+    pub vt: Matrix2x2,
+}
+
+impl Svd2 {
+    /// 重新乘出 U Σ Vᵀ。
+    pub fn reconstruct(&self) -> Matrix2x2 {
+        let [s1, s2] = self.singular_values;
+        self.u * Matrix2x2::scaling(s1, s2) * self.vt
+    }
+
+    /// 条件数 σ₁ / σ₂（奇异时为无穷大）。
+    pub fn condition_number(&self) -> f64 {
+        // 零矩阵时 σ₁ = σ₂ = 0，直接相除会得到 NaN
+        if self.singular_values[1] == 0.0 {
+            return f64::INFINITY;
+        }
+        self.singular_values[0] / self.singular_values[1]
+    }
+}
+
+/// `Matrix2x2::polar` 的结果：A = R S，常用于分析二维变形梯度。
+///
+/// - rotation = R(angle)，是真正的旋转（不含镜像）
+/// - stretch = S 是对称矩阵，特征值为 principal_stretches（λ₁ >= |λ₂|），
+///   第一个特征向量与 x 轴的夹角为 principal_angle
+///
+/// det A > 0 时 S 正定，就是通常意义下的右伸长张量 U = √(AᵀA)；
+/// det A < 0 时镜像留在 S 里，λ₂ < 0。
+#[derive(Debug, Clone, Copy)]
+pub struct Polar2 {
+    pub rotation: Matrix2x2,
+    pub angle: f64,
+    pub stretch: Matrix2x2,
+    pub principal_stretches: [f64; 2],
+    pub principal_angle: f64,
+}
+
+impl Polar2 {
+    /// 重新乘出 R S。
+    pub fn reconstruct(&self) -> Matrix2x2 {
+        self.rotation * self.stretch
+    }
+
+    /// 最大剪切 (λ₁ - λ₂) / 2：伸长张量在与主方向成 45° 的坐标系下的非对角元。
+    pub fn max_shear(&self) -> f64 {
+        (self.principal_stretches[0] - self.principal_stretches[1]) / 2.0
+    }
+}
+
+/// `Matrix2x2::eigen` 的结果。
+#[derive(Debug, Clone, Copy)]
+pub enum Eigen2 {
+    /// 两个实特征值 λ₁ >= λ₂ 及对应的单位特征向量。
+    ///
+    /// 亏损矩阵（例如 [1 1; 0 1]）只有一个特征方向，此时两个向量相同。
+    Real {
+        values: [f64; 2],
+        vectors: [Vector2; 2],
+    },
+    /// 一对共轭复特征值 re ± i·im（im > 0）；
+    /// re + i·im 的特征向量是 vector_re + i·vector_im（另一个取共轭），整体归一化为单位长度。
+    Complex {
+        re: f64,
+        im: f64,
+        vector_re: Vector2,
+        vector_im: Vector2,
+    },
 }
 
 /// 实现矩阵乘以向量：Matrix2x2 * Vector2
@@ -88,8 +379,336 @@ impl Mul for Matrix2x2 {
     }
 }
 
+impl Add for Matrix2x2 {
+    type Output = Matrix2x2;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.m11 + rhs.m11,
+            self.m12 + rhs.m12,
+            self.m21 + rhs.m21,
+            self.m22 + rhs.m22,
+        )
+    }
+}
+
+impl Sub for Matrix2x2 {
+    type Output = Matrix2x2;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.m11 - rhs.m11,
+            self.m12 - rhs.m12,
+            self.m21 - rhs.m21,
+            self.m22 - rhs.m22,
+        )
+    }
+}
+
+impl Neg for Matrix2x2 {
+    type Output = Matrix2x2;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.m11, -self.m12, -self.m21, -self.m22)
+    }
+}
+
+impl Mul<f64> for Matrix2x2 {
+    type Output = Matrix2x2;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(
+            self.m11 * rhs,
+            self.m12 * rhs,
+            self.m21 * rhs,
+            self.m22 * rhs,
+        )
+    }
+}
+
+impl Mul<Matrix2x2> for f64 {
+    type Output = Matrix2x2;
+
+    fn mul(self, rhs: Matrix2x2) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl From<Matrix2x2> for Matrix {
+    fn from(m: Matrix2x2) -> Self {
+        Matrix::new(2, 2, vec![m.m11, m.m12, m.m21, m.m22]).expect("2 × 2 data")
+    }
+}
+
+/// 从稠密矩阵转换，必须是 2 × 2。
+impl TryFrom<&Matrix> for Matrix2x2 {
+    type Error = MatrixError;
+
+    fn try_from(a: &Matrix) -> Result<Self, MatrixError> {
+        if a.rows() != 2 || a.cols() != 2 {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let d = a.as_slice();
+        Ok(Self::new(d[0], d[1], d[2], d[3]))
+    }
+}
+
 impl From<[[f64; 2]; 2]> for Matrix2x2 {
     fn from(arr: [[f64; 2]; 2]) -> Self {
         Self::new(arr[0][0], arr[0][1], arr[1][0], arr[1][1])
     }
 }
+
+/// 4 × 4 矩阵，用于三维齐次坐标：仿射变换（最后一行为 0 0 0 1）以及透视/正交投影。
+///
+/// 约定与 OpenGL 相同：列向量右乘（p' = M p），右手坐标系，相机看向 -z，
+/// 投影后的裁剪空间在除以 w 之后落在 [-1, 1]³。
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix4x4 {
+    m: [[f64; 4]; 4],
+}
+
+impl Matrix4x4 {
+    /// 按行给出 16 个元素：m[row][col]。
+    pub fn new(m: [[f64; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 })
+        }))
+    }
+
+    /// 第 row 行第 col 列；越界时 panic（与数组下标一致）。
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        self.m[row][col]
+    }
+
+    pub fn to_array(&self) -> [[f64; 4]; 4] {
+        self.m
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self.m[j][i])
+        }))
+    }
+
+    /// 平移 p ↦ p + t。
+    pub fn translation(t: &Vector3) -> Self {
+        let mut r = Self::identity();
+        r.m[0][3] = t.x();
+        r.m[1][3] = t.y();
+        r.m[2][3] = t.z();
+        r
+    }
+
+    /// 沿坐标轴缩放 (sx, sy, sz)。
+    pub fn scaling(s: &Vector3) -> Self {
+        let mut r = Self::identity();
+        r.m[0][0] = s.x();
+        r.m[1][1] = s.y();
+        r.m[2][2] = s.z();
+        r
+    }
+
+    /// 单位四元数 q 对应的旋转。
+    pub fn rotation(q: &Quaternion) -> Self {
+        let r = q.to_rotation_matrix();
+        let mut out = Self::identity();
+        for (i, row) in r.as_slice().chunks_exact(3).enumerate() {
+            out.m[i][..3].copy_from_slice(row);
+        }
+        out
+    }
+
+    /// 平移 · 旋转 · 缩放：先缩放，再旋转，最后平移。
+    pub fn from_trs(t: &Vector3, r: &Quaternion, s: &Vector3) -> Self {
+        Self::translation(t) * Self::rotation(r) * Self::scaling(s)
+    }
+
+    /// 观察矩阵：把世界坐标变到相机坐标，相机位于 eye、看向 target，up 给出“上”的大致方向。
+    ///
+    /// 相机坐标系的三个轴是 s = f × up（右）、u = s × f（上）、-f（后），f 是归一化的视线方向。
+    /// eye 与 target 重合，或 up 与视线平行时返回 None。
+    pub fn look_at(eye: &Vector3, target: &Vector3, up: &Vector3) -> Option<Self> {
+        let f = (*target - *eye).normalize()?;
+        let s = cross(&f, up).normalize()?;
+        let u = cross(&s, &f);
+        Some(Self::new([
+            [s.x(), s.y(), s.z(), -dot(&s, eye)],
+            [u.x(), u.y(), u.z(), -dot(&u, eye)],
+            [-f.x(), -f.y(), -f.z(), dot(&f, eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    }
+
+    /// 透视投影：竖直视角 fov_y（弧度）、宽高比 aspect、近/远裁剪面距离 near、far。
+    ///
+    /// 相机坐标中 z = -near 映射到 NDC 的 z = -1，z = -far 映射到 z = 1。
+    /// 参数不合法（fov_y ∉ (0, π)、aspect <= 0、不满足 0 < near < far）时返回 None。
+    pub fn perspective(fov_y: f64, aspect: f64, near: f64, far: f64) -> Option<Self> {
+        let valid = fov_y > 0.0 && fov_y < std::f64::consts::PI && aspect > 0.0;
+        if !valid || near <= 0.0 || far <= near {
+            return None;
+        }
+        let f = 1.0 / (fov_y / 2.0).tan();
+        Some(Self::new([
+            [f / aspect, 0.0, 0.0, 0.0],
+            [0.0, f, 0.0, 0.0],
+            [
+                0.0,
+                0.0,
+                (far + near) / (near - far),
+                2.0 * far * near / (near - far),
+            ],
+            [0.0, 0.0, -1.0, 0.0],
+        ]))
+    }
+
+    /// 正交投影：把盒子 [left, right] × [bottom, top] × [-far, -near] 映射到 [-1, 1]³。
+    ///
+    /// 任一方向的区间长度为 0 时返回 None。
+    pub fn orthographic(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Option<Self> {
+        if right == left || top == bottom || far == near {
+            return None;
+        }
+        Some(Self::new([
+            [
+                2.0 / (right - left),
+                0.0,
+                0.0,
+                -(right + left) / (right - left),
+            ],
+            [
+                0.0,
+                2.0 / (top - bottom),
+                0.0,
+                -(top + bottom) / (top - bottom),
+            ],
+            [0.0, 0.0, -2.0 / (far - near), -(far + near) / (far - near)],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    }
+
+    /// 作用在齐次坐标 (x, y, z, w) 上。
+    pub fn transform_homogeneous(&self, v: [f64; 4]) -> [f64; 4] {
+        std::array::from_fn(|i| (0..4).map(|j| self.m[i][j] * v[j]).sum())
+    }
+
+    /// 变换一个点：按 (x, y, z, 1) 相乘后再除以 w（透视除法）。
+    ///
+    /// w = 0（点落在投影中心所在的平面上）时返回 None。
+    pub fn transform_point(&self, p: &Vector3) -> Option<Vector3> {
+        let [x, y, z, w] = self.transform_homogeneous([p.x(), p.y(), p.z(), 1.0]);
+        if w == 0.0 {
+            return None;
+        }
+        Some(Vector3::new(x / w, y / w, z / w))
+    }
+
+    /// 变换一个方向：按 (x, y, z, 0) 相乘，平移不起作用。只对仿射矩阵有意义。
+    pub fn transform_vector(&self, v: &Vector3) -> Vector3 {
+        let [x, y, z, _] = self.transform_homogeneous([v.x(), v.y(), v.z(), 0.0]);
+        Vector3::new(x, y, z)
+    }
+
+    /// 行列式（按第一行展开成 3 × 3 子式）。
+    pub fn determinant(&self) -> f64 {
+        (0..4)
+            .map(|j| {
+                let sign = if j % 2 == 0 { 1.0 } else { -1.0 };
+                sign * self.m[0][j] * self.minor3(0, j)
+            })
+            .sum()
+    }
+
+    /// 去掉第 row 行和第 col 列之后的 3 × 3 行列式。
+    fn minor3(&self, row: usize, col: usize) -> f64 {
+        let rs: Vec<usize> = (0..4).filter(|&i| i != row).collect();
+        let cs: Vec<usize> = (0..4).filter(|&j| j != col).collect();
+        let a = |i: usize, j: usize| self.m[rs[i]][cs[j]];
+        a(0, 0) * (a(1, 1) * a(2, 2) - a(1, 2) * a(2, 1))
+            - a(0, 1) * (a(1, 0) * a(2, 2) - a(1, 2) * a(2, 0))
+            + a(0, 2) * (a(1, 0) * a(2, 1) - a(1, 1) * a(2, 0))
+    }
+
+    /// 逆矩阵：列主元 Gauss-Jordan 消元；主元相对最大元素小于 1e-14 时返回 `MatrixError::Singular`。
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        let mut a = self.m;
+        let mut inv = Self::identity().m;
+        let scale = a.iter().flatten().fold(0.0_f64, |s, x| s.max(x.abs()));
+        for k in 0..4 {
+            let p = (k..4)
+                .max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))
+                .expect("non-empty range");
+            if a[p][k].abs() <= 1e-14 * scale {
+                return Err(MatrixError::Singular);
+            }
+            a.swap(k, p);
+            inv.swap(k, p);
+            let d = a[k][k];
+            for j in 0..4 {
+                a[k][j] /= d;
+                inv[k][j] /= d;
+            }
+            for i in 0..4 {
+                if i == k {
+                    continue;
+                }
+                let f = a[i][k];
+                for j in 0..4 {
+                    a[i][j] -= f * a[k][j];
+                    inv[i][j] -= f * inv[k][j];
+                }
+            }
+        }
+        Ok(Self::new(inv))
+    }
+}
+
+impl Mul for Matrix4x4 {
+    type Output = Matrix4x4;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| (0..4).map(|k| self.m[i][k] * rhs.m[k][j]).sum())
+        }))
+    }
+}
+
+impl From<[[f64; 4]; 4]> for Matrix4x4 {
+    fn from(m: [[f64; 4]; 4]) -> Self {
+        Self::new(m)
+    }
+}
+
+impl From<Matrix4x4> for Matrix {
+    fn from(m: Matrix4x4) -> Self {
+        Matrix::new(4, 4, m.m.concat()).expect("4 × 4 data")
+    }
+}
+
+/// 从稠密矩阵转换，必须是 4 × 4。
+impl TryFrom<&Matrix> for Matrix4x4 {
+    type Error = MatrixError;
+
+    fn try_from(a: &Matrix) -> Result<Self, MatrixError> {
+        if a.rows() != 4 || a.cols() != 4 {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let d = a.as_slice();
+        Ok(Self::new(std::array::from_fn(|i| {
+            std::array::from_fn(|j| d[i * 4 + j])
+        })))
+    }
+}
@@ -1,9 +1,17 @@
+use crate::blas::{Transpose, gemm, gemv};
+use crate::triangular::{LowerTriangular, UpperTriangular};
+
 /// 通用 2D 矩阵类型 Matrix：
 /// - 用于表示任意大小的 m×n 实数矩阵
 /// - 内部建议使用一维 Vec<f64> 按行优先 (row-major) 存储：
 ///   data[row * cols + col]
 
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "MatrixFields")
+)]
 pub struct Matrix {
     rows: usize,
     cols: usize,
@@ -14,7 +22,10 @@ pub struct Matrix {
 pub enum MatrixError {
     DimensionMismatch,
     NotSquare,
+    NotSymmetric,
+    NotPositiveDefinite,
     Singular,
+    NoConvergence,
 }
 
 impl std::fmt::Display for MatrixError {
@@ -22,13 +33,40 @@ impl std::fmt::Display for MatrixError {
         match self {
             MatrixError::DimensionMismatch => write!(f, "dimension mismatch"),
             MatrixError::NotSquare => write!(f, "matrix is not square"),
+            MatrixError::NotSymmetric => write!(f, "matrix is not symmetric"),
+            MatrixError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
             MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::NoConvergence => write!(f, "iteration did not converge"),
         }
     }
 }
 
 impl std::error::Error for MatrixError {}
 
+/// 反序列化时的中间形式：先原样读出三个字段，再交给 `Matrix::new` 检查 data 的长度。
+///
+/// rows、cols 来自不可信的输入，先确认 rows × cols 不溢出，否则 `Matrix::new` 里的乘法
+/// 在 debug 下 panic、在 release 下回绕成一个错误的长度。
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MatrixFields {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<MatrixFields> for Matrix {
+    type Error = MatrixError;
+
+    fn try_from(f: MatrixFields) -> Result<Self, MatrixError> {
+        if f.rows.checked_mul(f.cols).is_none() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Matrix::new(f.rows, f.cols, f.data)
+    }
+}
+
 impl Matrix {
     pub fn new(rows: usize, cols: usize, data: Vec<f64>) -> Result<Self, MatrixError> {
         if data.len() != rows * cols {
@@ -58,6 +96,16 @@ impl Matrix {
         self.cols
     }
 
+    /// 以行优先顺序返回全部元素的只读切片。
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// 以行优先顺序返回全部元素的可写切片（供 `blas` 等原地计算的内核使用）。
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.data
+    }
+
     /// 内部索引辅助函数：
     /// - 把 (row, col) 映射成 data 里的下标 index
     ///
@@ -87,6 +135,42 @@ impl Matrix {
         self.data[index] = value;
     }
 
+    /// 取出第 col 列，返回长度为 rows 的 Vec。
+    pub fn col(&self, col: usize) -> Result<Vec<f64>, MatrixError> {
+        if col >= self.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok((0..self.rows)
+            .map(|row| self.data[self.index(row, col)])
+            .collect())
+    }
+
+    /// 用 values 覆盖第 col 列，要求 values.len() == rows。
+    pub fn set_col(&mut self, col: usize, values: &[f64]) -> Result<(), MatrixError> {
+        if col >= self.cols || values.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        for (row, v) in values.iter().enumerate() {
+            self.set(row, col, *v);
+        }
+        Ok(())
+    }
+
+    /// 交换第 a 行和第 b 行（初等行变换之一）。
+    ///
+    /// 行号越界时返回 `MatrixError::DimensionMismatch`。
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), MatrixError> {
+        if a >= self.rows || b >= self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        if a != b {
+            for col in 0..self.cols {
+                self.data.swap(a * self.cols + col, b * self.cols + col);
+            }
+        }
+        Ok(())
+    }
+
     pub fn transpose(&self) -> Result<Matrix, MatrixError> {
         let mut transposed = Matrix::zeros(self.cols(), self.rows());
         for i in 0..self.rows() {
@@ -121,7 +205,11 @@ impl Matrix {
         Ok(true)
     }
 
-    pub fn lu_decomposition(&self) -> Result<(Matrix, Matrix), MatrixError> {
+    /// 不选主元的 LU 分解：A = LU，L 为单位下三角，U 为上三角。
+    ///
+    /// 主元（U 的对角元）绝对值小于 1e-12 时返回 `MatrixError::Singular`；
+    /// 需要对任意可逆矩阵都能分解时用 `lu_partial_pivot`。
+    pub fn lu_decomposition(&self) -> Result<(LowerTriangular, UpperTriangular), MatrixError> {
         if self.rows() != self.cols() {
             return Err(MatrixError::NotSquare);
         }
@@ -143,48 +231,168 @@ impl Matrix {
                 }
             }
         }
-        Ok((l, u))
+        Ok((LowerTriangular::unit(l)?, UpperTriangular::new(u)?))
     }
 
-    pub fn solve_lu(l: &Matrix, u: &Matrix, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
-        if l.rows() != l.cols() || u.rows() != u.cols() {
+    /// Cholesky 分解：A = L Lᵀ，L 为对角元为正的下三角矩阵。
+    ///
+    /// 要求 A 对称正定：
+    /// - 不对称（按相对误差 1e-12 判断）时返回 `MatrixError::NotSymmetric`；
+    /// - 消元过程中出现非正的对角元时返回 `MatrixError::NotPositiveDefinite`。
+    ///
+    /// 只需要 LU 一半的计算量，并且不需要选主元。
+    pub fn cholesky(&self) -> Result<Matrix, MatrixError> {
+        if self.rows() != self.cols() {
             return Err(MatrixError::NotSquare);
         }
-        if l.rows() != u.rows() || l.rows() != b.len() {
-            return Err(MatrixError::DimensionMismatch);
+        let scale = self.data.iter().fold(0.0_f64, |m, x| m.max(x.abs()));
+        if !self.is_symmetric(1e-12 * scale.max(1.0))? {
+            return Err(MatrixError::NotSymmetric);
         }
 
-        let n = l.rows();
+        let n = self.rows();
+        let mut l = Matrix::zeros(n, n);
+        for j in 0..n {
+            let mut diag = self.get(j, j)?;
+            for k in 0..j {
+                diag -= l.get(j, k)? * l.get(j, k)?;
+            }
+            if diag <= 0.0 || !diag.is_finite() {
+                return Err(MatrixError::NotPositiveDefinite);
+            }
+            let ljj = diag.sqrt();
+            l.set(j, j, ljj);
 
-        let mut y = vec![0.0; n];
-        for i in 0..n {
-            let mut sum = 0.0;
-            for (j, yj) in y[..i].iter().enumerate() {
-                sum += l.get(i, j)? * yj;
+            for i in (j + 1)..n {
+                let mut sum = self.get(i, j)?;
+                for k in 0..j {
+                    sum -= l.get(i, k)? * l.get(j, k)?;
+                }
+                l.set(i, j, sum / ljj);
             }
-            let diag = l.get(i, i)?;
-            if diag.abs() < 1e-12 {
-                return Err(MatrixError::Singular);
+        }
+        Ok(l)
+    }
+
+    /// Cholesky 因子的秩一更新：self 是 A = L Lᵀ 的因子 L，原地改为 A + x xᵀ 的因子。
+    ///
+    /// 用一串 Givens 旋转逐列把 x 并入 L，O(n²)，而重新分解是 O(n³)。
+    /// 最小二乘里给 A 增加一行 x 时，AᵀA 正好变成 AᵀA + x xᵀ。
+    pub fn cholesky_update(&mut self, x: &[f64]) -> Result<(), MatrixError> {
+        self.cholesky_rank_one(x, 1.0)
+    }
+
+    /// Cholesky 因子的秩一降阶：原地改为 A - x xᵀ 的因子。
+    ///
+    /// 如果 A - x xᵀ 不再正定，返回 `MatrixError::NotPositiveDefinite`，此时 self 保持不变。
+    pub fn cholesky_downdate(&mut self, x: &[f64]) -> Result<(), MatrixError> {
+        self.cholesky_rank_one(x, -1.0)
+    }
+
+    /// sign = 1 为更新，-1 为降阶（双曲旋转）。先在副本上计算，成功后再写回。
+    fn cholesky_rank_one(&mut self, x: &[f64], sign: f64) -> Result<(), MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = self.rows();
+        if x.len() != n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut l = self.data.clone();
+        let mut x = x.to_vec();
+        for k in 0..n {
+            let lkk = l[k * n + k];
+            let r2 = lkk * lkk + sign * x[k] * x[k];
+            if r2 <= 0.0 || !r2.is_finite() || lkk == 0.0 {
+                return Err(MatrixError::NotPositiveDefinite);
             }
-            y[i] = (b[i] - sum) / diag;
+            let r = r2.sqrt();
+            let c = r / lkk;
+            let s = x[k] / lkk;
+            l[k * n + k] = r;
+            for i in (k + 1)..n {
+                let lik = (l[i * n + k] + sign * s * x[i]) / c;
+                x[i] = c * x[i] - s * lik;
+                l[i * n + k] = lik;
+            }
+        }
+        self.data = l;
+        Ok(())
+    }
+
+    /// 带部分选主元的 LU 分解：PA = LU。
+    ///
+    /// 和 `lu_decomposition` 的区别是每一列都选绝对值最大的元素作主元并交换行，
+    /// 因此只要 A 可逆就不会因为主元恰好为 0（或很小）而失败，数值上也更稳定。
+    ///
+    /// 返回 (L, U, perm)：perm[i] 表示 PA 的第 i 行来自 A 的第 perm[i] 行。
+    pub fn lu_partial_pivot(
+        &self,
+    ) -> Result<(LowerTriangular, UpperTriangular, Vec<usize>), MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare);
         }
 
-        let mut x = vec![0.0; n];
-        for i_rev in 0..n {
-            let i = n - 1 - i_rev;
-            let mut sum = 0.0;
-            for (offset, xj) in x[(i + 1)..].iter().enumerate() {
-                let j = i + 1 + offset;
-                sum += u.get(i, j)? * xj;
+        let n = self.rows();
+        let mut l = Matrix::zeros(n, n);
+        let mut u = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for i in 0..n {
+            let mut best = i;
+            for r in (i + 1)..n {
+                if u.get(r, i)?.abs() > u.get(best, i)?.abs() {
+                    best = r;
+                }
             }
-            let diag = u.get(i, i)?;
-            if diag.abs() < 1e-12 {
+            if u.get(best, i)?.abs() < 1e-12 {
                 return Err(MatrixError::Singular);
             }
-            x[i] = (y[i] - sum) / diag;
+            if best != i {
+                u.swap_rows(i, best)?;
+                l.swap_rows(i, best)?;
+                perm.swap(i, best);
+            }
+
+            let pivot = u.get(i, i)?;
+            for j in (i + 1)..n {
+                let factor = u.get(j, i)? / pivot;
+                l.set(j, i, factor);
+                for k in i..n {
+                    u.set(j, k, u.get(j, k)? - factor * u.get(i, k)?);
+                }
+            }
+        }
+        Ok((LowerTriangular::unit(l)?, UpperTriangular::new(u)?, perm))
+    }
+
+    /// 用 `lu_partial_pivot` 的结果求解 Ax = b：先按 perm 重排 b，再调用 `solve_lu`。
+    pub fn solve_lu_pivoted(
+        l: &LowerTriangular,
+        u: &UpperTriangular,
+        perm: &[usize],
+        b: &[f64],
+    ) -> Result<Vec<f64>, MatrixError> {
+        if perm.len() != b.len() {
+            return Err(MatrixError::DimensionMismatch);
         }
+        let pb: Vec<f64> = perm.iter().map(|&p| b[p]).collect();
+        Matrix::solve_lu(l, u, &pb)
+    }
 
-        Ok(x)
+    /// 用 LU 分解求解 Ax = b：先前代 L y = b，再回代 U x = y。
+    ///
+    /// U 有为 0 的对角元时返回 `MatrixError::Singular`。
+    pub fn solve_lu(
+        l: &LowerTriangular,
+        u: &UpperTriangular,
+        b: &[f64],
+    ) -> Result<Vec<f64>, MatrixError> {
+        if l.size() != u.size() || l.size() != b.len() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        u.solve(&l.solve(b)?)
     }
 }
 
@@ -194,15 +402,7 @@ pub fn matmul(a: &Matrix, b: &Matrix) -> Result<Matrix, MatrixError> {
         return Err(MatrixError::DimensionMismatch);
     }
     let mut c = Matrix::zeros(a.rows(), b.cols());
-    for i in 0..a.rows() {
-        for j in 0..b.cols() {
-            let mut sum = 0.0;
-            for k in 0..a.cols() {
-                sum += a.get(i, k)? * b.get(k, j)?;
-            }
-            c.set(i, j, sum);
-        }
-    }
+    gemm(Transpose::No, Transpose::No, 1.0, a, b, 0.0, &mut c)?;
     Ok(c)
 }
 
@@ -218,13 +418,7 @@ pub fn matvec(a: &Matrix, x: &[f64]) -> Result<Vec<f64>, MatrixError> {
     if a.cols() != x.len() {
         return Err(MatrixError::DimensionMismatch);
     }
-    let mut y = Vec::with_capacity(a.rows());
-    for i in 0..a.rows() {
-        let mut sum = 0.0;
-        for j in 0..a.cols() {
-            sum += a.get(i, j)? * x[j];
-        }
-        y.push(sum);
-    }
+    let mut y = vec![0.0; a.rows()];
+    gemv(Transpose::No, 1.0, a, x, 0.0, &mut y)?;
     Ok(y)
 }
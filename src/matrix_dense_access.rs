@@ -9,7 +9,7 @@ pub fn safe_get(a: &Matrix, row: usize, col: usize) -> Result<f64, MatrixError>
         return Err(MatrixError::DimensionMismatch);
     }
 
-    Ok(a.get(row, col)?)
+    a.get(row, col)
 }
 
 /// 提示：
@@ -0,0 +1,168 @@
+use crate::matrix_dense::{Matrix, MatrixError};
+
+/// 简化行阶梯形（reduced row echelon form）的结果：
+/// - `matrix`: 化简后的矩阵 R
+/// - `pivots`: 每个主元所在的列号，按行顺序排列（长度就是矩阵的秩）
+#[derive(Debug, Clone)]
+pub struct Rref {
+    pub matrix: Matrix,
+    pub pivots: Vec<usize>,
+}
+
+/// 一般线性方程组 Ax = b 的解的情况。
+#[derive(Debug, Clone)]
+pub enum LinearSolution {
+    /// 方程组不相容，没有解
+    NoSolution,
+    /// 有唯一解
+    Unique(Vec<f64>),
+    /// 有无穷多解：x = particular + null_space * t（t 任意）
+    ///
+    /// `null_space` 的每一列是零空间的一个基向量。
+    Infinite {
+        particular: Vec<f64>,
+        null_space: Matrix,
+    },
+}
+
+impl Matrix {
+    /// 用 Gauss–Jordan 消元把矩阵化成简化行阶梯形。
+    ///
+    /// 和 `lu_decomposition` 一样逐列消元，但这里：
+    /// - 每一列选绝对值最大的元素作主元（部分选主元），
+    /// - 主元归一化为 1，并且把主元上方和下方都消成 0，
+    /// - 绝对值不超过 `tol` 的元素视为 0，这一列直接跳过（不是主元列）。
+    pub fn rref(&self, tol: f64) -> Result<Rref, MatrixError> {
+        let mut r = self.clone();
+        let mut pivots = Vec::new();
+        let mut row = 0;
+
+        for col in 0..r.cols() {
+            if row == r.rows() {
+                break;
+            }
+
+            let mut best = row;
+            let mut best_abs = r.get(row, col)?.abs();
+            for i in (row + 1)..r.rows() {
+                let v = r.get(i, col)?.abs();
+                if v > best_abs {
+                    best = i;
+                    best_abs = v;
+                }
+            }
+            if best_abs <= tol {
+                for i in row..r.rows() {
+                    r.set(i, col, 0.0);
+                }
+                continue;
+            }
+            r.swap_rows(row, best)?;
+
+            let pivot = r.get(row, col)?;
+            for k in col..r.cols() {
+                r.set(row, k, r.get(row, k)? / pivot);
+            }
+
+            for i in 0..r.rows() {
+                if i == row {
+                    continue;
+                }
+                let factor = r.get(i, col)?;
+                if factor == 0.0 {
+                    continue;
+                }
+                for k in col..r.cols() {
+                    r.set(i, k, r.get(i, k)? - factor * r.get(row, k)?);
+                }
+                r.set(i, col, 0.0);
+            }
+
+            pivots.push(col);
+            row += 1;
+        }
+
+        Ok(Rref { matrix: r, pivots })
+    }
+
+    /// 矩阵的秩：RREF 中主元的个数。
+    pub fn rank(&self, tol: f64) -> Result<usize, MatrixError> {
+        Ok(self.rref(tol)?.pivots.len())
+    }
+
+    /// 零空间 N(A) 的一组基，按列存放在返回的 cols × (cols - rank) 矩阵里。
+    ///
+    /// 每个自由变量对应一个基向量：该自由变量取 1，其它自由变量取 0，
+    /// 主元变量由 RREF 反解得到。
+    pub fn null_space(&self, tol: f64) -> Result<Matrix, MatrixError> {
+        let rref = self.rref(tol)?;
+        null_space_from_rref(&rref, self.cols())
+    }
+
+    /// 列空间 C(A) 的一组基：原矩阵 A 中主元列组成的 rows × rank 矩阵。
+    pub fn column_space(&self, tol: f64) -> Result<Matrix, MatrixError> {
+        let rref = self.rref(tol)?;
+        let mut basis = Matrix::zeros(self.rows(), rref.pivots.len());
+        for (k, &col) in rref.pivots.iter().enumerate() {
+            for i in 0..self.rows() {
+                basis.set(i, k, self.get(i, col)?);
+            }
+        }
+        Ok(basis)
+    }
+
+    /// 求解一般（不要求方阵、不要求满秩）的线性方程组 Ax = b。
+    ///
+    /// 对增广矩阵 [A | b] 做 RREF：
+    /// - 如果最后一列出现主元，说明出现 0 = 非零，返回 `NoSolution`；
+    /// - 否则自由变量取 0 得到一个特解；
+    /// - 秩等于未知数个数时解唯一，否则附带零空间的基。
+    pub fn solve_general(&self, b: &[f64], tol: f64) -> Result<LinearSolution, MatrixError> {
+        if b.len() != self.rows() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let n = self.cols();
+        let mut augmented = Matrix::zeros(self.rows(), n + 1);
+        for (i, bi) in b.iter().enumerate() {
+            for j in 0..n {
+                augmented.set(i, j, self.get(i, j)?);
+            }
+            augmented.set(i, n, *bi);
+        }
+
+        let rref = augmented.rref(tol)?;
+        if rref.pivots.last() == Some(&n) {
+            return Ok(LinearSolution::NoSolution);
+        }
+
+        let mut particular = vec![0.0; n];
+        for (row, &col) in rref.pivots.iter().enumerate() {
+            particular[col] = rref.matrix.get(row, n)?;
+        }
+
+        if rref.pivots.len() == n {
+            return Ok(LinearSolution::Unique(particular));
+        }
+
+        Ok(LinearSolution::Infinite {
+            particular,
+            null_space: null_space_from_rref(&rref, n)?,
+        })
+    }
+}
+
+/// 从 RREF 的前 `n` 列读出零空间的基（增广矩阵时忽略最后一列）。
+fn null_space_from_rref(rref: &Rref, n: usize) -> Result<Matrix, MatrixError> {
+    let pivots: Vec<usize> = rref.pivots.iter().copied().filter(|&c| c < n).collect();
+    let free: Vec<usize> = (0..n).filter(|c| !pivots.contains(c)).collect();
+
+    let mut basis = Matrix::zeros(n, free.len());
+    for (k, &f) in free.iter().enumerate() {
+        basis.set(f, k, 1.0);
+        for (row, &p) in pivots.iter().enumerate() {
+            basis.set(p, k, -rref.matrix.get(row, f)?);
+        }
+    }
+    Ok(basis)
+}
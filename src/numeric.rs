@@ -1,5 +1,4 @@
 /// 使用梯形法则（trapezoidal rule）近似计算定积分：
-
 /// 要求：
 /// - 如果 xs.len() < 2 或 xs.len() != ys.len()，返回 0.0（或者你可以选择 panic，现在为了简化先返回 0）
 ///
@@ -57,3 +56,24 @@ pub fn finite_differences(xs: &[f64], ys: &[f64]) -> Vec<f64> {
 
     diffs
 }
+
+/// 补偿点积（Ogita–Rump–Oishi 的 Dot2 算法）。
+///
+/// 乘积的舍入误差用 `mul_add` 精确求出（TwoProduct），加法的舍入误差用 TwoSum 求出，
+/// 结果相当于先用两倍工作精度计算、再舍入回 f64。
+///
+/// 要求：a.len() == b.len()，否则只使用较短的那一段（和 zip 的行为一致）。
+pub fn compensated_dot(a: &[f64], b: &[f64]) -> f64 {
+    let mut sum = 0.0;
+    let mut comp = 0.0;
+    for (&x, &y) in a.iter().zip(b) {
+        let p = x * y;
+        let p_err = x.mul_add(y, -p);
+        let t = sum + p;
+        let z = t - sum;
+        let s_err = (sum - (t - z)) + (p - z);
+        sum = t;
+        comp += p_err + s_err;
+    }
+    sum + comp
+}
@@ -13,8 +13,7 @@ impl OdeStepper for Euler {
     where
         F: Fn(f64, f64) -> f64
     {
-        let y1 = y + dt * f(t, y);
-        y1
+        y + dt * f(t, y)
     }
 }
 
@@ -29,8 +28,7 @@ impl OdeStepper for Rk4 {
         let k2 = dt * f(t + dt / 2.0, y + k1 / 2.0);
         let k3 = dt * f(t + dt / 2.0, y + k2 / 2.0);
         let k4 = dt * f(t + dt, y + k3);
-        let y1 = y + (k1 + 2.0 * k2 + 2.0 * k3 + k4) / 6.0;
-        y1
+        y + (k1 + 2.0 * k2 + 2.0 * k3 + k4) / 6.0
     }
 }
 
@@ -56,7 +54,7 @@ where
     let mut y = y0;
 
     result.push((t, y));
-    for step in 0..n_steps {
+    for _ in 0..n_steps {
         y = stepper.step(&f, t, y, dt);
         t += dt;
         result.push((t, y));
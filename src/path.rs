@@ -1,4 +1,4 @@
-use crate::vector::{Vector2, distance};
+use crate::vector_n::{VectorN, distance};
 
 /// 计算一条折线的总长度。
 ///
@@ -9,13 +9,15 @@ use crate::vector::{Vector2, distance};
 ///
 /// 建议实现方式：
 /// - 使用切片自带的 `points.windows(2)`
-///   - 每个窗口是 `&[Vector2]`，长度固定为 2
+///   - 每个窗口是 `&[P]`，长度固定为 2
 ///   - window[0] 和 window[1] 就是一段线段的两个端点
-pub fn polyline_length(points: &[Vector2]) -> f64 {
+///
+/// 点的类型只要能转换成 `VectorN<N>` 就行：`Vector2`、`Vector3`、`VectorN<N>` 都可以直接传入。
+pub fn polyline_length<const N: usize, P: Copy + Into<VectorN<N>>>(points: &[P]) -> f64 {
     if points.len() < 2 {
         return 0.0;
     }
-    points.windows(2).map(|p| distance(&p[0], &p[1])).sum()
+    points.windows(2).map(|p| segment_length(p[0], p[1])).sum()
 }
 
 /// 计算从起点到每个点的“累计长度”。
@@ -28,15 +30,21 @@ pub fn polyline_length(points: &[Vector2]) -> f64 {
 /// 提示：
 /// - 可以先用 `points.windows(2)` 得到每一段的长度，形成一个迭代器：segments_lengths
 /// - 然后用迭代器的 `scan` 或者手写循环，累计这些长度。
-pub fn cumulative_lengths(points: &[Vector2]) -> Vec<f64> {
+///
+/// 与 `polyline_length` 一样接受任意维数的点。
+pub fn cumulative_lengths<const N: usize, P: Copy + Into<VectorN<N>>>(points: &[P]) -> Vec<f64> {
     let mut cumul_len = Vec::new();
     let mut len = 0.0;
     cumul_len.push(len);
 
-    for p in points.windows(2).map(|p| distance(&p[0], &p[1])) {
+    for p in points.windows(2).map(|p| segment_length(p[0], p[1])) {
         len += p;
         cumul_len.push(len);
     }
 
     cumul_len
 }
+
+fn segment_length<const N: usize, P: Into<VectorN<N>>>(a: P, b: P) -> f64 {
+    distance(&a.into(), &b.into())
+}
@@ -0,0 +1,219 @@
+use crate::path::polyline_length;
+use crate::primitives::{Orientation, Segment2, orient2d, same_point, sign_to_orientation};
+use crate::vector::{Vector2, dot};
+
+/// 平面多边形，按顺序保存顶点，首尾自动相连（不要把起点在末尾重复一遍）。
+///
+/// 顶点顺序决定有向面积的符号：逆时针为正，顺时针为负。
+/// 不要求多边形是简单的（可以自交），各方法在自交时的含义见各自的说明。
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon {
+    vertices: Vec<Vector2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Vector2>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Vector2] {
+        &self.vertices
+    }
+
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// 依次给出每条边（包括最后一个顶点回到第一个顶点的那条）。
+    pub fn edges(&self) -> impl Iterator<Item = Segment2> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| Segment2::new(self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// 顶点顺序反过来（逆时针 ↔ 顺时针）。
+    pub fn reversed(&self) -> Self {
+        let mut vertices = self.vertices.clone();
+        vertices.reverse();
+        Self { vertices }
+    }
+
+    /// 有向面积（鞋带公式）：逆时针为正，顺时针为负；自交时各部分按绕数加权。
+    ///
+    /// 以第一个顶点为原点分成扇形三角形求和，每个三角形用 `orient2d` 计算；
+    /// 顶点坐标很大而多边形很小时，比直接套 Σ xᵢ yᵢ₊₁ - xᵢ₊₁ yᵢ 的相消误差小得多。
+    pub fn signed_area(&self) -> f64 {
+        let Some(p0) = self.vertices.first() else {
+            return 0.0;
+        };
+        self.vertices
+            .windows(2)
+            .skip(1)
+            .map(|w| orient2d(p0, &w[0], &w[1]))
+            .sum::<f64>()
+            / 2.0
+    }
+
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// 环绕方向：按有向面积的符号判断，面积为 0（少于 3 个点、全部共线）时为 `Collinear`。
+    pub fn orientation(&self) -> Orientation {
+        sign_to_orientation(self.signed_area())
+    }
+
+    /// 面积重心；面积为 0 时返回 None。
+    pub fn centroid(&self) -> Option<Vector2> {
+        let p0 = *self.vertices.first()?;
+        let mut twice_area = 0.0;
+        let mut weighted = Vector2::new(0.0, 0.0);
+        for w in self.vertices.windows(2).skip(1) {
+            // 三角形 (p0, w[0], w[1]) 的两倍有向面积和重心（相对 p0）
+            let cross = orient2d(&p0, &w[0], &w[1]);
+            twice_area += cross;
+            weighted = weighted + (w[0] - p0 + (w[1] - p0)) * cross;
+        }
+        if twice_area == 0.0 {
+            return None;
+        }
+        Some(p0 + weighted * (1.0 / (3.0 * twice_area)))
+    }
+
+    /// 周长：在首尾闭合的折线上调用 `path::polyline_length`。
+    pub fn perimeter(&self) -> f64 {
+        let Some(&first) = self.vertices.first() else {
+            return 0.0;
+        };
+        let mut ring = self.vertices.clone();
+        ring.push(first);
+        polyline_length(&ring)
+    }
+
+    /// 是否为凸多边形（顺时针、逆时针都可以）。
+    ///
+    /// 所有非共线的拐角必须同向，而且外角之和必须恰好是一圈：
+    /// 后一个条件排除了五角星这类每个拐角都同向、但绕了两圈的自交多边形。
+    /// 重复顶点和共线顶点是允许的；面积为 0 的多边形不算凸。
+    pub fn is_convex(&self) -> bool {
+        // 先去掉相邻的重复顶点，否则重复点处的拐角会被算成 0
+        let mut v: Vec<Vector2> = Vec::with_capacity(self.vertices.len());
+        for &p in &self.vertices {
+            if v.last().is_none_or(|q| !same_point(q, &p)) {
+                v.push(p);
+            }
+        }
+        while v.len() > 1 && same_point(&v[0], &v[v.len() - 1]) {
+            v.pop();
+        }
+        let n = v.len();
+        if n < 3 || self.signed_area() == 0.0 {
+            return false;
+        }
+        let mut sign = 0.0;
+        let mut turning = 0.0;
+        for i in 0..n {
+            let (a, b, c) = (v[i], v[(i + 1) % n], v[(i + 2) % n]);
+            let turn = orient2d(&a, &b, &c);
+            if turn != 0.0 {
+                if sign * turn < 0.0 {
+                    return false;
+                }
+                sign = turn;
+            }
+            let (e1, e2) = (b - a, c - b);
+            turning += f64::atan2(turn, dot(&e1, &e2));
+        }
+        (turning.abs() - std::f64::consts::TAU).abs() < 1e-6
+    }
+
+    /// 多边形绕 p 的圈数（逆时针为正）。
+    ///
+    /// 用向右的水平射线数穿越次数（上行边 +1、下行边 -1），端点按半开区间归属，
+    /// 穿越判断用精确的 `orient2d`。p 恰好在边界上时结果取决于边的走向，
+    /// 需要确定答案时先用 `on_boundary` 判断。
+    pub fn winding_number(&self, p: &Vector2) -> i32 {
+        let mut winding = 0;
+        for edge in self.edges() {
+            let (a, b) = (edge.a(), edge.b());
+            if a.y() <= p.y() {
+                if b.y() > p.y() && orient2d(&a, &b, p) > 0.0 {
+                    winding += 1;
+                }
+            } else if b.y() <= p.y() && orient2d(&a, &b, p) < 0.0 {
+                winding -= 1;
+            }
+        }
+        winding
+    }
+
+    /// p 是否恰好落在某条边上（精确判断）。
+    pub fn on_boundary(&self, p: &Vector2) -> bool {
+        self.edges().any(|e| e.contains(p))
+    }
+
+    /// 奇偶规则：射线穿越次数为奇数即在内部；边界上的点算内部。
+    ///
+    /// 穿越次数与圈数的奇偶性相同，所以直接由 `winding_number` 判断。
+    pub fn contains_even_odd(&self, p: &Vector2) -> bool {
+        self.on_boundary(p) || self.winding_number(p) % 2 != 0
+    }
+
+    /// 非零规则：圈数不为 0 即在内部；边界上的点算内部。
+    ///
+    /// 对简单多边形与奇偶规则一致；自交多边形中被绕了两圈的区域只有非零规则认为在内部。
+    pub fn contains_nonzero(&self, p: &Vector2) -> bool {
+        self.on_boundary(p) || self.winding_number(p) != 0
+    }
+
+    /// Sutherland–Hodgman 裁剪：返回 self 落在凸多边形 clip 内的部分。
+    ///
+    /// clip 可以是顺时针或逆时针；不是凸多边形时返回 None。
+    /// self 可以是凹的，但此时结果可能含有沿裁剪边界来回的零宽“桥”（算法固有的性质），
+    /// 面积仍然正确。完全在外面时返回没有顶点的多边形。
+    pub fn clip(&self, clip: &Polygon) -> Option<Polygon> {
+        if !clip.is_convex() {
+            return None;
+        }
+        // 把裁剪多边形统一成逆时针，这样“内部”就是每条边的左侧
+        let clip = if clip.signed_area() < 0.0 {
+            clip.reversed()
+        } else {
+            clip.clone()
+        };
+        let mut output = self.vertices.clone();
+        for edge in clip.edges() {
+            let (a, b) = (edge.a(), edge.b());
+            if same_point(&a, &b) {
+                continue;
+            }
+            let input = std::mem::take(&mut output);
+            let n = input.len();
+            for i in 0..n {
+                let s = input[(i + n - 1) % n];
+                let e = input[i];
+                let (os, oe) = (orient2d(&a, &b, &s), orient2d(&a, &b, &e));
+                // 恰好落在裁剪边上的点算内部；只有严格跨过时才插入交点，避免重复顶点
+                if oe >= 0.0 {
+                    if os < 0.0 && oe > 0.0 {
+                        output.push(s + (e - s) * (os / (os - oe)));
+                    }
+                    output.push(e);
+                } else if os > 0.0 {
+                    output.push(s + (e - s) * (os / (os - oe)));
+                }
+            }
+        }
+        Some(Polygon::new(output))
+    }
+}
+
+impl From<Vec<Vector2>> for Polygon {
+    fn from(vertices: Vec<Vector2>) -> Self {
+        Self::new(vertices)
+    }
+}
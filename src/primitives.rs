@@ -0,0 +1,423 @@
+//! 二维几何图元（直线、射线、线段）以及鲁棒的方向判定。
+//!
+//! 所有“点在哪一侧”“是否共线”“是否平行”的判断都通过 `orient2d` / `cross_diff` 做，
+//! 它们给出的符号是精确的：先用普通浮点计算并估计舍入误差，只有结果落在误差范围内时
+//! 才用无误差的浮点展开（expansion）算法重算（Shewchuk 的自适应精度思路）。
+//! 因此退化输入（共线、端点重合、T 形相交）会被稳定地识别出来，而不是随舍入误差随机分类。
+//!
+//! 精确性的前提是计算过程中不发生上溢或下溢（坐标绝对值大致在 1e-140 到 1e140 之间）。
+
+use crate::vector::{Vector2, distance, dot};
+use std::cmp::Ordering;
+
+/// 三点的转向。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// a → b → c 逆时针（c 在有向直线 ab 的左侧）
+    CounterClockwise,
+    /// a → b → c 顺时针（c 在右侧）
+    Clockwise,
+    Collinear,
+}
+
+/// 2 × det[b - a, c - a]：正数表示 a, b, c 逆时针，负数表示顺时针，0 表示共线。
+///
+/// 返回值的符号是精确的；数值本身是近似值（相对误差在 1e-15 量级），可以用作面积的估计。
+pub fn orient2d(a: &Vector2, b: &Vector2, c: &Vector2) -> f64 {
+    cross_diff(a, b, a, c)
+}
+
+/// 三点的转向，基于精确的 `orient2d`。
+pub fn orientation(a: &Vector2, b: &Vector2, c: &Vector2) -> Orientation {
+    sign_to_orientation(orient2d(a, b, c))
+}
+
+/// 把带符号的面积（正为逆时针）转成 `Orientation`。
+pub(crate) fn sign_to_orientation(d: f64) -> Orientation {
+    if d > 0.0 {
+        Orientation::CounterClockwise
+    } else if d < 0.0 {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+/// (b - a) × (d - c) = (bx - ax)(dy - cy) - (by - ay)(dx - cx)，符号精确。
+///
+/// `orient2d(a, b, c)` 是 c = a 的特例；两条直线是否平行也用它判断。
+pub fn cross_diff(a: &Vector2, b: &Vector2, c: &Vector2, d: &Vector2) -> f64 {
+    let l = (b.x() - a.x()) * (d.y() - c.y());
+    let r = (b.y() - a.y()) * (d.x() - c.x());
+    let det = l - r;
+    // Shewchuk 的 ccwerrboundA：两次减法、一次乘法、一次减法累积的相对误差上界
+    const EPS: f64 = f64::EPSILON / 2.0;
+    const ERR_BOUND: f64 = (3.0 + 16.0 * EPS) * EPS;
+    if det.abs() > ERR_BOUND * (l.abs() + r.abs()) {
+        return det;
+    }
+    cross_diff_exact(a, b, c, d)
+}
+
+/// 把 (b - a) × (d - c) 完全展开成 8 个坐标乘积之和，每个乘积用 FMA 精确拆成两项，
+/// 再把 16 项精确地累加成一个不重叠的浮点展开；返回其最高位分量的符号和近似大小。
+fn cross_diff_exact(a: &Vector2, b: &Vector2, c: &Vector2, d: &Vector2) -> f64 {
+    let terms = [
+        (b.x(), d.y()),
+        (-b.x(), c.y()),
+        (-a.x(), d.y()),
+        (a.x(), c.y()),
+        (-b.y(), d.x()),
+        (b.y(), c.x()),
+        (a.y(), d.x()),
+        (-a.y(), c.x()),
+    ];
+    let mut expansion: Vec<f64> = Vec::with_capacity(16);
+    for (p, q) in terms {
+        let (hi, lo) = two_product(p, q);
+        grow_expansion(&mut expansion, lo);
+        grow_expansion(&mut expansion, hi);
+    }
+    // 分量按绝对值从小到大排列且互不重叠，从小到大求和得到的近似值与精确值同号
+    expansion.iter().sum()
+}
+
+/// a + b = s + e，s 是浮点和，e 是精确的舍入误差（Knuth 的 TwoSum）。
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bv = s - a;
+    let av = s - bv;
+    (s, (a - av) + (b - bv))
+}
+
+/// a · b = p + e，e 由 FMA 精确求出。
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+/// 把 b 精确加进展开 e（Shewchuk 的 Grow-Expansion），并去掉为 0 的分量。
+fn grow_expansion(e: &mut Vec<f64>, b: f64) {
+    let mut q = b;
+    let mut out = Vec::with_capacity(e.len() + 1);
+    for &component in e.iter() {
+        let (s, h) = two_sum(q, component);
+        if h != 0.0 {
+            out.push(h);
+        }
+        q = s;
+    }
+    if q != 0.0 {
+        out.push(q);
+    }
+    *e = out;
+}
+
+/// 点的字典序（先 x 后 y）。共线的点在字典序下的顺序就是它们在直线上的顺序。
+pub(crate) fn lex_cmp(a: &Vector2, b: &Vector2) -> Ordering {
+    a.x()
+        .partial_cmp(&b.x())
+        .unwrap_or(Ordering::Equal)
+        .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+}
+
+/// 两点坐标完全相同（精确比较）。
+pub(crate) fn same_point(a: &Vector2, b: &Vector2) -> bool {
+    a.x() == b.x() && a.y() == b.y()
+}
+
+/// 过两个不同点 p、q 的直线（有方向：从 p 指向 q）。
+///
+/// 直线由两个点而不是“点 + 方向”保存，这样所有侧向判断都作用在输入的原始坐标上，是精确的。
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line2 {
+    p: Vector2,
+    q: Vector2,
+}
+
+/// 两条直线的位置关系。
+#[derive(Debug, Clone, Copy)]
+pub enum LineIntersection {
+    Point(Vector2),
+    Parallel,
+    Coincident,
+}
+
+impl Line2 {
+    /// 过 p、q 两点的直线；p 与 q 重合时返回 None。
+    pub fn through(p: Vector2, q: Vector2) -> Option<Self> {
+        (!same_point(&p, &q)).then_some(Self { p, q })
+    }
+
+    /// 过 point、方向为 direction 的直线；direction 为零向量时返回 None。
+    pub fn new(point: Vector2, direction: Vector2) -> Option<Self> {
+        Self::through(point, point + direction)
+    }
+
+    pub fn point(&self) -> Vector2 {
+        self.p
+    }
+
+    pub fn direction(&self) -> Vector2 {
+        self.q - self.p
+    }
+
+    /// p 在有向直线的哪一侧（左侧为逆时针）；精确。
+    pub fn side(&self, p: &Vector2) -> Orientation {
+        orientation(&self.p, &self.q, p)
+    }
+
+    /// 有符号距离：左侧为正。
+    pub fn signed_distance(&self, p: &Vector2) -> f64 {
+        let d = self.direction();
+        orient2d(&self.p, &self.q, p) / d.length_sq().sqrt()
+    }
+
+    pub fn distance_to_point(&self, p: &Vector2) -> f64 {
+        self.signed_distance(p).abs()
+    }
+
+    /// p 在直线上的正交投影。
+    pub fn closest_point(&self, p: &Vector2) -> Vector2 {
+        let d = self.direction();
+        self.p + d * (dot(&(*p - self.p), &d) / d.length_sq())
+    }
+
+    /// 与另一条直线的交点；平行与重合的判定是精确的。
+    pub fn intersect(&self, other: &Line2) -> LineIntersection {
+        if cross_diff(&self.p, &self.q, &other.p, &other.q) == 0.0 {
+            return if self.side(&other.p) == Orientation::Collinear {
+                LineIntersection::Coincident
+            } else {
+                LineIntersection::Parallel
+            };
+        }
+        let o1 = orient2d(&self.p, &self.q, &other.p);
+        let o2 = orient2d(&self.p, &self.q, &other.q);
+        LineIntersection::Point(other.p + (other.q - other.p) * (o1 / (o1 - o2)))
+    }
+}
+
+/// 射线 origin + t · direction，t >= 0。
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray2 {
+    origin: Vector2,
+    through: Vector2,
+}
+
+impl Ray2 {
+    /// direction 为零向量时返回 None。
+    pub fn new(origin: Vector2, direction: Vector2) -> Option<Self> {
+        let through = origin + direction;
+        (!same_point(&origin, &through)).then_some(Self { origin, through })
+    }
+
+    pub fn origin(&self) -> Vector2 {
+        self.origin
+    }
+
+    pub fn direction(&self) -> Vector2 {
+        self.through - self.origin
+    }
+
+    pub fn point_at(&self, t: f64) -> Vector2 {
+        self.origin + self.direction() * t
+    }
+
+    /// 射线所在的直线。
+    pub fn line(&self) -> Line2 {
+        Line2 {
+            p: self.origin,
+            q: self.through,
+        }
+    }
+
+    /// 射线上离 p 最近的点（投影落在起点之后时取起点）。
+    pub fn closest_point(&self, p: &Vector2) -> Vector2 {
+        let d = self.direction();
+        let t = dot(&(*p - self.origin), &d) / d.length_sq();
+        self.point_at(t.max(0.0))
+    }
+
+    pub fn distance_to_point(&self, p: &Vector2) -> f64 {
+        distance(p, &self.closest_point(p))
+    }
+
+    /// 射线与线段的第一个交点（离起点最近的那个）；不相交时返回 None。
+    ///
+    /// 线段与射线共线且重叠时，返回重叠部分中离起点最近的点。
+    pub fn intersect_segment(&self, s: &Segment2) -> Option<Vector2> {
+        let line = self.line();
+        let (sa, sb) = (line.side(&s.a), line.side(&s.b));
+        if sa == Orientation::Collinear && sb == Orientation::Collinear {
+            // 共线：在射线方向上的参数 >= 0 的部分才算
+            let d = self.direction();
+            let ta = dot(&(s.a - self.origin), &d);
+            let tb = dot(&(s.b - self.origin), &d);
+            return match (ta >= 0.0, tb >= 0.0) {
+                (false, false) => None,
+                (true, true) => Some(if ta <= tb { s.a } else { s.b }),
+                _ => Some(self.origin),
+            };
+        }
+        if sa == sb {
+            return None;
+        }
+        // 线段跨过射线所在直线；再看射线是否朝线段所在直线前进。
+        // o = (b - a) × (origin - a)，沿射线前进时它按 (b - a) × direction 变化，两者异号才会碰到
+        let o = orient2d(&s.a, &s.b, &self.origin);
+        if o == 0.0 {
+            // 起点就在线段所在直线上，两条直线的交点只能是起点
+            return Some(self.origin);
+        }
+        let approach = cross_diff(&s.a, &s.b, &self.origin, &self.through);
+        if (o > 0.0) == (approach < 0.0) {
+            let hit = if sa == Orientation::Collinear {
+                s.a
+            } else if sb == Orientation::Collinear {
+                s.b
+            } else {
+                let oa = orient2d(&self.origin, &self.through, &s.a);
+                let ob = orient2d(&self.origin, &self.through, &s.b);
+                s.a + (s.b - s.a) * (oa / (oa - ob))
+            };
+            Some(hit)
+        } else {
+            None
+        }
+    }
+}
+
+/// 线段 [a, b]（允许 a = b 的退化线段）。
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment2 {
+    a: Vector2,
+    b: Vector2,
+}
+
+/// 两条线段的相交情况。
+#[derive(Debug, Clone, Copy)]
+pub enum SegmentIntersection {
+    None,
+    /// 交于一点（包括端点相接、T 形相交、共线但只接触一个端点）
+    Point(Vector2),
+    /// 共线且重叠的部分，端点取自输入线段的端点
+    Overlap(Segment2),
+}
+
+impl Segment2 {
+    pub fn new(a: Vector2, b: Vector2) -> Self {
+        Self { a, b }
+    }
+
+    pub fn a(&self) -> Vector2 {
+        self.a
+    }
+
+    pub fn b(&self) -> Vector2 {
+        self.b
+    }
+
+    pub fn length(&self) -> f64 {
+        distance(&self.a, &self.b)
+    }
+
+    pub fn midpoint(&self) -> Vector2 {
+        (self.a + self.b) * 0.5
+    }
+
+    /// 线段所在的直线；退化线段返回 None。
+    pub fn line(&self) -> Option<Line2> {
+        Line2::through(self.a, self.b)
+    }
+
+    /// 线段上离 p 最近的点：投影参数截断到 [0, 1]。
+    pub fn closest_point(&self, p: &Vector2) -> Vector2 {
+        let d = self.b - self.a;
+        let len_sq = d.length_sq();
+        if len_sq == 0.0 {
+            return self.a;
+        }
+        let t = (dot(&(*p - self.a), &d) / len_sq).clamp(0.0, 1.0);
+        self.a + d * t
+    }
+
+    pub fn distance_to_point(&self, p: &Vector2) -> f64 {
+        distance(p, &self.closest_point(p))
+    }
+
+    /// p 是否恰好在线段上（精确判断：共线且落在端点之间）。
+    pub fn contains(&self, p: &Vector2) -> bool {
+        if orient2d(&self.a, &self.b, p) != 0.0 {
+            return false;
+        }
+        let (lo, hi) = self.lex_endpoints();
+        lex_cmp(&lo, p) != Ordering::Greater && lex_cmp(p, &hi) != Ordering::Greater
+    }
+
+    pub fn intersects(&self, other: &Segment2) -> bool {
+        !matches!(self.intersect(other), SegmentIntersection::None)
+    }
+
+    /// 两条线段的交集。
+    ///
+    /// 四个转向都用精确谓词计算，所以共线重叠、端点相接都能可靠识别；
+    /// 一般位置的交点坐标由面积比插值得到（端点相接时直接返回该端点，没有舍入）。
+    pub fn intersect(&self, other: &Segment2) -> SegmentIntersection {
+        let (a, b, c, d) = (&self.a, &self.b, &other.a, &other.b);
+        let o1 = orient2d(a, b, c);
+        let o2 = orient2d(a, b, d);
+        let o3 = orient2d(c, d, a);
+        let o4 = orient2d(c, d, b);
+
+        if o1 == 0.0 && o2 == 0.0 && o3 == 0.0 && o4 == 0.0 {
+            // 四点共线（包括退化线段）：按字典序求两个区间的交
+            let (lo1, hi1) = self.lex_endpoints();
+            let (lo2, hi2) = other.lex_endpoints();
+            let lo = if lex_cmp(&lo1, &lo2) == Ordering::Less {
+                lo2
+            } else {
+                lo1
+            };
+            let hi = if lex_cmp(&hi1, &hi2) == Ordering::Less {
+                hi1
+            } else {
+                hi2
+            };
+            return match lex_cmp(&lo, &hi) {
+                Ordering::Greater => SegmentIntersection::None,
+                Ordering::Equal => SegmentIntersection::Point(lo),
+                Ordering::Less => SegmentIntersection::Overlap(Segment2::new(lo, hi)),
+            };
+        }
+        let straddles = |x: f64, y: f64| (x <= 0.0 && y >= 0.0) || (x >= 0.0 && y <= 0.0);
+        if !straddles(o1, o2) || !straddles(o3, o4) {
+            return SegmentIntersection::None;
+        }
+        let p = if o3 == 0.0 {
+            *a
+        } else if o4 == 0.0 {
+            *b
+        } else if o1 == 0.0 {
+            *c
+        } else if o2 == 0.0 {
+            *d
+        } else {
+            *a + (*b - *a) * (o3 / (o3 - o4))
+        };
+        SegmentIntersection::Point(p)
+    }
+
+    /// 按字典序排好的两个端点。
+    fn lex_endpoints(&self) -> (Vector2, Vector2) {
+        if lex_cmp(&self.a, &self.b) == Ordering::Greater {
+            (self.b, self.a)
+        } else {
+            (self.a, self.b)
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::blas::{Diag, Transpose, UpLo, gemv, nrm2, trsv};
+use crate::matrix_dense::{Matrix, MatrixError};
+
+/// 完整 QR 分解 A = Q R。
+///
+/// - q: m × m 正交矩阵
+/// - r: m × n 上三角（m > n 时下面 m - n 行全为 0）
+///
+/// 和 `gram_schmidt` 给出的“瘦” QR 不同，这里保留完整的 Q，
+/// 这样增删一行之后仍能用 O(m²) 的 Givens 旋转修正分解，而不必重新分解。
+#[derive(Debug, Clone)]
+pub struct Qr {
+    pub q: Matrix,
+    pub r: Matrix,
+}
+
+/// Householder QR：对每一列用一个 Householder 反射把对角线以下消成 0。
+///
+/// 数值上无条件稳定（Q 的正交性不依赖 A 的条件数），复杂度 O(m n²) + 累积 Q 的 O(m² n)。
+pub fn householder_qr(a: &Matrix) -> Result<Qr, MatrixError> {
+    let (m, n) = (a.rows(), a.cols());
+    let mut r = a.clone();
+    let mut q = Matrix::identity(m);
+
+    for j in 0..n.min(m.saturating_sub(1)) {
+        let mut v: Vec<f64> = (j..m).map(|i| r.get(i, j)).collect::<Result<_, _>>()?;
+        let alpha = nrm2(&v);
+        if alpha == 0.0 {
+            continue;
+        }
+        // v = x + sign(x₀)‖x‖e₁，避免相消
+        let beta = if v[0] >= 0.0 { -alpha } else { alpha };
+        v[0] -= beta;
+        let vv: f64 = v.iter().map(|x| x * x).sum();
+        if vv == 0.0 {
+            continue;
+        }
+
+        // R ← (I - 2vvᵀ/vᵀv) R，只影响第 j 行以下、第 j 列以右
+        let rd = r.as_mut_slice();
+        for c in j..n {
+            let dot: f64 = v
+                .iter()
+                .enumerate()
+                .map(|(k, vk)| vk * rd[(j + k) * n + c])
+                .sum();
+            let f = 2.0 * dot / vv;
+            for (k, vk) in v.iter().enumerate() {
+                rd[(j + k) * n + c] -= f * vk;
+            }
+        }
+        // Q ← Q (I - 2vvᵀ/vᵀv)
+        for q_row in q.as_mut_slice().chunks_exact_mut(m) {
+            let tail = &mut q_row[j..];
+            let dot: f64 = v.iter().zip(tail.iter()).map(|(vk, qk)| vk * qk).sum();
+            let f = 2.0 * dot / vv;
+            for (qk, vk) in tail.iter_mut().zip(&v) {
+                *qk -= f * vk;
+            }
+        }
+        r.set(j, j, beta);
+        for i in (j + 1)..m {
+            r.set(i, j, 0.0);
+        }
+    }
+    Ok(Qr { q, r })
+}
+
+impl Qr {
+    /// 在 A 的第 k 行之前插入一行（k = m 表示追加到末尾），原地更新为新矩阵的 QR 分解。
+    ///
+    /// [w; A] = diag(1, Q) [w; R]，[w; R] 是上 Hessenberg 矩阵，
+    /// 再用 min(m, n) 个 Givens 旋转消去次对角线即可，O(m² + m n)。
+    pub fn insert_row(&mut self, k: usize, row: &[f64]) -> Result<(), MatrixError> {
+        let (m, n) = (self.r.rows(), self.r.cols());
+        if k > m || row.len() != n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        // 新的 Q₁：第 k 行是 e₀ᵀ，其余行是原来 Q 的行右移一列
+        let mut q = Matrix::zeros(m + 1, m + 1);
+        q.set(k, 0, 1.0);
+        for p in 0..=m {
+            if p == k {
+                continue;
+            }
+            let old = if p < k { p } else { p - 1 };
+            for j in 0..m {
+                q.set(p, j + 1, self.q.get(old, j)?);
+            }
+        }
+        let mut r = Matrix::zeros(m + 1, n);
+        for (j, v) in row.iter().enumerate() {
+            r.set(0, j, *v);
+        }
+        for i in 0..m {
+            for j in 0..n {
+                r.set(i + 1, j, self.r.get(i, j)?);
+            }
+        }
+
+        for j in 0..n.min(m) {
+            let (c, s, _) = givens(r.get(j, j)?, r.get(j + 1, j)?);
+            rotate_rows(r.as_mut_slice(), n, j, j + 1, c, s);
+            rotate_cols(q.as_mut_slice(), m + 1, j, j + 1, c, s);
+            r.set(j + 1, j, 0.0);
+        }
+        self.q = q;
+        self.r = r;
+        Ok(())
+    }
+
+    /// 删除 A 的第 k 行，原地更新为新矩阵的 QR 分解。
+    ///
+    /// 用 Givens 旋转把 Q 的第 k 行化为 ±e₀ᵀ（同时 R 变成上 Hessenberg），
+    /// 此时 R 的第一行恰好对应被删除的行，去掉它和 Q 的第 k 行、第 0 列即可，O(m² + m n)。
+    pub fn delete_row(&mut self, k: usize) -> Result<(), MatrixError> {
+        let (m, n) = (self.r.rows(), self.r.cols());
+        if k >= m {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        for i in (1..m).rev() {
+            let (c, s, _) = givens(self.q.get(k, i - 1)?, self.q.get(k, i)?);
+            rotate_rows(self.r.as_mut_slice(), n, i - 1, i, c, s);
+            rotate_cols(self.q.as_mut_slice(), m, i - 1, i, c, s);
+        }
+
+        let mut q = Matrix::zeros(m - 1, m - 1);
+        for (p, old) in (0..m).filter(|&i| i != k).enumerate() {
+            for j in 1..m {
+                q.set(p, j - 1, self.q.get(old, j)?);
+            }
+        }
+        // 旋转后 R 是上 Hessenberg，去掉第一行后正好是上三角
+        let r = Matrix::new(m - 1, n, self.r.as_slice()[n..].to_vec())?;
+        self.q = q;
+        self.r = r;
+        Ok(())
+    }
+
+    /// 最小二乘解 min ‖Ax - b‖₂：x = R₁⁻¹ (Qᵀb)[..n]，要求 m >= n 且 A 列满秩。
+    pub fn solve_least_squares(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let (m, n) = (self.r.rows(), self.r.cols());
+        if m < n || b.len() != m {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let mut qtb = vec![0.0; m];
+        gemv(Transpose::Yes, 1.0, &self.q, b, 0.0, &mut qtb)?;
+
+        let mut r1 = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in i..n {
+                r1.set(i, j, self.r.get(i, j)?);
+            }
+        }
+        let diag: Vec<f64> = (0..n).map(|i| r1.get(i, i)).collect::<Result<_, _>>()?;
+        let scale = diag.iter().fold(0.0_f64, |s, d| s.max(d.abs()));
+        if diag.iter().any(|d| d.abs() <= 1e-12 * scale) {
+            return Err(MatrixError::Singular);
+        }
+        let mut x = qtb[..n].to_vec();
+        trsv(UpLo::Upper, Transpose::No, Diag::NonUnit, &r1, &mut x)?;
+        Ok(x)
+    }
+}
+
+/// Givens 旋转：返回 (c, s, r)，使 c·f + s·g = r 且 -s·f + c·g = 0。
+///
+/// crate 内共用（QR 更新、QZ 迭代），配合 `rotate_rows` / `rotate_cols` 使用。
+pub(crate) fn givens(f: f64, g: f64) -> (f64, f64, f64) {
+    if g == 0.0 {
+        return (1.0, 0.0, f);
+    }
+    let r = f.hypot(g);
+    (f / r, g / r, r)
+}
+
+/// 对按行优先存放、每行 `cols` 个元素的矩阵 data 做行旋转：
+/// row_i ← c·row_i + s·row_k，row_k ← -s·row_i + c·row_k
+pub(crate) fn rotate_rows(data: &mut [f64], cols: usize, i: usize, k: usize, c: f64, s: f64) {
+    for j in 0..cols {
+        let (a, b) = (data[i * cols + j], data[k * cols + j]);
+        data[i * cols + j] = c * a + s * b;
+        data[k * cols + j] = -s * a + c * b;
+    }
+}
+
+/// 列旋转：col_i ← c·col_i + s·col_k，col_k ← -s·col_i + c·col_k
+/// （对 Q 右乘 Gᵀ，与 `rotate_rows` 配对）
+pub(crate) fn rotate_cols(data: &mut [f64], cols: usize, i: usize, k: usize, c: f64, s: f64) {
+    for row in data.chunks_exact_mut(cols) {
+        let (a, b) = (row[i], row[k]);
+        row[i] = c * a + s * b;
+        row[k] = -s * a + c * b;
+    }
+}
@@ -0,0 +1,291 @@
+use crate::matrix_dense::{Matrix, MatrixError};
+use crate::vector3::{Vector3, cross};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// 四元数 q = w + x i + y j + z k。
+///
+/// 单位四元数表示三维旋转：绕单位轴 n 转 θ 对应 (cos θ/2, sin θ/2 · n)。
+/// q 和 -q 表示同一个旋转。
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion {
+    w: f64,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+/// 欧拉角的旋转顺序（内旋）：`Xyz` 表示先绕 X 轴，再绕旋转后的 Y 轴，最后绕旋转后的 Z 轴，
+/// 等价于旋转矩阵 R = Rx(a) Ry(b) Rz(c)。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerOrder {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+}
+
+impl Quaternion {
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// 恒等旋转 1 + 0i + 0j + 0k。
+    pub fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    pub fn w(&self) -> f64 {
+        self.w
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    /// 向量部分 (x, y, z)。
+    pub fn vector_part(&self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// 绕 axis 转 angle 弧度（右手定则）；axis 不必是单位向量，太接近零向量时返回 None。
+    pub fn from_axis_angle(axis: &Vector3, angle: f64) -> Option<Self> {
+        let n = axis.normalize()?;
+        let (s, c) = (angle / 2.0).sin_cos();
+        Some(Self::new(c, s * n.x(), s * n.y(), s * n.z()))
+    }
+
+    /// 由欧拉角构造：angles 依次对应 order 中的三个轴。
+    pub fn from_euler(angles: [f64; 3], order: EulerOrder) -> Self {
+        let axes = match order {
+            EulerOrder::Xyz => [0, 1, 2],
+            EulerOrder::Xzy => [0, 2, 1],
+            EulerOrder::Yxz => [1, 0, 2],
+            EulerOrder::Yzx => [1, 2, 0],
+            EulerOrder::Zxy => [2, 0, 1],
+            EulerOrder::Zyx => [2, 1, 0],
+        };
+        axes.iter()
+            .zip(angles)
+            .map(|(&axis, angle)| {
+                let (s, c) = (angle / 2.0).sin_cos();
+                let mut v = [0.0; 3];
+                v[axis] = s;
+                Self::new(c, v[0], v[1], v[2])
+            })
+            .fold(Self::identity(), |acc, q| acc * q)
+    }
+
+    /// 旋转轴和角度，角度在 [0, π]；恒等旋转的轴取 x 轴。
+    pub fn to_axis_angle(&self) -> Option<(Vector3, f64)> {
+        let q = self.normalize()?;
+        // 取 w >= 0 的代表元，使角度落在 [0, π]
+        let q = if q.w < 0.0 { -q } else { q };
+        let v = q.vector_part();
+        let s = v.length_sq().sqrt();
+        // atan2 在小角度和接近 π 时都比 acos(w) 准确
+        let angle = 2.0 * f64::atan2(s, q.w);
+        if s == 0.0 {
+            return Some((Vector3::new(1.0, 0.0, 0.0), 0.0));
+        }
+        Some((v * (1.0 / s), angle))
+    }
+
+    pub fn norm_sq(&self) -> f64 {
+        dot(self, self)
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.norm_sq().sqrt()
+    }
+
+    /// 共轭 w - x i - y j - z k；对单位四元数就是逆旋转。
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// 归一化为单位四元数；约定同 `Vector3::normalize`，太接近 0 时返回 None。
+    pub fn normalize(&self) -> Option<Self> {
+        let n2 = self.norm_sq();
+        if n2 < 1e-10 {
+            return None;
+        }
+        Some(*self * (1.0 / n2.sqrt()))
+    }
+
+    /// 乘法逆 q⁻¹ = q* / ‖q‖²；q 为 0 时返回 None。
+    pub fn inverse(&self) -> Option<Self> {
+        let n2 = self.norm_sq();
+        if n2 == 0.0 {
+            return None;
+        }
+        Some(self.conjugate() * (1.0 / n2))
+    }
+
+    /// 用这个四元数表示的旋转作用在 v 上：q v q*。
+    ///
+    /// 要求 q 是单位四元数。实现用的是展开后的形式
+    /// v' = v + 2w (u × v) + 2 u × (u × v)（u 为向量部分），比两次 Hamilton 乘积少一半乘法。
+    pub fn rotate(&self, v: &Vector3) -> Vector3 {
+        let u = self.vector_part();
+        let t = cross(&u, v) * 2.0;
+        *v + t * self.w + cross(&u, &t)
+    }
+
+    /// 对应的 3 × 3 旋转矩阵（q 应为单位四元数）。
+    pub fn to_rotation_matrix(&self) -> Matrix {
+        let Self { w, x, y, z } = *self;
+        let data = vec![
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y - w * z),
+            2.0 * (x * z + w * y),
+            2.0 * (x * y + w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z - w * x),
+            2.0 * (x * z - w * y),
+            2.0 * (y * z + w * x),
+            1.0 - 2.0 * (x * x + y * y),
+        ];
+        Matrix::new(3, 3, data).expect("3 × 3 data")
+    }
+
+    /// 从 3 × 3 旋转矩阵恢复单位四元数（Shepperd 方法）。
+    ///
+    /// 先看迹和三个对角元哪个最大，再从最大的那个分量开分解，
+    /// 避免在旋转角接近 π 时对一个接近 0 的数开方再去除。
+    /// 不检查 r 是否真的正交；结果总会被归一化。
+    pub fn from_rotation_matrix(r: &Matrix) -> Result<Self, MatrixError> {
+        if r.rows() != 3 || r.cols() != 3 {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let m = |i: usize, j: usize| r.as_slice()[i * 3 + j];
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+        let q = if trace >= m(0, 0).max(m(1, 1)).max(m(2, 2)) {
+            let s = 2.0 * (1.0 + trace).sqrt();
+            Self::new(
+                s / 4.0,
+                (m(2, 1) - m(1, 2)) / s,
+                (m(0, 2) - m(2, 0)) / s,
+                (m(1, 0) - m(0, 1)) / s,
+            )
+        } else if m(0, 0) >= m(1, 1) && m(0, 0) >= m(2, 2) {
+            let s = 2.0 * (1.0 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt();
+            Self::new(
+                (m(2, 1) - m(1, 2)) / s,
+                s / 4.0,
+                (m(0, 1) + m(1, 0)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+            )
+        } else if m(1, 1) >= m(2, 2) {
+            let s = 2.0 * (1.0 + m(1, 1) - m(0, 0) - m(2, 2)).sqrt();
+            Self::new(
+                (m(0, 2) - m(2, 0)) / s,
+                (m(0, 1) + m(1, 0)) / s,
+                s / 4.0,
+                (m(1, 2) + m(2, 1)) / s,
+            )
+        } else {
+            let s = 2.0 * (1.0 + m(2, 2) - m(0, 0) - m(1, 1)).sqrt();
+            Self::new(
+                (m(1, 0) - m(0, 1)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+                (m(1, 2) + m(2, 1)) / s,
+                s / 4.0,
+            )
+        };
+        q.normalize().ok_or(MatrixError::Singular)
+    }
+}
+
+/// Hamilton 乘积：(p q) 表示先做 q 再做 p 的旋转。
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, q: Self) -> Self::Output {
+        let p = self;
+        Self::new(
+            p.w * q.w - p.x * q.x - p.y * q.y - p.z * q.z,
+            p.w * q.x + p.x * q.w + p.y * q.z - p.z * q.y,
+            p.w * q.y - p.x * q.z + p.y * q.w + p.z * q.x,
+            p.w * q.z + p.x * q.y - p.y * q.x + p.z * q.w,
+        )
+    }
+}
+
+impl Mul<f64> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, s: f64) -> Self::Output {
+        Self::new(self.w * s, self.x * s, self.y * s, self.z * s)
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, q: Self) -> Self::Output {
+        Self::new(self.w + q.w, self.x + q.x, self.y + q.y, self.z + q.z)
+    }
+}
+
+impl Sub for Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, q: Self) -> Self::Output {
+        Self::new(self.w - q.w, self.x - q.x, self.y - q.y, self.z - q.z)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+/// 四维点积 p · q。对单位四元数，|p · q| = cos(两旋转夹角 / 2)。
+pub fn dot(p: &Quaternion, q: &Quaternion) -> f64 {
+    p.w * q.w + p.x * q.x + p.y * q.y + p.z * q.z
+}
+
+/// ±q 中与 p 在同一半球的那个（两者是同一个旋转），这样插值走的是最短路径。
+fn same_hemisphere(p: &Quaternion, q: &Quaternion) -> Quaternion {
+    if dot(p, q) < 0.0 { -*q } else { *q }
+}
+
+/// 归一化线性插值：先线性插值再归一化。
+///
+/// 比 `slerp` 便宜，路径相同但角速度不恒定；a、b 应为单位四元数。
+pub fn nlerp(a: &Quaternion, b: &Quaternion, t: f64) -> Quaternion {
+    let b = same_hemisphere(a, b);
+    (*a * (1.0 - t) + b * t)
+        .normalize()
+        .unwrap_or(Quaternion::identity())
+}
+
+/// 球面线性插值：沿最短大圆弧以恒定角速度从 a（t = 0）转到 b（t = 1）。
+///
+/// a、b 应为单位四元数。两者几乎重合时 sin θ → 0，改用 `nlerp`（此时两者的差别小于舍入误差）。
+pub fn slerp(a: &Quaternion, b: &Quaternion, t: f64) -> Quaternion {
+    let b = same_hemisphere(a, b);
+    let cos_theta = dot(a, &b).min(1.0);
+    if cos_theta > 1.0 - 1e-10 {
+        return nlerp(a, &b, t);
+    }
+    let theta = cos_theta.acos();
+    let sin_theta = theta.sin();
+    let wa = ((1.0 - t) * theta).sin() / sin_theta;
+    let wb = (t * theta).sin() / sin_theta;
+    *a * wa + b * wb
+}
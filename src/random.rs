@@ -0,0 +1,56 @@
+/// 一个很小的确定性伪随机数生成器（SplitMix64）。
+///
+/// - 同一个种子永远产生同一串数，方便让随机算法（随机 SVD、随机打乱等）的结果可复现；
+/// - 状态只有一个 u64，周期 2⁶⁴，统计质量对数值算法里的“随机测试向量”足够；
+/// - 不是密码学安全的随机数，不要用于安全相关的场景。
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+    /// Box–Muller 一次产生两个正态分布样本，多出来的那个缓存在这里
+    spare_gaussian: Option<f64>,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            spare_gaussian: None,
+        }
+    }
+
+    /// 下一个均匀分布的 u64。
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// 下一个 [0, 1) 上均匀分布的 f64（取高 53 位，保证每个值都能精确表示）。
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// 下一个标准正态分布 N(0, 1) 的样本（Box–Muller 变换）。
+    pub fn next_gaussian(&mut self) -> f64 {
+        if let Some(z) = self.spare_gaussian.take() {
+            return z;
+        }
+        // u1 取 (0, 1]，避免 ln(0)
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        let r = (-2.0 * u1.ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * u2;
+        self.spare_gaussian = Some(r * theta.sin());
+        r * theta.cos()
+    }
+
+    /// 原地随机打乱（Fisher–Yates），每种排列等概率（忽略取模带来的 2⁻⁶⁴ 量级偏差）。
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = (self.next_u64() % (i as u64 + 1)) as usize;
+            items.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::conditioning::{MatrixNorm, norm_inf};
+use crate::matrix_dense::{Matrix, MatrixError};
+use crate::matrix_dense_access::safe_get_row;
+use crate::numeric::compensated_dot;
+use crate::triangular::{LowerTriangular, UpperTriangular};
+
+/// 迭代精化的控制参数。
+#[derive(Debug, Clone, Copy)]
+pub struct RefinementOptions {
+    /// 最多做多少次“求残差 → 解修正量 → 更新 x”
+    pub max_iter: usize,
+    /// 相对后向误差降到这个值以下就停止
+    pub tol: f64,
+}
+
+impl Default for RefinementOptions {
+    fn default() -> Self {
+        Self {
+            max_iter: 10,
+            tol: f64::EPSILON,
+        }
+    }
+}
+
+/// 迭代精化的结果。
+#[derive(Debug, Clone)]
+pub struct RefinedSolution {
+    /// 精化后的解
+    pub x: Vec<f64>,
+    /// 实际做了几次修正
+    pub iterations: usize,
+    /// 最终的相对（按范数）后向误差：‖b - Ax‖∞ / (‖A‖∞ ‖x‖∞ + ‖b‖∞)
+    pub backward_error: f64,
+    /// 是否达到了 `tol`
+    pub converged: bool,
+}
+
+impl Matrix {
+    /// 计算残差 r = b - A x。
+    ///
+    /// 每一行都用补偿点积把 b_i 和 -A_i·x 一起累加，
+    /// 这样在 x 已经很准确、r 很小的时候也不会被抵消误差淹没。
+    pub fn residual(&self, x: &[f64], b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if self.cols() != x.len() || self.rows() != b.len() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut neg_x: Vec<f64> = x.iter().map(|xi| -xi).collect();
+        neg_x.push(1.0);
+
+        let mut r = Vec::with_capacity(self.rows());
+        for (i, bi) in b.iter().enumerate() {
+            let mut row = safe_get_row(self, i)?;
+            row.push(*bi);
+            r.push(compensated_dot(&row, &neg_x));
+        }
+        Ok(r)
+    }
+
+    /// 在 `solve_lu` 的基础上做迭代精化。
+    ///
+    /// 步骤：
+    /// - 先用 L、U 解出初始 x；
+    /// - 反复计算 r = b - Ax（补偿求和），再用同一组 L、U 解 A d = r，令 x += d；
+    /// - 后向误差 <= `tol`、或者后向误差不再至少减半（继续迭代已无收益）、
+    ///   或者达到 `max_iter` 时停止。
+    ///
+    /// `l`、`u` 必须是 `self.lu_decomposition()` 的结果。
+    pub fn solve_refined(
+        &self,
+        l: &LowerTriangular,
+        u: &UpperTriangular,
+        b: &[f64],
+        options: &RefinementOptions,
+    ) -> Result<RefinedSolution, MatrixError> {
+        let x = Matrix::solve_lu(l, u, b)?;
+        refine(self, b, x, options, |r| Matrix::solve_lu(l, u, r))
+    }
+
+    /// 混合精度求解：用 f32 做 LU 分解和三角回代，再用 f64 残差精化到 f64 精度。
+    ///
+    /// 分解是 O(n³) 的主要开销，在单精度下做更快、更省内存；
+    /// 只要 A 的条件数远小于 1 / f32::EPSILON（约 10⁷），精化后就能得到和 f64 求解同样准确的解。
+    ///
+    /// 由于 `Matrix` 只存 f64，这里用一份内部的 f32 拷贝（带部分选主元）完成分解。
+    pub fn solve_mixed_precision(
+        &self,
+        b: &[f64],
+        options: &RefinementOptions,
+    ) -> Result<RefinedSolution, MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare);
+        }
+        if self.rows() != b.len() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let lu = LuF32::factor(self)?;
+        let x = lu.solve(b);
+        refine(self, b, x, options, |r| Ok(lu.solve(r)))
+    }
+}
+
+fn refine<F>(
+    a: &Matrix,
+    b: &[f64],
+    mut x: Vec<f64>,
+    options: &RefinementOptions,
+    solve: F,
+) -> Result<RefinedSolution, MatrixError>
+where
+    F: Fn(&[f64]) -> Result<Vec<f64>, MatrixError>,
+{
+    let a_norm = a.norm(MatrixNorm::Inf);
+    let b_norm = norm_inf(b);
+
+    let mut r = a.residual(&x, b)?;
+    let mut berr = backward_error(&r, a_norm, &x, b_norm);
+    let mut iterations = 0;
+
+    while berr > options.tol && iterations < options.max_iter {
+        let d = solve(&r)?;
+        let candidate: Vec<f64> = x.iter().zip(&d).map(|(xi, di)| xi + di).collect();
+        let r_new = a.residual(&candidate, b)?;
+        let berr_new = backward_error(&r_new, a_norm, &candidate, b_norm);
+        iterations += 1;
+
+        if berr_new > 0.5 * berr {
+            // 不再有明显改进；如果新解至少不更差，就保留它
+            if berr_new < berr {
+                x = candidate;
+                berr = berr_new;
+            }
+            break;
+        }
+        x = candidate;
+        r = r_new;
+        berr = berr_new;
+    }
+
+    Ok(RefinedSolution {
+        x,
+        iterations,
+        backward_error: berr,
+        converged: berr <= options.tol,
+    })
+}
+
+fn backward_error(r: &[f64], a_norm: f64, x: &[f64], b_norm: f64) -> f64 {
+    let denom = a_norm * norm_inf(x) + b_norm;
+    if denom == 0.0 {
+        return 0.0;
+    }
+    norm_inf(r) / denom
+}
+
+/// 单精度 LU 分解（PA = LU，L 为单位下三角），L 和 U 紧凑地存在同一个数组里。
+struct LuF32 {
+    n: usize,
+    lu: Vec<f32>,
+    perm: Vec<usize>,
+}
+
+impl LuF32 {
+    fn factor(a: &Matrix) -> Result<Self, MatrixError> {
+        let n = a.rows();
+        let mut lu = Vec::with_capacity(n * n);
+        for i in 0..n {
+            lu.extend(safe_get_row(a, i)?.iter().map(|&v| v as f32));
+        }
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            let p = (k..n)
+                .max_by(|&i, &j| lu[i * n + k].abs().total_cmp(&lu[j * n + k].abs()))
+                .unwrap_or(k);
+            if lu[p * n + k].abs() < 1e-12 {
+                return Err(MatrixError::Singular);
+            }
+            if p != k {
+                for c in 0..n {
+                    lu.swap(k * n + c, p * n + c);
+                }
+                perm.swap(k, p);
+            }
+            let pivot = lu[k * n + k];
+            for i in (k + 1)..n {
+                let factor = lu[i * n + k] / pivot;
+                lu[i * n + k] = factor;
+                for c in (k + 1)..n {
+                    lu[i * n + c] -= factor * lu[k * n + c];
+                }
+            }
+        }
+        Ok(Self { n, lu, perm })
+    }
+
+    fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.n;
+        let mut y: Vec<f32> = self.perm.iter().map(|&p| b[p] as f32).collect();
+        for i in 0..n {
+            let sum: f32 = (0..i).map(|j| self.lu[i * n + j] * y[j]).sum();
+            y[i] -= sum;
+        }
+        for i in (0..n).rev() {
+            let sum: f32 = ((i + 1)..n).map(|j| self.lu[i * n + j] * y[j]).sum();
+            y[i] = (y[i] - sum) / self.lu[i * n + i];
+        }
+        y.into_iter().map(f64::from).collect()
+    }
+}
@@ -72,7 +72,7 @@ where
             return Err(RootError::NoConvergence);
         }
 
-        x = x - fx / dfx;
+        x -= fx / dfx;
     }
 
     Err(RootError::NoConvergence)
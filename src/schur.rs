@@ -0,0 +1,319 @@
+use crate::matrix_dense::{Matrix, MatrixError};
+
+/// 每个特征值（或共轭特征值对）允许的平均 QR 迭代次数上限。
+const MAX_ITER_PER_EIGENVALUE: usize = 30;
+
+/// 实 Schur 分解：A = Q T Qᵀ。
+///
+/// - Q: n × n 正交矩阵
+/// - T: n × n 拟上三角矩阵（quasi-upper-triangular）：
+///   对角线上是 1×1 块（实特征值）或 2×2 块（一对共轭复特征值），
+///   实特征值对应的 2×2 块已经被进一步分裂成上三角。
+///
+/// 算法：
+/// 1. 用 Householder 变换把 A 化为上 Hessenberg 形；
+/// 2. 在 Hessenberg 矩阵上做 Francis 隐式双位移 QR 迭代，次对角元足够小时就地收缩（deflation）。
+///
+/// 所有正交变换都累积进 Q。不收敛时返回 `MatrixError::NoConvergence`。
+pub fn real_schur(a: &Matrix) -> Result<(Matrix, Matrix), MatrixError> {
+    if a.rows() != a.cols() {
+        return Err(MatrixError::NotSquare);
+    }
+    let n = a.rows();
+    let mut h = a.as_slice().to_vec();
+    let mut q = Matrix::identity(n).as_slice().to_vec();
+
+    hessenberg_in_place(&mut h, &mut q, n);
+    francis_qr_in_place(&mut h, &mut q, n)?;
+
+    Ok((Matrix::new(n, n, q)?, Matrix::new(n, n, h)?))
+}
+
+/// 只做 Hessenberg 约化：A = Q H Qᵀ，H 的第一条次对角线以下全为 0。
+pub fn hessenberg(a: &Matrix) -> Result<(Matrix, Matrix), MatrixError> {
+    if a.rows() != a.cols() {
+        return Err(MatrixError::NotSquare);
+    }
+    let n = a.rows();
+    let mut h = a.as_slice().to_vec();
+    let mut q = Matrix::identity(n).as_slice().to_vec();
+    hessenberg_in_place(&mut h, &mut q, n);
+    Ok((Matrix::new(n, n, q)?, Matrix::new(n, n, h)?))
+}
+
+/// 读出拟上三角矩阵 T 的全部特征值，按对角块顺序返回 (实部, 虚部)。
+pub fn schur_eigenvalues(t: &Matrix) -> Result<Vec<(f64, f64)>, MatrixError> {
+    let mut values = Vec::with_capacity(t.rows());
+    for (start, size) in diagonal_blocks(t)? {
+        if size == 1 {
+            values.push((t.get(start, start)?, 0.0));
+        } else {
+            let a = t.get(start, start)?;
+            let b = t.get(start, start + 1)?;
+            let c = t.get(start + 1, start)?;
+            let d = t.get(start + 1, start + 1)?;
+            let mean = 0.5 * (a + d);
+            let p = 0.5 * (a - d);
+            let disc = p * p + b * c;
+            if disc >= 0.0 {
+                let s = disc.sqrt();
+                values.push((mean + s, 0.0));
+                values.push((mean - s, 0.0));
+            } else {
+                let s = (-disc).sqrt();
+                values.push((mean, s));
+                values.push((mean, -s));
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// 拟上三角矩阵的对角块划分：返回每个块的 (起始下标, 大小)，大小为 1 或 2。
+pub fn diagonal_blocks(t: &Matrix) -> Result<Vec<(usize, usize)>, MatrixError> {
+    let n = t.rows();
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < n {
+        if i + 1 < n && t.get(i + 1, i)? != 0.0 {
+            blocks.push((i, 2));
+            i += 2;
+        } else {
+            blocks.push((i, 1));
+            i += 1;
+        }
+    }
+    Ok(blocks)
+}
+
+/// 计算 Householder 向量 v 和系数 beta，使得 (I - beta v vᵀ) x = (±‖x‖, 0, …, 0)。
+/// x 为零向量时 beta = 0（即单位变换）。
+fn householder(x: &[f64]) -> (Vec<f64>, f64) {
+    let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+    let mut v = x.to_vec();
+    if norm == 0.0 {
+        return (v, 0.0);
+    }
+    let alpha = if x[0] >= 0.0 { -norm } else { norm };
+    v[0] -= alpha;
+    let vtv: f64 = v.iter().map(|x| x * x).sum();
+    if vtv == 0.0 {
+        return (v, 0.0);
+    }
+    (v, 2.0 / vtv)
+}
+
+/// 左乘：对第 row0.. row0+len 行、第 cols 列范围施加 I - beta v vᵀ。
+fn apply_left(
+    h: &mut [f64],
+    n: usize,
+    row0: usize,
+    v: &[f64],
+    beta: f64,
+    cols: std::ops::Range<usize>,
+) {
+    for j in cols {
+        let s: f64 = v
+            .iter()
+            .enumerate()
+            .map(|(i, vi)| vi * h[(row0 + i) * n + j])
+            .sum();
+        for (i, vi) in v.iter().enumerate() {
+            h[(row0 + i) * n + j] -= beta * vi * s;
+        }
+    }
+}
+
+/// 右乘：对第 rows 行范围、第 col0.. col0+len 列施加 I - beta v vᵀ。
+fn apply_right(
+    h: &mut [f64],
+    n: usize,
+    col0: usize,
+    v: &[f64],
+    beta: f64,
+    rows: std::ops::Range<usize>,
+) {
+    for i in rows {
+        let s: f64 = v
+            .iter()
+            .enumerate()
+            .map(|(j, vj)| vj * h[i * n + col0 + j])
+            .sum();
+        for (j, vj) in v.iter().enumerate() {
+            h[i * n + col0 + j] -= beta * s * vj;
+        }
+    }
+}
+
+fn hessenberg_in_place(h: &mut [f64], q: &mut [f64], n: usize) {
+    if n < 3 {
+        return;
+    }
+    for k in 0..(n - 2) {
+        let x: Vec<f64> = ((k + 1)..n).map(|i| h[i * n + k]).collect();
+        let (v, beta) = householder(&x);
+        if beta == 0.0 {
+            continue;
+        }
+        apply_left(h, n, k + 1, &v, beta, k..n);
+        apply_right(h, n, k + 1, &v, beta, 0..n);
+        apply_right(q, n, k + 1, &v, beta, 0..n);
+        for i in (k + 2)..n {
+            h[i * n + k] = 0.0;
+        }
+    }
+}
+
+fn francis_qr_in_place(h: &mut [f64], q: &mut [f64], n: usize) -> Result<(), MatrixError> {
+    if n == 0 {
+        return Ok(());
+    }
+    let matrix_norm = h
+        .iter()
+        .map(|x| x.abs())
+        .sum::<f64>()
+        .max(f64::MIN_POSITIVE);
+    let mut hi = n - 1;
+    let mut iter = 0;
+    let mut total_iter = 0;
+
+    while hi > 0 {
+        // 从下往上找可以忽略的次对角元，确定当前未约化的窗口 [lo, hi]
+        let mut lo = hi;
+        while lo > 0 {
+            let sub = h[lo * n + lo - 1].abs();
+            let mut scale = h[(lo - 1) * n + lo - 1].abs() + h[lo * n + lo].abs();
+            if scale == 0.0 {
+                scale = matrix_norm;
+            }
+            if sub <= f64::EPSILON * scale {
+                h[lo * n + lo - 1] = 0.0;
+                break;
+            }
+            lo -= 1;
+        }
+
+        if lo == hi {
+            hi -= 1;
+            iter = 0;
+            continue;
+        }
+        if lo + 1 == hi {
+            split_2x2_block(h, q, n, hi - 1);
+            if hi < 2 {
+                break;
+            }
+            hi -= 2;
+            iter = 0;
+            continue;
+        }
+
+        iter += 1;
+        total_iter += 1;
+        if total_iter > MAX_ITER_PER_EIGENVALUE * n {
+            return Err(MatrixError::NoConvergence);
+        }
+
+        // 双位移：取右下角 2×2 块的两个特征值（以迹 s 和行列式 t 的形式）
+        let (s, t) = if iter % 10 == 0 {
+            // 长时间不收敛时用“例外位移”打破对称的停滞
+            let ex = h[hi * n + hi - 1].abs() + h[(hi - 1) * n + hi - 2].abs();
+            (1.5 * ex, ex * ex)
+        } else {
+            let a = h[(hi - 1) * n + hi - 1];
+            let b = h[(hi - 1) * n + hi];
+            let c = h[hi * n + hi - 1];
+            let d = h[hi * n + hi];
+            (a + d, a * d - b * c)
+        };
+
+        let h00 = h[lo * n + lo];
+        let h01 = h[lo * n + lo + 1];
+        let h10 = h[(lo + 1) * n + lo];
+        let h11 = h[(lo + 1) * n + lo + 1];
+        let h21 = h[(lo + 2) * n + lo + 1];
+        let mut x = h00 * h00 + h01 * h10 - s * h00 + t;
+        let mut y = h10 * (h00 + h11 - s);
+        let mut z = h10 * h21;
+
+        // 追赶“凸起”（bulge chasing）
+        for k in lo..(hi - 1) {
+            let (v, beta) = householder(&[x, y, z]);
+            if beta != 0.0 {
+                let col_start = if k > lo { k - 1 } else { lo };
+                apply_left(h, n, k, &v, beta, col_start..n);
+                let row_end = (k + 4).min(hi + 1);
+                apply_right(h, n, k, &v, beta, 0..row_end);
+                apply_right(q, n, k, &v, beta, 0..n);
+            }
+            if k > lo {
+                h[(k + 1) * n + k - 1] = 0.0;
+                h[(k + 2) * n + k - 1] = 0.0;
+            }
+            x = h[(k + 1) * n + k];
+            y = h[(k + 2) * n + k];
+            if k + 3 <= hi {
+                z = h[(k + 3) * n + k];
+            }
+        }
+        let (v, beta) = householder(&[x, y]);
+        if beta != 0.0 {
+            apply_left(h, n, hi - 1, &v, beta, (hi - 2)..n);
+            apply_right(h, n, hi - 1, &v, beta, 0..(hi + 1));
+            apply_right(q, n, hi - 1, &v, beta, 0..n);
+        }
+        h[hi * n + hi - 2] = 0.0;
+    }
+
+    Ok(())
+}
+
+/// 处理位于 (k, k) 的 2×2 对角块：
+/// 如果它有两个实特征值，就用一个 Givens 旋转把它化成上三角；复特征值则保持不动。
+fn split_2x2_block(h: &mut [f64], q: &mut [f64], n: usize, k: usize) {
+    let a = h[k * n + k];
+    let b = h[k * n + k + 1];
+    let c = h[(k + 1) * n + k];
+    let d = h[(k + 1) * n + k + 1];
+    if c == 0.0 {
+        return;
+    }
+
+    let p = 0.5 * (a - d);
+    let disc = p * p + b * c;
+    if disc < 0.0 {
+        return;
+    }
+
+    // 取离 d 较远的那个特征值，求对应的特征向量 (cs, sn)
+    let root = disc.sqrt();
+    let lambda = 0.5 * (a + d) + if p >= 0.0 { root } else { -root };
+    let (mut cs, mut sn) = if (lambda - d).abs() >= (lambda - a).abs() {
+        (lambda - d, c)
+    } else {
+        (b, lambda - a)
+    };
+    let r = cs.hypot(sn);
+    if r == 0.0 {
+        return;
+    }
+    cs /= r;
+    sn /= r;
+
+    // G = [cs -sn; sn cs]，T ← Gᵀ T G，Q ← Q G
+    for j in 0..n {
+        let t1 = h[k * n + j];
+        let t2 = h[(k + 1) * n + j];
+        h[k * n + j] = cs * t1 + sn * t2;
+        h[(k + 1) * n + j] = -sn * t1 + cs * t2;
+    }
+    for m in [&mut *h, &mut *q] {
+        for i in 0..n {
+            let t1 = m[i * n + k];
+            let t2 = m[i * n + k + 1];
+            m[i * n + k] = cs * t1 + sn * t2;
+            m[i * n + k + 1] = -sn * t1 + cs * t2;
+        }
+    }
+    h[(k + 1) * n + k] = 0.0;
+}
@@ -0,0 +1,449 @@
+//! 矩阵/向量的二进制存档格式（`.lalb`）。
+//!
+//! 布局（多字节整数和 f64 都按头部声明的字节序存放）：
+//!
+//! | 偏移 | 长度 | 内容                                   |
+//! |------|------|----------------------------------------|
+//! | 0    | 4    | 魔数 `b"LALB"`                         |
+//! | 4    | 2    | 格式版本（u16，目前为 1）              |
+//! | 6    | 1    | 字节序：0 = 小端，1 = 大端             |
+//! | 7    | 1    | 元素类型：1 = f64                      |
+//! | 8    | 1    | 对象类型：1 = Matrix，2 = Vector2，3 = Matrix2x2 |
+//! | 9    | 3    | 保留，必须为 0                         |
+//! | 12   | 8    | 行数（u64）                            |
+//! | 20   | 8    | 列数（u64）                            |
+//! | 28   | 4    | 头部校验和：字节 0..28 的 CRC-32       |
+//! | 32   | 8·rows·cols | 数据，行优先                    |
+//! | ...  | 4    | 数据校验和：数据部分的 CRC-32          |
+//!
+//! 头部单独校验，所以损坏的形状字段会在分配内存之前就被发现。
+
+use crate::matrix::Matrix2x2;
+use crate::matrix_dense::{Matrix, MatrixError};
+use crate::vector::Vector2;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+const MAGIC: [u8; 4] = *b"LALB";
+const VERSION: u16 = 1;
+const DTYPE_F64: u8 = 1;
+const HEADER_LEN: usize = 28;
+
+/// 读取数据时每次处理的元素个数（流式读写，不需要先把整个文件读进内存）。
+const CHUNK: usize = 1024;
+
+/// 存档里的对象类型，读取时必须与目标类型一致。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectKind {
+    Matrix = 1,
+    Vector2 = 2,
+    Matrix2x2 = 3,
+}
+
+/// 写出时使用的字节序；读取时按文件头自动识别。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    Little,
+    Big,
+}
+
+#[derive(Debug)]
+pub enum SerializeError {
+    Io(std::io::Error),
+    /// 数据在头部或数据部分结束之前就结束了
+    Truncated,
+    BadMagic,
+    UnsupportedVersion(u16),
+    UnsupportedByteOrder(u8),
+    UnsupportedDtype(u8),
+    /// 对象类型与要读取的类型不一致（或者是未知的类型编号）
+    WrongKind {
+        expected: ObjectKind,
+        found: u8,
+    },
+    /// 头部的保留字节（偏移 9..12）不全为 0
+    NonZeroReserved([u8; 3]),
+    /// 形状与对象类型不符，或 rows·cols 溢出
+    BadShape {
+        rows: u64,
+        cols: u64,
+    },
+    HeaderChecksum {
+        expected: u32,
+        found: u32,
+    },
+    DataChecksum {
+        expected: u32,
+        found: u32,
+    },
+    Matrix(MatrixError),
+}
+
+impl std::fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SerializeError::Io(e) => write!(f, "i/o error: {}", e),
+            SerializeError::Truncated => write!(f, "unexpected end of data"),
+            SerializeError::BadMagic => write!(f, "not a matrix archive (bad magic bytes)"),
+            SerializeError::UnsupportedVersion(v) => write!(f, "unsupported format version {}", v),
+            SerializeError::UnsupportedByteOrder(b) => write!(f, "unknown byte order flag {}", b),
+            SerializeError::UnsupportedDtype(d) => write!(f, "unsupported element type {}", d),
+            SerializeError::WrongKind { expected, found } => {
+                write!(
+                    f,
+                    "expected a {:?} archive, found object kind {}",
+                    expected, found
+                )
+            }
+            SerializeError::NonZeroReserved(bytes) => {
+                write!(
+                    f,
+                    "reserved header bytes must be zero, found {:02x?}",
+                    bytes
+                )
+            }
+            SerializeError::BadShape { rows, cols } => {
+                write!(f, "invalid shape {}×{} for this object", rows, cols)
+            }
+            SerializeError::HeaderChecksum { expected, found } => write!(
+                f,
+                "header checksum mismatch (stored {:08x}, computed {:08x})",
+                expected, found
+            ),
+            SerializeError::DataChecksum { expected, found } => write!(
+                f,
+                "data checksum mismatch (stored {:08x}, computed {:08x})",
+                expected, found
+            ),
+            SerializeError::Matrix(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+impl From<std::io::Error> for SerializeError {
+    fn from(e: std::io::Error) -> Self {
+        if e.kind() == std::io::ErrorKind::UnexpectedEof {
+            SerializeError::Truncated
+        } else {
+            SerializeError::Io(e)
+        }
+    }
+}
+
+impl From<MatrixError> for SerializeError {
+    fn from(e: MatrixError) -> Self {
+        SerializeError::Matrix(e)
+    }
+}
+
+/// 可以写成/读回二进制存档的类型。
+///
+/// 只需实现 `write_to_with_order` 和 `read_from`；`write_to`（小端）、`save`、`load` 有默认实现。
+pub trait BinaryFormat: Sized {
+    fn write_to_with_order<W: Write>(
+        &self,
+        writer: &mut W,
+        order: ByteOrder,
+    ) -> Result<(), SerializeError>;
+
+    fn read_from<R: Read>(reader: &mut R) -> Result<Self, SerializeError>;
+
+    fn write_to<W: Write>(&self, writer: &mut W) -> Result<(), SerializeError> {
+        self.write_to_with_order(writer, ByteOrder::Little)
+    }
+
+    fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SerializeError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn load<P: AsRef<Path>>(path: P) -> Result<Self, SerializeError> {
+        Self::read_from(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+impl BinaryFormat for Matrix {
+    fn write_to_with_order<W: Write>(
+        &self,
+        writer: &mut W,
+        order: ByteOrder,
+    ) -> Result<(), SerializeError> {
+        write_archive(
+            writer,
+            order,
+            ObjectKind::Matrix,
+            (self.rows(), self.cols()),
+            self.as_slice(),
+        )
+    }
+
+    fn read_from<R: Read>(reader: &mut R) -> Result<Self, SerializeError> {
+        let (rows, cols, data) = read_archive(reader, ObjectKind::Matrix, None)?;
+        Ok(Matrix::new(rows, cols, data)?)
+    }
+}
+
+/// Vector2 存成 2 × 1 的列向量。
+impl BinaryFormat for Vector2 {
+    fn write_to_with_order<W: Write>(
+        &self,
+        writer: &mut W,
+        order: ByteOrder,
+    ) -> Result<(), SerializeError> {
+        write_archive(
+            writer,
+            order,
+            ObjectKind::Vector2,
+            (2, 1),
+            &[self.x(), self.y()],
+        )
+    }
+
+    fn read_from<R: Read>(reader: &mut R) -> Result<Self, SerializeError> {
+        let (_, _, d) = read_archive(reader, ObjectKind::Vector2, Some((2, 1)))?;
+        Ok(Vector2::new(d[0], d[1]))
+    }
+}
+
+impl BinaryFormat for Matrix2x2 {
+    fn write_to_with_order<W: Write>(
+        &self,
+        writer: &mut W,
+        order: ByteOrder,
+    ) -> Result<(), SerializeError> {
+        write_archive(
+            writer,
+            order,
+            ObjectKind::Matrix2x2,
+            (2, 2),
+            &[self.m11(), self.m12(), self.m21(), self.m22()],
+        )
+    }
+
+    fn read_from<R: Read>(reader: &mut R) -> Result<Self, SerializeError> {
+        let (_, _, d) = read_archive(reader, ObjectKind::Matrix2x2, Some((2, 2)))?;
+        Ok(Matrix2x2::new(d[0], d[1], d[2], d[3]))
+    }
+}
+
+fn write_archive<W: Write>(
+    writer: &mut W,
+    order: ByteOrder,
+    kind: ObjectKind,
+    (rows, cols): (usize, usize),
+    data: &[f64],
+) -> Result<(), SerializeError> {
+    let mut header = Vec::with_capacity(HEADER_LEN + 4);
+    header.extend_from_slice(&MAGIC);
+    header.extend_from_slice(&u16_bytes(VERSION, order));
+    header.push(match order {
+        ByteOrder::Little => 0,
+        ByteOrder::Big => 1,
+    });
+    header.push(DTYPE_F64);
+    header.push(kind as u8);
+    header.extend_from_slice(&[0; 3]);
+    header.extend_from_slice(&u64_bytes(rows as u64, order));
+    header.extend_from_slice(&u64_bytes(cols as u64, order));
+    let header_crc = crc32(&header);
+    header.extend_from_slice(&u32_bytes(header_crc, order));
+    writer.write_all(&header)?;
+
+    let mut crc = Crc32::new();
+    let mut buf = Vec::with_capacity(CHUNK * 8);
+    for chunk in data.chunks(CHUNK) {
+        buf.clear();
+        for v in chunk {
+            buf.extend_from_slice(&match order {
+                ByteOrder::Little => v.to_le_bytes(),
+                ByteOrder::Big => v.to_be_bytes(),
+            });
+        }
+        crc.update(&buf);
+        writer.write_all(&buf)?;
+    }
+    writer.write_all(&u32_bytes(crc.finish(), order))?;
+    Ok(())
+}
+
+/// 读取并校验一个存档，返回 (rows, cols, data)。`shape` 为 Some 时还要求形状完全一致。
+fn read_archive<R: Read>(
+    reader: &mut R,
+    expected: ObjectKind,
+    shape: Option<(u64, u64)>,
+) -> Result<(usize, usize, Vec<f64>), SerializeError> {
+    let mut header = [0u8; HEADER_LEN + 4];
+    reader.read_exact(&mut header)?;
+    if header[0..4] != MAGIC {
+        return Err(SerializeError::BadMagic);
+    }
+    let order = match header[6] {
+        0 => ByteOrder::Little,
+        1 => ByteOrder::Big,
+        other => return Err(SerializeError::UnsupportedByteOrder(other)),
+    };
+    let stored = read_u32(&header[HEADER_LEN..], order);
+    let computed = crc32(&header[..HEADER_LEN]);
+    if stored != computed {
+        return Err(SerializeError::HeaderChecksum {
+            expected: stored,
+            found: computed,
+        });
+    }
+
+    let version = u16::from_le_bytes([header[4], header[5]]);
+    let version = match order {
+        ByteOrder::Little => version,
+        ByteOrder::Big => version.swap_bytes(),
+    };
+    if version != VERSION {
+        return Err(SerializeError::UnsupportedVersion(version));
+    }
+    if header[7] != DTYPE_F64 {
+        return Err(SerializeError::UnsupportedDtype(header[7]));
+    }
+    if header[8] != expected as u8 {
+        return Err(SerializeError::WrongKind {
+            expected,
+            found: header[8],
+        });
+    }
+    if header[9..12] != [0; 3] {
+        return Err(SerializeError::NonZeroReserved([
+            header[9], header[10], header[11],
+        ]));
+    }
+    let rows = read_u64(&header[12..20], order);
+    let cols = read_u64(&header[20..28], order);
+    let len = rows
+        .checked_mul(cols)
+        .and_then(|n| usize::try_from(n).ok())
+        .filter(|n| n.checked_mul(8).is_some());
+    let len = match (len, shape) {
+        (Some(n), None) => n,
+        (Some(n), Some(s)) if s == (rows, cols) => n,
+        _ => return Err(SerializeError::BadShape { rows, cols }),
+    };
+
+    let mut crc = Crc32::new();
+    let mut data = Vec::with_capacity(len.min(1 << 20));
+    let mut buf = vec![0u8; CHUNK * 8];
+    let mut remaining = len;
+    while remaining > 0 {
+        let n = remaining.min(CHUNK);
+        let bytes = &mut buf[..n * 8];
+        reader.read_exact(bytes)?;
+        crc.update(bytes);
+        data.extend(bytes.chunks_exact(8).map(|b| {
+            let b: [u8; 8] = b.try_into().expect("chunk of 8 bytes");
+            match order {
+                ByteOrder::Little => f64::from_le_bytes(b),
+                ByteOrder::Big => f64::from_be_bytes(b),
+            }
+        }));
+        remaining -= n;
+    }
+
+    let mut tail = [0u8; 4];
+    reader.read_exact(&mut tail)?;
+    let stored = read_u32(&tail, order);
+    let computed = crc.finish();
+    if stored != computed {
+        return Err(SerializeError::DataChecksum {
+            expected: stored,
+            found: computed,
+        });
+    }
+    Ok((rows as usize, cols as usize, data))
+}
+
+fn u16_bytes(v: u16, order: ByteOrder) -> [u8; 2] {
+    match order {
+        ByteOrder::Little => v.to_le_bytes(),
+        ByteOrder::Big => v.to_be_bytes(),
+    }
+}
+
+fn u32_bytes(v: u32, order: ByteOrder) -> [u8; 4] {
+    match order {
+        ByteOrder::Little => v.to_le_bytes(),
+        ByteOrder::Big => v.to_be_bytes(),
+    }
+}
+
+fn u64_bytes(v: u64, order: ByteOrder) -> [u8; 8] {
+    match order {
+        ByteOrder::Little => v.to_le_bytes(),
+        ByteOrder::Big => v.to_be_bytes(),
+    }
+}
+
+fn read_u32(b: &[u8], order: ByteOrder) -> u32 {
+    let b: [u8; 4] = b[..4].try_into().expect("4 bytes");
+    match order {
+        ByteOrder::Little => u32::from_le_bytes(b),
+        ByteOrder::Big => u32::from_be_bytes(b),
+    }
+}
+
+fn read_u64(b: &[u8], order: ByteOrder) -> u64 {
+    let b: [u8; 8] = b[..8].try_into().expect("8 bytes");
+    match order {
+        ByteOrder::Little => u64::from_le_bytes(b),
+        ByteOrder::Big => u64::from_be_bytes(b),
+    }
+}
+
+/// CRC-32（IEEE 802.3，多项式 0xEDB88320，与 zlib/PNG 相同）。
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = Crc32::new();
+    crc.update(bytes);
+    crc.finish()
+}
+
+/// 编译期生成的 CRC-32 查找表。
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+};
+
+/// 可以分段喂数据的 CRC-32 计算器。
+struct Crc32 {
+    state: u32,
+}
+
+impl Crc32 {
+    fn new() -> Self {
+        Self { state: 0xFFFF_FFFF }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.state = CRC_TABLE[((self.state ^ b as u32) & 0xFF) as usize] ^ (self.state >> 8);
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        self.state ^ 0xFFFF_FFFF
+    }
+}
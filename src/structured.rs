@@ -0,0 +1,605 @@
+use crate::eigen_iterative::{FnOperator, LinearOperator};
+use crate::matrix_dense::{Matrix, MatrixError};
+
+/// 两个序列长度都不小于这个值时，卷积改用 FFT（更短时直接 O(n²) 求和反而更快）。
+const FFT_THRESHOLD: usize = 32;
+
+/// Levinson 递推中分母（相对）小于这个值时认为顺序主子式奇异。
+const BREAKDOWN_TOL: f64 = 1e-12;
+
+/// Toeplitz 矩阵：每条对角线上的元素相同，T[i][j] = t(i - j)。
+///
+/// 只存第一列 (t(0), t(1), ..., t(m-1)) 和第一行 (t(0), t(-1), ..., t(-(n-1)))，
+/// 内存 O(m + n)。
+#[derive(Debug, Clone)]
+pub struct Toeplitz {
+    first_col: Vec<f64>,
+    first_row: Vec<f64>,
+}
+
+/// 循环矩阵：C[i][j] = c[(i - j) mod n]，每一列是上一列向下循环移一位。
+///
+/// 所有循环矩阵都能被 DFT 对角化，特征值就是第一列的 DFT。
+#[derive(Debug, Clone)]
+pub struct Circulant {
+    first_col: Vec<f64>,
+}
+
+/// Vandermonde 矩阵：V[i][j] = x_i^j（m 个节点，n 列）。
+///
+/// V a = f 就是“过这些点的多项式插值”，a 是系数（常数项在前）。
+#[derive(Debug, Clone)]
+pub struct Vandermonde {
+    nodes: Vec<f64>,
+    cols: usize,
+}
+
+/// Hankel 矩阵：每条反对角线上的元素相同，H[i][j] = h[i + j]。
+///
+/// 存长度 m + n - 1 的序列 h（第一列接上最后一行去掉第一个元素）。
+#[derive(Debug, Clone)]
+pub struct Hankel {
+    h: Vec<f64>,
+    rows: usize,
+}
+
+/// Levinson–Durbin 递推的结果：AR(p) 模型 x_t = Σ a_j x_{t-j} + e_t 的 Yule–Walker 解。
+#[derive(Debug, Clone)]
+pub struct YuleWalker {
+    /// 自回归系数 a_1..a_p
+    pub coefficients: Vec<f64>,
+    /// 各阶的反射系数（偏自相关）κ_1..κ_p，|κ| < 1 等价于自相关序列正定
+    pub reflection: Vec<f64>,
+    /// p 阶预测误差方差
+    pub error_variance: f64,
+}
+
+impl Toeplitz {
+    /// 由第一列和第一行构造；两者的第一个元素必须相同。
+    pub fn new(first_col: Vec<f64>, first_row: Vec<f64>) -> Result<Self, MatrixError> {
+        match (first_col.first(), first_row.first()) {
+            (Some(c), Some(r)) if c == r => Ok(Self {
+                first_col,
+                first_row,
+            }),
+            _ => Err(MatrixError::DimensionMismatch),
+        }
+    }
+
+    /// 对称 Toeplitz 矩阵（例如自相关矩阵），r 同时是第一行和第一列。
+    pub fn symmetric(r: Vec<f64>) -> Result<Self, MatrixError> {
+        Self::new(r.clone(), r)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.first_col.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.first_row.len()
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Result<f64, MatrixError> {
+        if i >= self.rows() || j >= self.cols() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(if i >= j {
+            self.first_col[i - j]
+        } else {
+            self.first_row[j - i]
+        })
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        dense_from(self.rows(), self.cols(), |i, j| {
+            if i >= j {
+                self.first_col[i - j]
+            } else {
+                self.first_row[j - i]
+            }
+        })
+    }
+
+    /// y = T x：把 T 写成一个卷积，长矩阵时用 FFT，O((m + n) log(m + n))。
+    pub fn matvec(&self, x: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if x.len() != self.cols() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(self.matvec_unchecked(x))
+    }
+
+    /// 作为算子使用（可以直接交给 `power_iteration`、`lanczos` 等）；不是方阵时返回 `NotSquare`。
+    pub fn as_operator(&self) -> Result<impl LinearOperator + '_, MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare);
+        }
+        Ok(FnOperator::new(self.rows(), |x: &[f64], y: &mut [f64]| {
+            y.copy_from_slice(&self.matvec_unchecked(x))
+        }))
+    }
+
+    fn matvec_unchecked(&self, x: &[f64]) -> Vec<f64> {
+        // s[k] = t(k - (n-1))，则 y_i = Σ_j s[i - j + n - 1] x_j = (s * x)[i + n - 1]
+        let n = self.cols();
+        let s: Vec<f64> = self.first_row[1..]
+            .iter()
+            .rev()
+            .chain(&self.first_col)
+            .copied()
+            .collect();
+        let full = convolve(&s, x);
+        full[(n - 1)..(n - 1 + self.rows())].to_vec()
+    }
+
+    /// 解 T x = b（一般的非对称 Toeplitz 方阵），Levinson 递推，O(n²)，只需 O(n) 额外内存。
+    ///
+    /// 递推过程中同时维护前向向量 f（T_k f = e_1）和后向向量 g（T_k g = e_k），
+    /// 每次把阶数加一。要求所有顺序主子式非奇异，否则返回 `MatrixError::Singular`
+    /// （这时 T 本身可能仍可逆，可以改用 `to_dense()` 后做选主元 LU）。
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let n = self.rows();
+        if self.cols() != n {
+            return Err(MatrixError::NotSquare);
+        }
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        let t0 = self.first_col[0];
+        let scale = self
+            .first_col
+            .iter()
+            .chain(&self.first_row)
+            .fold(0.0_f64, |m, v| m.max(v.abs()));
+        if t0.abs() <= BREAKDOWN_TOL * scale {
+            return Err(MatrixError::Singular);
+        }
+        let mut f = vec![1.0 / t0];
+        let mut g = vec![1.0 / t0];
+        let mut x = vec![b[0] / t0];
+
+        for (k, bk) in b.iter().enumerate().skip(1) {
+            // T_{k+1} [f; 0] = e_1 + ef·e_{k+1}，T_{k+1} [0; g] = eb·e_1 + e_{k+1}
+            let ef: f64 = (0..k).map(|j| self.first_col[k - j] * f[j]).sum();
+            let eb: f64 = (0..k).map(|j| self.first_row[j + 1] * g[j]).sum();
+            let denom = 1.0 - ef * eb;
+            if denom.abs() <= BREAKDOWN_TOL {
+                return Err(MatrixError::Singular);
+            }
+
+            let mut f_new = vec![0.0; k + 1];
+            let mut g_new = vec![0.0; k + 1];
+            for i in 0..=k {
+                let fi = if i < k { f[i] } else { 0.0 };
+                let gi = if i > 0 { g[i - 1] } else { 0.0 };
+                f_new[i] = (fi - ef * gi) / denom;
+                g_new[i] = (gi - eb * fi) / denom;
+            }
+            f = f_new;
+            g = g_new;
+
+            // T_{k+1} [x; 0] = [b_0..b_{k-1}; ex]，用 g 修正最后一个分量
+            let ex: f64 = (0..k).map(|j| self.first_col[k - j] * x[j]).sum();
+            x.push(0.0);
+            let correction = bk - ex;
+            for (xi, gi) in x.iter_mut().zip(&g) {
+                *xi += correction * gi;
+            }
+        }
+        Ok(x)
+    }
+}
+
+/// Levinson–Durbin：由自相关序列 r_0..r_p 解 p 阶 Yule–Walker 方程
+///   Σ_j a_j r_{|i-j|} = r_i，i = 1..p，
+/// O(p²)。自相关序列不正定（某一阶预测误差 <= 0）时返回 `MatrixError::NotPositiveDefinite`。
+pub fn levinson_durbin(r: &[f64], order: usize) -> Result<YuleWalker, MatrixError> {
+    if r.len() <= order {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    let mut a: Vec<f64> = Vec::with_capacity(order);
+    let mut reflection = Vec::with_capacity(order);
+    let mut error = r[0];
+    if error <= 0.0 {
+        return Err(MatrixError::NotPositiveDefinite);
+    }
+
+    for k in 1..=order {
+        let acc = r[k] - (1..k).map(|j| a[j - 1] * r[k - j]).sum::<f64>();
+        let kappa = acc / error;
+        let previous = a.clone();
+        for j in 1..k {
+            a[j - 1] = previous[j - 1] - kappa * previous[k - j - 1];
+        }
+        a.push(kappa);
+        reflection.push(kappa);
+
+        error *= 1.0 - kappa * kappa;
+        if error <= 0.0 {
+            return Err(MatrixError::NotPositiveDefinite);
+        }
+    }
+    Ok(YuleWalker {
+        coefficients: a,
+        reflection,
+        error_variance: error,
+    })
+}
+
+impl Circulant {
+    /// 由第一列构造；与 `Toeplitz::new` 一样，空的第一列返回 `MatrixError::DimensionMismatch`。
+    pub fn new(first_col: Vec<f64>) -> Result<Self, MatrixError> {
+        if first_col.is_empty() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(Self { first_col })
+    }
+
+    pub fn size(&self) -> usize {
+        self.first_col.len()
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Result<f64, MatrixError> {
+        let n = self.size();
+        if i >= n || j >= n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(self.first_col[(i + n - j) % n])
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        let n = self.size();
+        dense_from(n, n, |i, j| self.first_col[(i + n - j) % n])
+    }
+
+    /// 全部特征值 (实部, 虚部)：第一列的 DFT。第 k 个特征向量是 (ω^{jk})_j，ω = e^{2πi/n}。
+    pub fn eigenvalues(&self) -> Vec<(f64, f64)> {
+        let (re, im) = dft(&self.first_col, &vec![0.0; self.size()], false);
+        re.into_iter().zip(im).collect()
+    }
+
+    /// y = C x，看作 Toeplitz 矩阵用 FFT 卷积计算。
+    pub fn matvec(&self, x: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if x.len() != self.size() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(self.as_toeplitz().matvec_unchecked(x))
+    }
+
+    /// 解 C x = b：x = IDFT(DFT(b) / DFT(c))。
+    ///
+    /// n 是 2 的幂时用 FFT（O(n log n)），否则直接做 O(n²) 的 DFT。
+    /// 有特征值为 0 时返回 `MatrixError::Singular`。
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let n = self.size();
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let (lre, lim) = dft(&self.first_col, &vec![0.0; n], false);
+        let (bre, bim) = dft(b, &vec![0.0; n], false);
+        let largest = lre
+            .iter()
+            .zip(&lim)
+            .fold(0.0_f64, |m, (r, i)| m.max(r.hypot(*i)));
+
+        let mut qre = vec![0.0; n];
+        let mut qim = vec![0.0; n];
+        for k in 0..n {
+            let d = lre[k] * lre[k] + lim[k] * lim[k];
+            if d.sqrt() <= BREAKDOWN_TOL * largest || d == 0.0 {
+                return Err(MatrixError::Singular);
+            }
+            qre[k] = (bre[k] * lre[k] + bim[k] * lim[k]) / d;
+            qim[k] = (bim[k] * lre[k] - bre[k] * lim[k]) / d;
+        }
+        let (x, _) = dft(&qre, &qim, true);
+        Ok(x)
+    }
+
+    fn as_toeplitz(&self) -> Toeplitz {
+        let n = self.size();
+        let first_row = (0..n).map(|j| self.first_col[(n - j) % n]).collect();
+        Toeplitz {
+            first_col: self.first_col.clone(),
+            first_row,
+        }
+    }
+}
+
+impl Vandermonde {
+    /// m 个节点、n 列（n = 多项式次数 + 1）的 Vandermonde 矩阵。
+    pub fn new(nodes: Vec<f64>, cols: usize) -> Self {
+        Self { nodes, cols }
+    }
+
+    /// 方阵：列数等于节点数。
+    pub fn square(nodes: Vec<f64>) -> Self {
+        let n = nodes.len();
+        Self::new(nodes, n)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn nodes(&self) -> &[f64] {
+        &self.nodes
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Result<f64, MatrixError> {
+        if i >= self.rows() || j >= self.cols() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(self.nodes[i].powi(j as i32))
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        let mut m = Matrix::zeros(self.rows(), self.cols());
+        for (i, &x) in self.nodes.iter().enumerate() {
+            let mut p = 1.0;
+            for j in 0..self.cols {
+                m.set(i, j, p);
+                p *= x;
+            }
+        }
+        m
+    }
+
+    /// y = V a：对每个节点用 Horner 法求多项式 Σ a_j x^j 的值，O(m n)。
+    pub fn matvec(&self, a: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if a.len() != self.cols() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(self
+            .nodes
+            .iter()
+            .map(|&x| a.iter().rev().fold(0.0, |acc, c| acc * x + c))
+            .collect())
+    }
+
+    /// 解 V a = f（插值多项式的系数），Björck–Pereyra 算法，O(n²)、不形成矩阵。
+    ///
+    /// 先用差商求 Newton 形式的系数，再逐步展开成幂基系数。
+    /// 对节点单调排列的问题，这个算法的精度通常远好于 Vandermonde 矩阵的条件数所预示的。
+    /// 有重复节点时返回 `MatrixError::Singular`。
+    pub fn solve(&self, f: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let n = self.rows();
+        if self.cols() != n {
+            return Err(MatrixError::NotSquare);
+        }
+        if f.len() != n {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let x = &self.nodes;
+        let mut a = f.to_vec();
+
+        // 差商：a 变成 Newton 系数
+        for k in 0..n.saturating_sub(1) {
+            for i in ((k + 1)..n).rev() {
+                let dx = x[i] - x[i - k - 1];
+                if dx == 0.0 {
+                    return Err(MatrixError::Singular);
+                }
+                a[i] = (a[i] - a[i - 1]) / dx;
+            }
+        }
+        // Newton 形式 → 幂基
+        for k in (0..n.saturating_sub(1)).rev() {
+            for i in k..(n - 1) {
+                a[i] -= a[i + 1] * x[k];
+            }
+        }
+        Ok(a)
+    }
+}
+
+impl Hankel {
+    /// 由第一列和最后一行构造；第一列的最后一个元素必须等于最后一行的第一个元素。
+    pub fn new(first_col: Vec<f64>, last_row: Vec<f64>) -> Result<Self, MatrixError> {
+        match (first_col.last(), last_row.first()) {
+            (Some(c), Some(r)) if c == r => {
+                let rows = first_col.len();
+                let mut h = first_col;
+                h.extend_from_slice(&last_row[1..]);
+                Ok(Self { h, rows })
+            }
+            _ => Err(MatrixError::DimensionMismatch),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.h.len() + 1 - self.rows
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Result<f64, MatrixError> {
+        if i >= self.rows() || j >= self.cols() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(self.h[i + j])
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        dense_from(self.rows(), self.cols(), |i, j| self.h[i + j])
+    }
+
+    /// y = H x：y_i = Σ_j h[i + j] x_j 是 h 与反转后的 x 的卷积，长矩阵时用 FFT。
+    pub fn matvec(&self, x: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if x.len() != self.cols() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        Ok(self.matvec_unchecked(x))
+    }
+
+    /// 作为算子使用；不是方阵时返回 `NotSquare`。
+    pub fn as_operator(&self) -> Result<impl LinearOperator + '_, MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare);
+        }
+        Ok(FnOperator::new(self.rows(), |x: &[f64], y: &mut [f64]| {
+            y.copy_from_slice(&self.matvec_unchecked(x))
+        }))
+    }
+
+    fn matvec_unchecked(&self, x: &[f64]) -> Vec<f64> {
+        let n = self.cols();
+        let reversed: Vec<f64> = x.iter().rev().copied().collect();
+        let full = convolve(&self.h, &reversed);
+        full[(n - 1)..(n - 1 + self.rows())].to_vec()
+    }
+}
+
+/// 循环矩阵总是方阵，直接作为算子使用。
+impl LinearOperator for Circulant {
+    fn dim(&self) -> usize {
+        self.size()
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        y.copy_from_slice(&self.as_toeplitz().matvec_unchecked(x));
+    }
+}
+
+fn dense_from(rows: usize, cols: usize, entry: impl Fn(usize, usize) -> f64) -> Matrix {
+    let mut m = Matrix::zeros(rows, cols);
+    for i in 0..rows {
+        for j in 0..cols {
+            m.set(i, j, entry(i, j));
+        }
+    }
+    m
+}
+
+/// 线性卷积 (a * b)[k] = Σ_i a[i] b[k - i]，长度 a.len() + b.len() - 1。
+///
+/// 两个序列都足够长时补零到 2 的幂再用 FFT，否则直接求和。
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) < FFT_THRESHOLD {
+        let mut out = vec![0.0; len];
+        for (i, ai) in a.iter().enumerate() {
+            for (j, bj) in b.iter().enumerate() {
+                out[i + j] += ai * bj;
+            }
+        }
+        return out;
+    }
+
+    let size = len.next_power_of_two();
+    let mut are = a.to_vec();
+    are.resize(size, 0.0);
+    let mut aim = vec![0.0; size];
+    let mut bre = b.to_vec();
+    bre.resize(size, 0.0);
+    let mut bim = vec![0.0; size];
+    fft_in_place(&mut are, &mut aim, false);
+    fft_in_place(&mut bre, &mut bim, false);
+    for k in 0..size {
+        let (r, i) = (
+            are[k] * bre[k] - aim[k] * bim[k],
+            are[k] * bim[k] + aim[k] * bre[k],
+        );
+        are[k] = r;
+        aim[k] = i;
+    }
+    fft_in_place(&mut are, &mut aim, true);
+    are.truncate(len);
+    are
+}
+
+/// 离散傅里叶变换 X_k = Σ_j x_j e^{∓2πi jk/n}（inverse 时取 + 号并除以 n）。
+///
+/// 长度是 2 的幂时用 FFT，否则直接按定义求和。
+fn dft(re: &[f64], im: &[f64], inverse: bool) -> (Vec<f64>, Vec<f64>) {
+    let n = re.len();
+    if n.is_power_of_two() {
+        let (mut r, mut i) = (re.to_vec(), im.to_vec());
+        fft_in_place(&mut r, &mut i, inverse);
+        return (r, i);
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut out_re = vec![0.0; n];
+    let mut out_im = vec![0.0; n];
+    for k in 0..n {
+        for j in 0..n {
+            // jk 先对 n 取模，避免大角度下 sin/cos 的精度损失
+            let angle = sign * 2.0 * std::f64::consts::PI * ((j * k) % n) as f64 / n as f64;
+            let (s, c) = angle.sin_cos();
+            out_re[k] += re[j] * c - im[j] * s;
+            out_im[k] += re[j] * s + im[j] * c;
+        }
+    }
+    if inverse {
+        for k in 0..n {
+            out_re[k] /= n as f64;
+            out_im[k] /= n as f64;
+        }
+    }
+    (out_re, out_im)
+}
+
+/// 迭代式基 2 Cooley–Tukey FFT，长度必须是 2 的幂。inverse 时结果已除以 n。
+fn fft_in_place(re: &mut [f64], im: &mut [f64], inverse: bool) {
+    let n = re.len();
+    if n <= 1 {
+        return;
+    }
+
+    // 位逆序重排
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2.0 * std::f64::consts::PI / len as f64;
+        let half = len / 2;
+        for start in (0..n).step_by(len) {
+            for k in 0..half {
+                // 每个旋转因子直接算，避免递推累积误差
+                let (s, c) = (angle * k as f64).sin_cos();
+                let (a, b) = (start + k, start + k + half);
+                let tr = re[b] * c - im[b] * s;
+                let ti = re[b] * s + im[b] * c;
+                re[b] = re[a] - tr;
+                im[b] = im[a] - ti;
+                re[a] += tr;
+                im[a] += ti;
+            }
+        }
+        len <<= 1;
+    }
+
+    if inverse {
+        let scale = 1.0 / n as f64;
+        for (r, i) in re.iter_mut().zip(im.iter_mut()) {
+            *r *= scale;
+            *i *= scale;
+        }
+    }
+}
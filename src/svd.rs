@@ -0,0 +1,293 @@
+use crate::blas::dot_unchecked;
+use crate::gram_schmidt::orthonormal_basis;
+use crate::matrix_dense::{Matrix, MatrixError, matmul};
+use crate::random::Rng;
+
+/// 一侧 Jacobi 方法最多扫描的轮数。
+const MAX_SWEEPS: usize = 60;
+
+/// `low_rank_approx` 默认使用的随机种子。
+const DEFAULT_SEED: u64 = 0x5EED;
+
+/// 奇异值分解 A ≈ U Σ Vᵀ（“瘦”形式）。
+///
+/// - u: m × r，列正交归一
+/// - singular_values: 长度 r，从大到小排列
+/// - vt: r × n，行正交归一
+#[derive(Debug, Clone)]
+pub struct Svd {
+    pub u: Matrix,
+    pub singular_values: Vec<f64>,
+    pub vt: Matrix,
+}
+
+impl Svd {
+    /// 重新乘出 U Σ Vᵀ。
+    pub fn reconstruct(&self) -> Result<Matrix, MatrixError> {
+        let mut us = self.u.clone();
+        for (j, s) in self.singular_values.iter().enumerate() {
+            for i in 0..us.rows() {
+                us.set(i, j, us.get(i, j)? * s);
+            }
+        }
+        matmul(&us, &self.vt)
+    }
+}
+
+/// 随机 SVD 的参数。
+#[derive(Debug, Clone, Copy)]
+pub struct RandomizedSvdOptions {
+    /// 目标秩 k
+    pub rank: usize,
+    /// 过采样列数 p：实际用 k + p 个随机向量去捕获列空间，通常 5～10 就够
+    pub oversampling: usize,
+    /// 幂迭代次数 q：奇异值衰减慢时用 (A Aᵀ)^q A 代替 A，让谱更陡
+    pub power_iterations: usize,
+    /// 随机种子，相同种子得到完全相同的结果
+    pub seed: u64,
+}
+
+impl RandomizedSvdOptions {
+    pub fn new(rank: usize) -> Self {
+        Self {
+            rank,
+            oversampling: 10,
+            power_iterations: 2,
+            seed: DEFAULT_SEED,
+        }
+    }
+}
+
+/// 低秩近似 A ≈ left * right。
+///
+/// - left: m × k（= U_k Σ_k）
+/// - right: k × n（= V_kᵀ）
+///
+/// 只存两个细长的因子，内存从 m·n 降到 (m + n)·k。
+#[derive(Debug, Clone)]
+pub struct LowRank {
+    pub left: Matrix,
+    pub right: Matrix,
+}
+
+impl LowRank {
+    /// 乘出完整的 m × n 近似矩阵。
+    pub fn to_matrix(&self) -> Result<Matrix, MatrixError> {
+        matmul(&self.left, &self.right)
+    }
+}
+
+impl Matrix {
+    /// 用随机 SVD 求秩为 k 的近似，返回两个因子（默认参数、固定种子，结果可复现）。
+    ///
+    /// 需要调整过采样、幂迭代次数或种子时直接调用 [`randomized_svd`]。
+    pub fn low_rank_approx(&self, k: usize) -> Result<LowRank, MatrixError> {
+        let svd = randomized_svd(self, &RandomizedSvdOptions::new(k))?;
+        let mut left = svd.u;
+        for (j, s) in svd.singular_values.iter().enumerate() {
+            for i in 0..left.rows() {
+                left.set(i, j, left.get(i, j)? * s);
+            }
+        }
+        Ok(LowRank {
+            left,
+            right: svd.vt,
+        })
+    }
+}
+
+/// 完整（瘦）SVD：一侧 Jacobi 方法（Hestenes）。
+///
+/// 反复对 A 的两列做平面旋转，使它们正交，同时把旋转累积进 V；
+/// 所有列两两正交后，列长度就是奇异值，归一化后的列就是 U。
+/// 结果里 r = min(m, n)；m < n 时对 Aᵀ 做分解再交换 U、V。
+///
+/// 精度很高（小奇异值也有好的相对精度），但复杂度是 O(m n² × 扫描轮数)，适合中小矩阵，
+/// 也是随机 SVD 里分解投影后小矩阵的工具。
+pub fn svd(a: &Matrix) -> Result<Svd, MatrixError> {
+    if a.rows() < a.cols() {
+        let t = svd(&a.transpose()?)?;
+        return Ok(Svd {
+            u: t.vt.transpose()?,
+            singular_values: t.singular_values,
+            vt: t.u.transpose()?,
+        });
+    }
+
+    let (m, n) = (a.rows(), a.cols());
+    let mut u: Vec<Vec<f64>> = (0..n).map(|j| a.col(j)).collect::<Result<_, _>>()?;
+    let mut v: Vec<Vec<f64>> = (0..n)
+        .map(|j| (0..n).map(|i| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let alpha = dot_unchecked(&u[p], &u[p]);
+                let beta = dot_unchecked(&u[q], &u[q]);
+                let gamma = dot_unchecked(&u[p], &u[q]);
+                if gamma == 0.0 || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + zeta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = c * t;
+                for cols in [&mut u, &mut v] {
+                    let (left, right) = cols.split_at_mut(q);
+                    for (xp, xq) in left[p].iter_mut().zip(right[0].iter_mut()) {
+                        let (a, b) = (*xp, *xq);
+                        *xp = c * a - s * b;
+                        *xq = s * a + c * b;
+                    }
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(MatrixError::NoConvergence);
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    let sigma: Vec<f64> = u.iter().map(|col| dot_unchecked(col, col).sqrt()).collect();
+    order.sort_by(|&i, &j| sigma[j].total_cmp(&sigma[i]));
+
+    let largest = sigma.iter().fold(0.0_f64, |s, v| s.max(*v));
+    let mut u_mat = Matrix::zeros(m, n);
+    let mut vt = Matrix::zeros(n, n);
+    let mut singular_values = Vec::with_capacity(n);
+    let mut zero_cols = Vec::new();
+    for (k, &j) in order.iter().enumerate() {
+        let s = sigma[j];
+        singular_values.push(s);
+        if s > f64::EPSILON * largest * m as f64 && s > 0.0 {
+            let col: Vec<f64> = u[j].iter().map(|x| x / s).collect();
+            u_mat.set_col(k, &col)?;
+        } else {
+            zero_cols.push(k);
+        }
+        for (i, vij) in v[j].iter().enumerate() {
+            vt.set(k, i, *vij);
+        }
+    }
+    // 零奇异值对应的左奇异向量不唯一，用与其余列正交的单位向量补全，保证 U 的列正交归一
+    fill_orthonormal_complement(&mut u_mat, &zero_cols)?;
+
+    Ok(Svd {
+        u: u_mat,
+        singular_values,
+        vt,
+    })
+}
+
+/// Halko–Martinsson–Tropp 随机 SVD。
+///
+/// 1. 随机范围探测：Y = A Ω，Ω 是 n × (k + p) 的高斯随机矩阵，Q = orth(Y)；
+///    需要时做 q 次幂迭代 Q ← orth(A orth(Aᵀ Q))（每一步都重新正交化，避免舍入误差淹没小奇异值）；
+/// 2. 投影：B = Qᵀ A，只有 (k + p) × n；
+/// 3. 对小矩阵 B 做完整 SVD：B = Ũ Σ Vᵀ，则 A ≈ (Q Ũ) Σ Vᵀ，取前 k 个。
+///
+/// 所有随机数都来自以 `options.seed` 初始化的 [`Rng`]，同样的输入和种子给出同样的结果。
+/// 结果总是恰好 k 个分量：rank(A) < k 时多出的奇异值为 0，对应的奇异向量仍然正交归一。
+pub fn randomized_svd(a: &Matrix, options: &RandomizedSvdOptions) -> Result<Svd, MatrixError> {
+    let k = options.rank;
+    if k == 0 || k > a.rows().min(a.cols()) {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    let mut rng = Rng::new(options.seed);
+    let q = randomized_range_finder(
+        a,
+        k + options.oversampling,
+        options.power_iterations,
+        &mut rng,
+    )?;
+
+    let b = matmul(&q.transpose()?, a)?;
+    let small = svd(&b)?;
+    let u = matmul(&q, &small.u)?;
+    let v = small.vt.transpose()?;
+
+    // rank(A) < k 时探测到的列空间不足 k 维：补上零奇异值，
+    // 对应的奇异向量取与已有向量正交的单位向量，保证结果总是 k 个分量
+    let found = k.min(small.singular_values.len());
+    let mut u_k = Matrix::zeros(a.rows(), k);
+    let mut v_k = Matrix::zeros(a.cols(), k);
+    for j in 0..found {
+        u_k.set_col(j, &u.col(j)?)?;
+        v_k.set_col(j, &v.col(j)?)?;
+    }
+    let mut singular_values = small.singular_values[..found].to_vec();
+    singular_values.resize(k, 0.0);
+    let padded: Vec<usize> = (found..k).collect();
+    fill_orthonormal_complement(&mut u_k, &padded)?;
+    fill_orthonormal_complement(&mut v_k, &padded)?;
+
+    Ok(Svd {
+        u: u_k,
+        singular_values,
+        vt: v_k.transpose()?,
+    })
+}
+
+/// 随机范围探测：返回一个 m × l' 的列正交矩阵 Q（l' <= l），使 Q Qᵀ A ≈ A。
+///
+/// `samples` 是随机测试向量的个数（目标秩 + 过采样），会被截断到 min(m, n)。
+pub fn randomized_range_finder(
+    a: &Matrix,
+    samples: usize,
+    power_iterations: usize,
+    rng: &mut Rng,
+) -> Result<Matrix, MatrixError> {
+    let l = samples.min(a.rows()).min(a.cols());
+    let omega = Matrix::new(
+        a.cols(),
+        l,
+        (0..a.cols() * l).map(|_| rng.next_gaussian()).collect(),
+    )?;
+
+    let tol = 1e-12;
+    let mut q = orthonormal_basis(&matmul(a, &omega)?, tol)?;
+    let at = a.transpose()?;
+    for _ in 0..power_iterations {
+        let z = orthonormal_basis(&matmul(&at, &q)?, tol)?;
+        q = orthonormal_basis(&matmul(a, &z)?, tol)?;
+    }
+    Ok(q)
+}
+
+fn fill_orthonormal_complement(u: &mut Matrix, cols: &[usize]) -> Result<(), MatrixError> {
+    let m = u.rows();
+    let mut candidate = 0;
+    for &k in cols {
+        while candidate < m {
+            let mut e = vec![0.0; m];
+            e[candidate] = 1.0;
+            candidate += 1;
+            for _ in 0..2 {
+                for j in 0..u.cols() {
+                    let col = u.col(j)?;
+                    let c = dot_unchecked(&col, &e);
+                    for (ei, ci) in e.iter_mut().zip(&col) {
+                        *ei -= c * ci;
+                    }
+                }
+            }
+            let len = dot_unchecked(&e, &e).sqrt();
+            if len > 1e-8 {
+                let e: Vec<f64> = e.iter().map(|x| x / len).collect();
+                u.set_col(k, &e)?;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
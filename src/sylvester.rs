@@ -0,0 +1,243 @@
+use crate::matrix_dense::{Matrix, MatrixError, matmul};
+use crate::schur::{diagonal_blocks, real_schur};
+
+/// 用 Bartels–Stewart 算法求解 Sylvester 方程 AX + XB = C。
+///
+/// - A: m × m，B: n × n，C 和 X: m × n
+/// - 先求实 Schur 分解 A = U T Uᵀ、B = V S Vᵀ，方程变成 T Y + Y S = Uᵀ C V；
+/// - T、S 都是拟上三角的，于是可以按 S 的对角块从左到右、T 的对角块从下到上逐块回代，
+///   每一块只需要解一个不超过 4 个未知数的小线性方程组；
+/// - 最后 X = U Y Vᵀ。
+///
+/// 当 A 和 -B 有公共特征值时方程没有唯一解，返回 `MatrixError::Singular`。
+pub fn solve_sylvester(a: &Matrix, b: &Matrix, c: &Matrix) -> Result<Matrix, MatrixError> {
+    if a.rows() != a.cols() || b.rows() != b.cols() {
+        return Err(MatrixError::NotSquare);
+    }
+    if c.rows() != a.rows() || c.cols() != b.rows() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    let (u, t) = real_schur(a)?;
+    let (v, s) = real_schur(b)?;
+    let f = matmul(&matmul(&u.transpose()?, c)?, &v)?;
+
+    let (m, n) = (a.rows(), b.rows());
+    let t_blocks = diagonal_blocks(&t)?;
+    let mut y = Matrix::zeros(m, n);
+
+    for &(j0, q) in &diagonal_blocks(&s)? {
+        // G = F_J - Σ_{K<J} Y_K S_KJ
+        let mut g = Matrix::zeros(m, q);
+        for i in 0..m {
+            for jj in 0..q {
+                let mut sum = f.get(i, j0 + jj)?;
+                for k in 0..j0 {
+                    sum -= y.get(i, k)? * s.get(k, j0 + jj)?;
+                }
+                g.set(i, jj, sum);
+            }
+        }
+
+        for &(i0, p) in t_blocks.iter().rev() {
+            // rhs = G_I - Σ_{L>I} T_IL Y_LJ
+            let mut rhs = vec![0.0; p * q];
+            for ii in 0..p {
+                for jj in 0..q {
+                    let mut sum = g.get(i0 + ii, jj)?;
+                    for l in (i0 + p)..m {
+                        sum -= t.get(i0 + ii, l)? * y.get(l, j0 + jj)?;
+                    }
+                    rhs[jj * p + ii] = sum;
+                }
+            }
+
+            // (I_q ⊗ T_II + S_JJᵀ ⊗ I_p) vec(Y_IJ) = vec(rhs)
+            let mut k = vec![0.0; p * q * p * q];
+            for c1 in 0..q {
+                for r1 in 0..p {
+                    for c2 in 0..q {
+                        for r2 in 0..p {
+                            let mut val = 0.0;
+                            if c1 == c2 {
+                                val += t.get(i0 + r1, i0 + r2)?;
+                            }
+                            if r1 == r2 {
+                                val += s.get(j0 + c2, j0 + c1)?;
+                            }
+                            k[(c1 * p + r1) * p * q + c2 * p + r2] = val;
+                        }
+                    }
+                }
+            }
+            let block = solve_small(&mut k, &mut rhs, p * q)?;
+            for ii in 0..p {
+                for jj in 0..q {
+                    y.set(i0 + ii, j0 + jj, block[jj * p + ii]);
+                }
+            }
+        }
+    }
+
+    matmul(&matmul(&u, &y)?, &v.transpose()?)
+}
+
+/// 连续时间 Lyapunov 方程 AX + XAᵀ + Q = 0。
+///
+/// 等价于 Sylvester 方程 AX + X(Aᵀ) = -Q；
+/// 当 A 稳定（所有特征值实部 < 0）且 Q 对称半正定时，X 是对称半正定的可控性 Gram 矩阵。
+pub fn solve_lyapunov(a: &Matrix, q: &Matrix) -> Result<Matrix, MatrixError> {
+    let neg_q = scaled(q, -1.0)?;
+    let x = solve_sylvester(a, &a.transpose()?, &neg_q)?;
+    symmetrize_if(q, x)
+}
+
+/// 离散时间 Lyapunov（Stein）方程 A X Aᵀ - X + Q = 0。
+///
+/// 同样先求 A = U T Uᵀ，令 Y = Uᵀ X U、F = Uᵀ Q U，方程变成 T Y Tᵀ - Y = -F。
+/// 按 T 的对角块从右往左处理 Y 的列块、从下往上处理行块，
+/// 每个小块满足 (T_JJ ⊗ T_II - I) vec(Y_IJ) = vec(rhs)。
+///
+/// 当 A 有一对特征值满足 λᵢ λⱼ = 1 时方程没有唯一解，返回 `MatrixError::Singular`。
+pub fn solve_discrete_lyapunov(a: &Matrix, q: &Matrix) -> Result<Matrix, MatrixError> {
+    if a.rows() != a.cols() {
+        return Err(MatrixError::NotSquare);
+    }
+    if q.rows() != a.rows() || q.cols() != a.cols() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    let n = a.rows();
+    let (u, t) = real_schur(a)?;
+    let f = matmul(&matmul(&u.transpose()?, q)?, &u)?;
+    let blocks = diagonal_blocks(&t)?;
+    let mut y = Matrix::zeros(n, n);
+
+    for &(j0, qn) in blocks.iter().rev() {
+        // W = Σ_{L>J} Y_L T_JLᵀ，  G = -F_J - T W
+        let mut w = Matrix::zeros(n, qn);
+        for i in 0..n {
+            for jj in 0..qn {
+                let mut sum = 0.0;
+                for l in (j0 + qn)..n {
+                    sum += y.get(i, l)? * t.get(j0 + jj, l)?;
+                }
+                w.set(i, jj, sum);
+            }
+        }
+        let tw = matmul(&t, &w)?;
+        let mut g = Matrix::zeros(n, qn);
+        for i in 0..n {
+            for jj in 0..qn {
+                g.set(i, jj, -f.get(i, j0 + jj)? - tw.get(i, jj)?);
+            }
+        }
+
+        for &(i0, p) in blocks.iter().rev() {
+            // rhs = G_I - Σ_{K>I} T_IK Y_KJ T_JJᵀ
+            let mut rhs = vec![0.0; p * qn];
+            for ii in 0..p {
+                for jj in 0..qn {
+                    let mut sum = g.get(i0 + ii, jj)?;
+                    for k in (i0 + p)..n {
+                        let tik = t.get(i0 + ii, k)?;
+                        if tik == 0.0 {
+                            continue;
+                        }
+                        for ll in 0..qn {
+                            sum -= tik * y.get(k, j0 + ll)? * t.get(j0 + jj, j0 + ll)?;
+                        }
+                    }
+                    rhs[jj * p + ii] = sum;
+                }
+            }
+
+            // (T_JJ ⊗ T_II - I) vec(Y_IJ) = vec(rhs)
+            let size = p * qn;
+            let mut k = vec![0.0; size * size];
+            for c1 in 0..qn {
+                for r1 in 0..p {
+                    for c2 in 0..qn {
+                        for r2 in 0..p {
+                            let mut val = t.get(j0 + c1, j0 + c2)? * t.get(i0 + r1, i0 + r2)?;
+                            if c1 == c2 && r1 == r2 {
+                                val -= 1.0;
+                            }
+                            k[(c1 * p + r1) * size + c2 * p + r2] = val;
+                        }
+                    }
+                }
+            }
+            let block = solve_small(&mut k, &mut rhs, size)?;
+            for ii in 0..p {
+                for jj in 0..qn {
+                    y.set(i0 + ii, j0 + jj, block[jj * p + ii]);
+                }
+            }
+        }
+    }
+
+    let x = matmul(&matmul(&u, &y)?, &u.transpose()?)?;
+    symmetrize_if(q, x)
+}
+
+/// 用部分选主元的 Gauss 消元解 n × n（n <= 4）的小方程组，k 按行优先存放。
+fn solve_small(k: &mut [f64], rhs: &mut [f64], n: usize) -> Result<Vec<f64>, MatrixError> {
+    let scale = k
+        .iter()
+        .fold(0.0_f64, |m, x| m.max(x.abs()))
+        .max(f64::MIN_POSITIVE);
+    for col in 0..n {
+        let pivot_row = (col..n)
+            .max_by(|&i, &j| k[i * n + col].abs().total_cmp(&k[j * n + col].abs()))
+            .unwrap_or(col);
+        if k[pivot_row * n + col].abs() <= f64::EPSILON * scale {
+            return Err(MatrixError::Singular);
+        }
+        if pivot_row != col {
+            for c in 0..n {
+                k.swap(col * n + c, pivot_row * n + c);
+            }
+            rhs.swap(col, pivot_row);
+        }
+        for row in (col + 1)..n {
+            let factor = k[row * n + col] / k[col * n + col];
+            for c in col..n {
+                k[row * n + c] -= factor * k[col * n + c];
+            }
+            rhs[row] -= factor * rhs[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let sum: f64 = ((i + 1)..n).map(|j| k[i * n + j] * x[j]).sum();
+        x[i] = (rhs[i] - sum) / k[i * n + i];
+    }
+    Ok(x)
+}
+
+fn scaled(a: &Matrix, alpha: f64) -> Result<Matrix, MatrixError> {
+    Matrix::new(
+        a.rows(),
+        a.cols(),
+        a.as_slice().iter().map(|x| alpha * x).collect(),
+    )
+}
+
+/// Q 对称时，理论解 X 也对称；把舍入误差造成的微小不对称抹掉。
+fn symmetrize_if(q: &Matrix, x: Matrix) -> Result<Matrix, MatrixError> {
+    if !q.is_symmetric(0.0)? {
+        return Ok(x);
+    }
+    let xt = x.transpose()?;
+    Matrix::new(
+        x.rows(),
+        x.cols(),
+        x.as_slice()
+            .iter()
+            .zip(xt.as_slice())
+            .map(|(a, b)| 0.5 * (a + b))
+            .collect(),
+    )
+}
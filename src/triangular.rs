@@ -0,0 +1,220 @@
+use crate::blas::{Diag, Side, Transpose, UpLo, trsm, trsv};
+use crate::matrix_dense::{Matrix, MatrixError};
+
+/// 下三角方阵 L（对角线以上全为 0）。
+///
+/// 单位下三角（`unit`）的对角元恒为 1，求解时不做除法；LU 分解的 L 就是这种形式。
+#[derive(Debug, Clone)]
+pub struct LowerTriangular {
+    inner: Triangular,
+}
+
+/// 上三角方阵 U（对角线以下全为 0）。
+#[derive(Debug, Clone)]
+pub struct UpperTriangular {
+    inner: Triangular,
+}
+
+/// 两种三角矩阵共用的实现，`uplo` 区分上下三角。
+#[derive(Debug, Clone)]
+struct Triangular {
+    matrix: Matrix,
+    uplo: UpLo,
+    diag: Diag,
+}
+
+impl LowerTriangular {
+    /// 取方阵 m 的下三角部分（含对角线），严格上三角部分被清零。
+    pub fn new(m: Matrix) -> Result<Self, MatrixError> {
+        Triangular::new(m, UpLo::Lower, Diag::NonUnit).map(|inner| Self { inner })
+    }
+
+    /// 单位下三角：取严格下三角部分，对角元视为（并存为）1。
+    pub fn unit(m: Matrix) -> Result<Self, MatrixError> {
+        Triangular::new(m, UpLo::Lower, Diag::Unit).map(|inner| Self { inner })
+    }
+
+    pub fn size(&self) -> usize {
+        self.inner.matrix.rows()
+    }
+
+    pub fn is_unit_diagonal(&self) -> bool {
+        self.inner.diag == Diag::Unit
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Result<f64, MatrixError> {
+        self.inner.matrix.get(row, col)
+    }
+
+    pub fn as_matrix(&self) -> &Matrix {
+        &self.inner.matrix
+    }
+
+    pub fn into_matrix(self) -> Matrix {
+        self.inner.matrix
+    }
+
+    /// Lᵀ，是一个上三角矩阵。
+    pub fn transpose(&self) -> Result<UpperTriangular, MatrixError> {
+        self.inner
+            .transpose(UpLo::Upper)
+            .map(|inner| UpperTriangular { inner })
+    }
+
+    /// 前代求解 L x = b。
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        self.inner.solve(Transpose::No, b)
+    }
+
+    /// 回代求解 Lᵀ x = b（不需要显式转置）。
+    pub fn solve_transpose(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        self.inner.solve(Transpose::Yes, b)
+    }
+
+    /// 多右端项：L X = B，B 的每一列是一个右端项。
+    pub fn solve_matrix(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        self.inner.solve_matrix(b)
+    }
+
+    /// L⁻¹，仍是下三角（单位下三角的逆仍是单位下三角）。
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        self.inner.inverse().map(|inner| Self { inner })
+    }
+
+    /// det(L) = 对角元之积。
+    pub fn determinant(&self) -> f64 {
+        self.inner.determinant()
+    }
+}
+
+impl UpperTriangular {
+    /// 取方阵 m 的上三角部分（含对角线），严格下三角部分被清零。
+    pub fn new(m: Matrix) -> Result<Self, MatrixError> {
+        Triangular::new(m, UpLo::Upper, Diag::NonUnit).map(|inner| Self { inner })
+    }
+
+    /// 单位上三角：取严格上三角部分，对角元视为（并存为）1。
+    pub fn unit(m: Matrix) -> Result<Self, MatrixError> {
+        Triangular::new(m, UpLo::Upper, Diag::Unit).map(|inner| Self { inner })
+    }
+
+    pub fn size(&self) -> usize {
+        self.inner.matrix.rows()
+    }
+
+    pub fn is_unit_diagonal(&self) -> bool {
+        self.inner.diag == Diag::Unit
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Result<f64, MatrixError> {
+        self.inner.matrix.get(row, col)
+    }
+
+    pub fn as_matrix(&self) -> &Matrix {
+        &self.inner.matrix
+    }
+
+    pub fn into_matrix(self) -> Matrix {
+        self.inner.matrix
+    }
+
+    /// Uᵀ，是一个下三角矩阵。
+    pub fn transpose(&self) -> Result<LowerTriangular, MatrixError> {
+        self.inner
+            .transpose(UpLo::Lower)
+            .map(|inner| LowerTriangular { inner })
+    }
+
+    /// 回代求解 U x = b。
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        self.inner.solve(Transpose::No, b)
+    }
+
+    /// 前代求解 Uᵀ x = b（不需要显式转置）。
+    pub fn solve_transpose(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        self.inner.solve(Transpose::Yes, b)
+    }
+
+    /// 多右端项：U X = B，B 的每一列是一个右端项。
+    pub fn solve_matrix(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        self.inner.solve_matrix(b)
+    }
+
+    /// U⁻¹，仍是上三角。
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        self.inner.inverse().map(|inner| Self { inner })
+    }
+
+    /// det(U) = 对角元之积。
+    pub fn determinant(&self) -> f64 {
+        self.inner.determinant()
+    }
+}
+
+impl Triangular {
+    fn new(mut matrix: Matrix, uplo: UpLo, diag: Diag) -> Result<Self, MatrixError> {
+        if matrix.rows() != matrix.cols() {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = matrix.rows();
+        for i in 0..n {
+            for j in 0..n {
+                let outside = match uplo {
+                    UpLo::Lower => j > i,
+                    UpLo::Upper => j < i,
+                };
+                if outside {
+                    matrix.set(i, j, 0.0);
+                }
+            }
+            if diag == Diag::Unit {
+                matrix.set(i, i, 1.0);
+            }
+        }
+        Ok(Self { matrix, uplo, diag })
+    }
+
+    fn transpose(&self, uplo: UpLo) -> Result<Self, MatrixError> {
+        Ok(Self {
+            matrix: self.matrix.transpose()?,
+            uplo,
+            diag: self.diag,
+        })
+    }
+
+    /// 对角元为 0 时 trsv 返回 `MatrixError::Singular`；对角元只在这里被读一次，不另做预检查。
+    fn solve(&self, trans: Transpose, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let mut x = b.to_vec();
+        trsv(self.uplo, trans, self.diag, &self.matrix, &mut x)?;
+        Ok(x)
+    }
+
+    fn solve_matrix(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        let mut x = b.clone();
+        trsm(
+            Side::Left,
+            self.uplo,
+            Transpose::No,
+            self.diag,
+            1.0,
+            &self.matrix,
+            &mut x,
+        )?;
+        Ok(x)
+    }
+
+    fn inverse(&self) -> Result<Self, MatrixError> {
+        let inv = self.solve_matrix(&Matrix::identity(self.matrix.rows()))?;
+        // 三角矩阵的逆仍是同一种三角矩阵，另一半本来就是 0
+        Self::new(inv, self.uplo, self.diag)
+    }
+
+    fn determinant(&self) -> f64 {
+        match self.diag {
+            Diag::Unit => 1.0,
+            Diag::NonUnit => (0..self.matrix.rows())
+                .map(|i| self.matrix.as_slice()[i * self.matrix.cols() + i])
+                .product(),
+        }
+    }
+}
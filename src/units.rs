@@ -7,6 +7,7 @@
 ///   - 使用 Result + 自定义错误类型 QuantityError
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LengthUnit {
     Meter,
     Centimeter,
@@ -14,6 +15,7 @@ pub enum LengthUnit {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Quantity {
     value: f64,
     unit: LengthUnit,
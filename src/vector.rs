@@ -1,3 +1,5 @@
+use crate::gram_schmidt::orthonormal_basis;
+use crate::matrix_dense::{Matrix, MatrixError};
 use std::ops::{Add, Mul, Neg, Sub};
 // src/vector.rs
 // 你需要自己在这里定义 struct Vector2：
@@ -5,6 +7,7 @@ use std::ops::{Add, Mul, Neg, Sub};
 // - 字段类型建议用 f64
 // - 你可以自己决定是否实现 Debug / Clone / Copy 等 trait
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector2 {
     x: f64,
     y: f64,
@@ -125,3 +128,28 @@ pub fn project_onto(v: &Vector2, basis: &Vector2) -> Vector2 {
         y: proj * basis.y,
     }
 }
+
+/// 把 `project_onto` 推广到子空间：计算 `v` 在矩阵 `a` 的列所张成子空间上的正交投影。
+///
+///   proj(v) = Q Qᵀ v，其中 Q 是 a 的列空间的标准正交基
+///
+/// 要点：
+/// - a 是 m × k 矩阵，v 的长度必须等于 m，否则返回 DimensionMismatch
+/// - a 的列不要求线性无关；线性相关的列在求正交基时会被跳过
+/// - 和 `project_onto` 一样，如果子空间是 {0}（例如 a 全为 0），返回全 0 向量
+pub fn project_onto_columns(v: &[f64], a: &Matrix) -> Result<Vec<f64>, MatrixError> {
+    if v.len() != a.rows() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    let q = orthonormal_basis(a, 1e-10)?;
+    let mut proj = vec![0.0; v.len()];
+    for k in 0..q.cols() {
+        let qk = q.col(k)?;
+        let coeff: f64 = qk.iter().zip(v).map(|(qi, vi)| qi * vi).sum();
+        for (pi, qi) in proj.iter_mut().zip(&qk) {
+            *pi += coeff * qi;
+        }
+    }
+    Ok(proj)
+}
@@ -0,0 +1,144 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// 三维向量 (x, y, z)，接口与 `vector::Vector2` 保持一致：
+/// 运算符用 trait 实现，点积、范数等用自由函数。
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Vector3 {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    pub fn length_sq(&self) -> f64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// 返回归一化后的向量（单位向量）。
+    ///
+    /// 约定同 `Vector2::normalize`：太接近零向量时返回 None。
+    pub fn normalize(&self) -> Option<Vector3> {
+        let len_sq = self.length_sq();
+        if len_sq < 1e-10 {
+            return None;
+        }
+        let len = f64::sqrt(len_sq);
+        Some(Vector3::new(self.x / len, self.y / len, self.z / len))
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul<Vector3> for f64 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl From<(f64, f64, f64)> for Vector3 {
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+/// 点积：a · b = a.x b.x + a.y b.y + a.z b.z
+pub fn dot(a: &Vector3, b: &Vector3) -> f64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+/// 叉积 a × b：与 a、b 都垂直，方向按右手定则，长度 = ‖a‖‖b‖ sin θ。
+pub fn cross(a: &Vector3, b: &Vector3) -> Vector3 {
+    Vector3::new(
+        a.y * b.z - a.z * b.y,
+        a.z * b.x - a.x * b.z,
+        a.x * b.y - a.y * b.x,
+    )
+}
+
+/// 欧几里得范数 ‖v‖。
+pub fn norm(v: &Vector3) -> f64 {
+    f64::sqrt(dot(v, v))
+}
+
+/// 两点之间的距离 ‖a - b‖。
+pub fn distance(a: &Vector3, b: &Vector3) -> f64 {
+    norm(&(*a - *b))
+}
+
+/// v 在 basis 方向上的投影 ((v · basis) / (basis · basis)) basis；basis 是零向量时返回零向量。
+pub fn project_onto(v: &Vector3, basis: &Vector3) -> Vector3 {
+    let bb = dot(basis, basis);
+    if bb < 1e-20 {
+        return Vector3::zero();
+    }
+    *basis * (dot(v, basis) / bb)
+}
+
+/// 标量三重积 a · (b × c) = det[a; b; c]，即三个向量张成的平行六面体的有向体积。
+pub fn triple_product(a: &Vector3, b: &Vector3, c: &Vector3) -> f64 {
+    dot(a, &cross(b, c))
+}
+
+/// 两个向量的夹角，范围 [0, π]；任一向量太接近零向量时返回 None。
+///
+/// 用 atan2(‖a × b‖, a · b) 而不是 acos(a · b / ‖a‖‖b‖)：
+/// 后者在夹角接近 0 或 π 时会损失一半有效数字。
+pub fn angle_between(a: &Vector3, b: &Vector3) -> Option<f64> {
+    if a.length_sq() < 1e-10 || b.length_sq() < 1e-10 {
+        return None;
+    }
+    Some(f64::atan2(norm(&cross(a, b)), dot(a, b)))
+}
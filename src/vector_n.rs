@@ -0,0 +1,307 @@
+use crate::matrix::Matrix2x2;
+use crate::matrix_dense::MatrixError;
+use crate::vector::Vector2;
+use crate::vector3::Vector3;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+/// 维数在编译期确定的 N 维向量，存成 `[f64; N]`（栈上分配，`Copy`）。
+///
+/// 和 `Vector2`、`Vector3` 一样，运算符用 trait 实现，点积、范数等用自由函数；
+/// 两者之间可以用 `From` 互相转换，所以只接受 `Into<VectorN<N>>` 的函数（例如 `path` 里的）
+/// 对任意维数的点都能用。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VectorN<const N: usize> {
+    data: [f64; N],
+}
+
+impl<const N: usize> VectorN<N> {
+    pub fn new(data: [f64; N]) -> Self {
+        Self { data }
+    }
+
+    pub fn zero() -> Self {
+        Self { data: [0.0; N] }
+    }
+
+    /// 每个分量都等于 value。
+    pub fn splat(value: f64) -> Self {
+        Self { data: [value; N] }
+    }
+
+    /// 第 i 个标准基向量 eᵢ；i >= N 时返回 None。
+    pub fn unit(i: usize) -> Option<Self> {
+        (i < N).then(|| {
+            let mut v = Self::zero();
+            v.data[i] = 1.0;
+            v
+        })
+    }
+
+    /// 用 f(i) 生成第 i 个分量。
+    pub fn from_fn(f: impl FnMut(usize) -> f64) -> Self {
+        Self {
+            data: std::array::from_fn(f),
+        }
+    }
+
+    pub fn dim(&self) -> usize {
+        N
+    }
+
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.data
+    }
+
+    pub fn to_array(self) -> [f64; N] {
+        self.data
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, f64> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, f64> {
+        self.data.iter_mut()
+    }
+
+    /// 对每个分量做 f，得到一个新向量。
+    pub fn map(self, f: impl FnMut(f64) -> f64) -> Self {
+        Self {
+            data: self.data.map(f),
+        }
+    }
+
+    pub fn length_sq(&self) -> f64 {
+        dot(self, self)
+    }
+
+    /// 返回归一化后的向量；约定同 `Vector2::normalize`，太接近零向量时返回 None。
+    pub fn normalize(&self) -> Option<Self> {
+        let len_sq = self.length_sq();
+        if len_sq < 1e-10 {
+            return None;
+        }
+        Some(*self / f64::sqrt(len_sq))
+    }
+}
+
+impl<const N: usize> Default for VectorN<N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const N: usize> Index<usize> for VectorN<N> {
+    type Output = f64;
+
+    fn index(&self, i: usize) -> &f64 {
+        &self.data[i]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for VectorN<N> {
+    fn index_mut(&mut self, i: usize) -> &mut f64 {
+        &mut self.data[i]
+    }
+}
+
+impl<const N: usize> IntoIterator for VectorN<N> {
+    type Item = f64;
+    type IntoIter = std::array::IntoIter<f64, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a VectorN<N> {
+    type Item = &'a f64;
+    type IntoIter = std::slice::Iter<'a, f64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<const N: usize> Add for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize> Sub for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize> Neg for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x)
+    }
+}
+
+impl<const N: usize> Mul<f64> for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.map(|x| x * rhs)
+    }
+}
+
+impl<const N: usize> Mul<VectorN<N>> for f64 {
+    type Output = VectorN<N>;
+
+    fn mul(self, rhs: VectorN<N>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const N: usize> Div<f64> for VectorN<N> {
+    type Output = VectorN<N>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self.map(|x| x / rhs)
+    }
+}
+
+impl<const N: usize> AddAssign for VectorN<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.data.iter_mut().zip(rhs.data) {
+            *a += b;
+        }
+    }
+}
+
+impl<const N: usize> SubAssign for VectorN<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.data.iter_mut().zip(rhs.data) {
+            *a -= b;
+        }
+    }
+}
+
+impl<const N: usize> MulAssign<f64> for VectorN<N> {
+    fn mul_assign(&mut self, rhs: f64) {
+        for a in self.data.iter_mut() {
+            *a *= rhs;
+        }
+    }
+}
+
+impl<const N: usize> DivAssign<f64> for VectorN<N> {
+    fn div_assign(&mut self, rhs: f64) {
+        for a in self.data.iter_mut() {
+            *a /= rhs;
+        }
+    }
+}
+
+impl<const N: usize> From<[f64; N]> for VectorN<N> {
+    fn from(data: [f64; N]) -> Self {
+        Self { data }
+    }
+}
+
+impl<const N: usize> From<VectorN<N>> for [f64; N] {
+    fn from(v: VectorN<N>) -> Self {
+        v.data
+    }
+}
+
+/// 从切片构造，长度必须恰好为 N。
+impl<const N: usize> TryFrom<&[f64]> for VectorN<N> {
+    type Error = MatrixError;
+
+    fn try_from(s: &[f64]) -> Result<Self, MatrixError> {
+        s.try_into()
+            .map(Self::new)
+            .map_err(|_| MatrixError::DimensionMismatch)
+    }
+}
+
+impl From<Vector2> for VectorN<2> {
+    fn from(v: Vector2) -> Self {
+        Self::new([v.x(), v.y()])
+    }
+}
+
+impl From<VectorN<2>> for Vector2 {
+    fn from(v: VectorN<2>) -> Self {
+        Vector2::new(v[0], v[1])
+    }
+}
+
+impl From<Vector3> for VectorN<3> {
+    fn from(v: Vector3) -> Self {
+        Self::new([v.x(), v.y(), v.z()])
+    }
+}
+
+impl From<VectorN<3>> for Vector3 {
+    fn from(v: VectorN<3>) -> Self {
+        Vector3::new(v[0], v[1], v[2])
+    }
+}
+
+/// Matrix2x2 * VectorN<2>，结果与 `Matrix2x2 * Vector2` 相同。
+impl Mul<VectorN<2>> for Matrix2x2 {
+    type Output = VectorN<2>;
+
+    fn mul(self, rhs: VectorN<2>) -> Self::Output {
+        (self * Vector2::from(rhs)).into()
+    }
+}
+
+/// 点积 a · b = Σ aᵢ bᵢ
+pub fn dot<const N: usize>(a: &VectorN<N>, b: &VectorN<N>) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// 欧几里得范数 ‖v‖。
+pub fn norm<const N: usize>(v: &VectorN<N>) -> f64 {
+    f64::sqrt(dot(v, v))
+}
+
+/// 两点之间的距离 ‖a - b‖。
+pub fn distance<const N: usize>(a: &VectorN<N>, b: &VectorN<N>) -> f64 {
+    norm(&(*a - *b))
+}
+
+/// v 在 basis 方向上的投影；basis 是零向量时返回零向量（与 `vector::project_onto` 一致）。
+pub fn project_onto<const N: usize>(v: &VectorN<N>, basis: &VectorN<N>) -> VectorN<N> {
+    let bb = dot(basis, basis);
+    if bb < 1e-20 {
+        return VectorN::zero();
+    }
+    *basis * (dot(v, basis) / bb)
+}
+
+/// 线性插值 a + t (b - a)：t = 0 给出 a，t = 1 给出 b，t 不限于 [0, 1]。
+pub fn lerp<const N: usize>(a: &VectorN<N>, b: &VectorN<N>, t: f64) -> VectorN<N> {
+    VectorN::from_fn(|i| a[i] + t * (b[i] - a[i]))
+}
+
+/// 逐分量取较小值。
+pub fn component_min<const N: usize>(a: &VectorN<N>, b: &VectorN<N>) -> VectorN<N> {
+    VectorN::from_fn(|i| a[i].min(b[i]))
+}
+
+/// 逐分量取较大值。
+pub fn component_max<const N: usize>(a: &VectorN<N>, b: &VectorN<N>) -> VectorN<N> {
+    VectorN::from_fn(|i| a[i].max(b[i]))
+}